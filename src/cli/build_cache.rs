@@ -0,0 +1,75 @@
+use crate::pages_error::PagesError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, RwLock};
+
+#[derive(Serialize, Deserialize, Default)]
+struct BuildCacheContent {
+    build_digest: String,
+    pages: HashMap<String, Option<String>>,
+}
+
+pub struct BuildCache {
+    path: PathBuf,
+    build_digest: String,
+    previous: RwLock<Option<BuildCacheContent>>,
+    current: Mutex<HashMap<String, Option<String>>>,
+}
+
+impl BuildCache {
+    pub fn load(path: PathBuf, build_digest: String) -> anyhow::Result<Self> {
+        let previous = if path.exists() {
+            // an unreadable cache file is handled as a missing one
+            serde_json::from_reader(fs::File::open(&path)?).ok()
+        } else {
+            None
+        };
+        Ok(Self {
+            path,
+            build_digest,
+            previous: RwLock::new(previous),
+            current: Default::default(),
+        })
+    }
+
+    pub fn has_previous_build(&self) -> anyhow::Result<bool> {
+        Ok(self.previous.read().map_err(|e| PagesError::Exec(e.to_string()))?.is_some())
+    }
+
+    pub fn previous_paths(&self) -> anyhow::Result<Vec<String>> {
+        Ok(match &*self.previous.read().map_err(|e| PagesError::Exec(e.to_string()))? {
+            None => vec![],
+            Some(p) => p.pages.keys().cloned().collect(),
+        })
+    }
+
+    pub fn is_fresh(&self, page_path: &str, page_key: &str) -> anyhow::Result<bool> {
+        if let Some(previous) = &*self.previous.read().map_err(|e| PagesError::Exec(e.to_string()))? {
+            if previous.build_digest != self.build_digest {
+                return Ok(false);
+            }
+            if let Some(Some(previous_key)) = previous.pages.get(page_path) {
+                return Ok(previous_key == page_key);
+            }
+        }
+        Ok(false)
+    }
+
+    pub fn record(&self, page_path: String, page_key: Option<String>) -> anyhow::Result<()> {
+        self.current.lock().map_err(|e| PagesError::Exec(e.to_string()))?.insert(page_path, page_key);
+        Ok(())
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        let mut current = self.current.lock().map_err(|e| PagesError::Exec(e.to_string()))?;
+        let content = BuildCacheContent {
+            build_digest: self.build_digest.clone(),
+            pages: current.drain().collect(),
+        };
+        serde_json::to_writer(fs::File::create(&self.path)?, &content)?;
+        *self.previous.write().map_err(|e| PagesError::Exec(e.to_string()))? = Some(content);
+        Ok(())
+    }
+}
@@ -1,10 +1,15 @@
 use crate::cli::writer::Writer;
-use crate::cli::FsWriter;
-use crate::config::Value;
-use crate::maker::{Maker, SelectorConfig, StageValue};
-use crate::pages::{Env, FsLoader, Loader, PrintLevel, ROOT_PATH_KEY};
+use crate::cli::{BuildCache, FsWriter};
+use crate::config::{Value, ValueError};
+use crate::maker::{Maker, MountConfig, SelectorConfig, SiteConfig, StageValue};
+use crate::pages::{
+    digest_serializable, ContentCache, Env, FsLoader, GitLoader, Loader, Mount, MountLoader, Page, PageBundle, PrintLevel, VecBundle, BUILD_DRAFTS_KEY, BUILD_FUTURE_KEY, DEFAULT_LANG_KEY, MOUNTS_KEY,
+    ROOT_PATH_KEY, TAXONOMIES_KEY, USE_GITIGNORE_KEY,
+};
 use crate::pages_error::PagesError;
-use crate::stages::{PageGeneratorBag, PageGeneratorBagImpl, ProcessingResult};
+use crate::remote::GitReference;
+use crate::stages::{PageGeneratorBag, PageGeneratorBagImpl, ProcessingResult, Stage};
+use std::collections::HashMap;
 use std::env::current_dir;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -17,8 +22,12 @@ pub struct Executor {
     pub writer: Box<dyn Writer>,
     pub stage_config: StageValue,
     pub env: Env,
+    pub output_dir: PathBuf,
+    pub config_path: Option<PathBuf>,
+    pub cache_path: Option<PathBuf>,
 }
 
+#[derive(Default)]
 pub struct ExecutorParams {
     pub input_dir: Option<PathBuf>,
     pub output_dir: Option<PathBuf>,
@@ -26,25 +35,51 @@ pub struct ExecutorParams {
     pub git_repo_path_config: Option<String>,
     pub handlebars_config: Option<Value>,
     pub print_level: Option<PrintLevel>,
+    pub cache_path: Option<PathBuf>,
+    pub source_reference: Option<GitReference>,
+    pub use_gitignore: bool,
+    pub mounts: Vec<MountConfig>,
+    pub content_cache_limit: Option<usize>,
+    pub build_drafts: bool,
+    pub build_future: bool,
+    pub build_time: Option<String>,
+    pub default_lang: Option<String>,
 }
 
 impl Executor {
     pub fn execute(&self) -> anyhow::Result<Execution> {
+        self.execute_stage(None)
+    }
+
+    pub fn make_stage(&self) -> anyhow::Result<Arc<dyn Stage>> {
+        self.maker.make(None, &self.stage_config, &self.env).map_err(|e| ValueError::prefix(e, "stages"))
+    }
+
+    // executes the pipeline using an already made stage when provided
+    pub fn execute_stage(&self, stage: Option<&Arc<dyn Stage>>) -> anyhow::Result<Execution> {
         let start = Instant::now();
 
         let input_bundle = self.loader.load(&self.env)?;
         let loading_elapsed = start.elapsed();
 
-        let stage = self.maker.make(None, &self.stage_config, &self.env)?;
+        let stage = match stage {
+            Some(s) => Arc::clone(s),
+            None => self.make_stage()?,
+        };
         let stage_making_elapsed = start.elapsed();
 
         let gen_bag: Arc<dyn PageGeneratorBag> = PageGeneratorBagImpl::new();
         let (result_bundle, processing_result) = stage.process(&input_bundle, &self.env, &gen_bag)?;
+        let result_bundle = Executor::drop_unpublished(result_bundle, &self.env);
         let processing_elapsed = start.elapsed();
 
         self.writer.write(&result_bundle, &self.env, &gen_bag)?;
         let writing_elapsed = start.elapsed();
 
+        if let Some(content_cache) = self.env.content_cache() {
+            content_cache.print_stats(&self.env)?;
+        }
+
         Ok(Execution {
             loading_elapsed,
             stage_making_elapsed,
@@ -73,22 +108,97 @@ impl Executor {
             output_dir = curr_dir.join(output_dir);
         }
 
-        let stage_config = if let Some(config_file) = params.config_path {
-            Executor::read_config(&curr_dir, &config_file)?
+        let config_path = if let Some(config_file) = &params.config_path {
+            Some(if config_file.is_relative() { curr_dir.join(config_file) } else { config_file.clone() })
         } else if input_dir.join("stages.yaml").exists() {
-            Executor::read_config(&curr_dir, &input_dir.join("stages.yaml"))?
+            Some(input_dir.join("stages.yaml"))
         } else if input_dir.join("stages.json").exists() {
-            Executor::read_config(&curr_dir, &input_dir.join("stages.json"))?
+            Some(input_dir.join("stages.json"))
         } else {
-            Executor::default_config(&params)
+            None
         };
+        let site_config = match &config_path {
+            Some(config_file) => Some(Executor::read_config(&curr_dir, config_file)?),
+            None => None,
+        };
+        let taxonomies = site_config.as_ref().map(|c| c.taxonomies().to_vec()).unwrap_or_default();
+        let (config_mounts, stage_config) = match site_config {
+            Some(site_config) => site_config.into_parts(),
+            None => (vec![], Executor::default_config(&params)),
+        };
+        let cache_path = params.cache_path.as_ref().map(|p| if p.is_relative() { curr_dir.join(p) } else { p.clone() });
 
-        let loader = Box::new(FsLoader::new(input_dir.clone()));
+        // cli mount sources are relative to current dir, config ones to config file dir
+        let mut mount_dirs = vec![];
+        for mount in &params.mounts {
+            mount_dirs.push((curr_dir.join(&mount.source), Executor::mount_prefix(&mount.prefix)));
+        }
+        if let Some(config_dir) = config_path.as_ref().and_then(|p| p.parent()) {
+            for mount in config_mounts {
+                mount_dirs.push((config_dir.join(&mount.source), Executor::mount_prefix(&mount.prefix)));
+            }
+        }
+        // input dir is mounted at the root, unless no source dir is given and another source is mounted there
+        if !mount_dirs.is_empty() && (params.input_dir.is_some() || !mount_dirs.iter().any(|(_, prefix)| prefix.is_empty())) {
+            mount_dirs.insert(0, (input_dir.clone(), "".to_string()));
+        }
+        for (i, (mount_dir, prefix)) in mount_dirs.iter().enumerate() {
+            if let Some((other_dir, _)) = mount_dirs[..i].iter().find(|(_, p)| p == prefix) {
+                return Err(PagesError::Conflict(format!("{} and {} are both mounted on /{}", other_dir.to_string_lossy(), mount_dir.to_string_lossy(), prefix)).into());
+            }
+        }
+        // root source, e.g. for git metadata
+        let root_dir = mount_dirs.iter().find(|(_, prefix)| prefix.is_empty()).map(|(d, _)| d.clone()).unwrap_or_else(|| input_dir.clone());
+        let loader: Box<dyn Loader> = if mount_dirs.is_empty() {
+            Executor::make_source_loader(input_dir.clone(), &params.source_reference)?
+        } else {
+            let mut mounts = vec![];
+            for (mount_dir, prefix) in &mount_dirs {
+                mounts.push(Mount::new(
+                    mount_dir.to_string_lossy().to_string(),
+                    prefix,
+                    Executor::make_source_loader(mount_dir.clone(), &params.source_reference)?,
+                ));
+            }
+            Box::new(MountLoader { mounts })
+        };
         let maker = Maker::default();
-        let writer = Box::new(FsWriter::new(output_dir)?);
+        let writer = Executor::make_writer(&output_dir, &cache_path, &stage_config)?;
 
-        let env = Env::default_for_level(params.print_level);
-        env.insert(ROOT_PATH_KEY.to_string(), Value::String(input_dir.to_string_lossy().to_string()));
+        let mut env = Env::default_for_level(params.print_level);
+        if let Some(content_cache_limit) = params.content_cache_limit {
+            env = env.with_content_cache(Arc::new(ContentCache::new(content_cache_limit)));
+        }
+        env.insert(ROOT_PATH_KEY.to_string(), Value::String(root_dir.to_string_lossy().to_string()));
+        if !mount_dirs.is_empty() {
+            let mounts = mount_dirs
+                .iter()
+                .map(|(mount_dir, prefix)| {
+                    let mut mount = HashMap::new();
+                    mount.insert("source".to_string(), Value::String(mount_dir.to_string_lossy().to_string()));
+                    mount.insert("prefix".to_string(), Value::String(prefix.clone()));
+                    Value::Map(mount)
+                })
+                .collect();
+            env.insert(MOUNTS_KEY.to_string(), Value::Vec(mounts));
+        }
+        if params.use_gitignore {
+            env.insert(USE_GITIGNORE_KEY.to_string(), Value::Bool(true));
+        }
+        if params.build_drafts {
+            env.insert(BUILD_DRAFTS_KEY.to_string(), Value::Bool(true));
+        }
+        if params.build_future {
+            env.insert(BUILD_FUTURE_KEY.to_string(), Value::Bool(true));
+        }
+        if let Some(build_time) = &params.build_time {
+            env.set_build_time(build_time)?;
+        }
+
+        if let Some(default_lang) = &params.default_lang {
+            env.insert(DEFAULT_LANG_KEY.to_string(), Value::String(default_lang.to_string()));
+        }
+        Executor::set_taxonomies(&env, &taxonomies);
 
         Ok(Self {
             loader,
@@ -96,11 +206,66 @@ impl Executor {
             writer,
             stage_config,
             env,
+            output_dir,
+            config_path,
+            cache_path,
+        })
+    }
+
+    // drafts, future and expired pages are dropped before the output index is made, so they appear in no index, tag page or query result
+    pub(crate) fn drop_unpublished(bundle: Arc<dyn PageBundle>, env: &Env) -> Arc<dyn PageBundle> {
+        let now = env.build_time();
+        let (build_drafts, build_future) = (env.build_drafts(), env.build_future());
+        let is_published = |p: &&Arc<dyn Page>| match p.metadata() {
+            None => true,
+            Some(m) => (build_drafts || !m.is_draft()) && (build_future || !m.is_future(now)) && !m.is_expired(now),
+        };
+        if bundle.pages().iter().all(|p| is_published(&p)) {
+            return bundle;
+        }
+        let pages: Vec<Arc<dyn Page>> = bundle.pages().iter().filter(is_published).cloned().collect();
+        env.print_v("Executor", &format!("{} draft, future or expired pages dropped", bundle.pages().len() - pages.len()));
+        Arc::new(VecBundle { p: pages })
+    }
+
+    fn make_source_loader(dir: PathBuf, source_reference: &Option<GitReference>) -> anyhow::Result<Box<dyn Loader>> {
+        Ok(match source_reference {
+            None => Box::new(FsLoader::new(dir)),
+            Some(reference) => Box::new(GitLoader::discover(dir, reference.clone())?),
+        })
+    }
+
+    pub(crate) fn make_writer(output_dir: &Path, cache_path: &Option<PathBuf>, stage_config: &StageValue) -> anyhow::Result<Box<dyn Writer>> {
+        Ok(match cache_path {
+            None => Box::new(FsWriter::new(output_dir.to_path_buf())?),
+            Some(cache_path) => {
+                // any stage configuration or program version change invalidates the whole cache
+                let build_digest = digest_serializable(&(env!("CARGO_PKG_VERSION"), stage_config))?;
+                Box::new(FsWriter::new_with_cache(output_dir.to_path_buf(), BuildCache::load(cache_path.to_path_buf(), build_digest)?)?)
+            }
         })
     }
 
     fn default_config(params: &ExecutorParams) -> StageValue {
         let mut stages = vec![
+            StageValue::Replace {
+                by: Box::new(StageValue::ProcessorStage {
+                    processor_type: "front_matter".to_string(),
+                    config: Default::default(),
+                }),
+                replace: SelectorConfig::Base {
+                    path: None,
+                    tag: None,
+                    tags: None,
+                    ext: Some(".md".to_string()),
+                    author: None,
+                    publishing: None,
+                    last_edit: None,
+                    title_contains: None,
+                    data: None,
+                    has_metadata: None,
+                },
+            },
             StageValue::ProcessorStage {
                 processor_type: "shadow".to_string(),
                 config: Default::default(),
@@ -130,6 +295,10 @@ impl Executor {
                     ext: Some(".md".to_string()),
                     author: None,
                     publishing: None,
+                    last_edit: None,
+                    title_contains: None,
+                    data: None,
+                    has_metadata: None,
                 },
             },
         ];
@@ -146,6 +315,10 @@ impl Executor {
                     ext: Some(".html".to_string()),
                     author: None,
                     publishing: None,
+                    last_edit: None,
+                    title_contains: None,
+                    data: None,
+                    has_metadata: None,
                 },
             })
         }
@@ -153,10 +326,26 @@ impl Executor {
             processor_type: "path_generator".to_string(),
             config: Value::None,
         });
+        stages.push(StageValue::ProcessorStage {
+            processor_type: "localize".to_string(),
+            config: Value::None,
+        });
         StageValue::Sequence(stages)
     }
 
-    fn read_config(curr_dir: &Path, config_file: &Path) -> anyhow::Result<StageValue> {
+    // taxonomies are set when declared, or reset when reloaded ones are removed
+    pub(crate) fn set_taxonomies(env: &Env, taxonomies: &[String]) {
+        if !taxonomies.is_empty() || env.get(TAXONOMIES_KEY).is_some() {
+            env.insert(TAXONOMIES_KEY.to_string(), Value::Vec(taxonomies.iter().map(|t| Value::String(t.to_string())).collect()));
+        }
+    }
+
+    // normalized mount prefix : `/assets/` and `assets` are both mounted on `assets`
+    fn mount_prefix(prefix: &str) -> String {
+        prefix.split('/').filter(|s| !s.is_empty()).collect::<Vec<&str>>().join("/")
+    }
+
+    pub(crate) fn read_config(curr_dir: &Path, config_file: &Path) -> anyhow::Result<SiteConfig> {
         if config_file.is_relative() {
             return Executor::read_config(curr_dir, &curr_dir.join(config_file));
         }
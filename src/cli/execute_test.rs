@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    use crate::cli::{Executor, ExecutorParams};
+    use crate::config::Value;
+    use crate::maker::{GitMetadataStageMaker, MountConfig, StageMaker};
+    use crate::pages::test_page::TestPage;
+    use crate::pages::{BundleIndex, Env, Metadata, PageBundle, VecBundle, BUILD_DRAFTS_KEY, BUILD_FUTURE_KEY};
+    use crate::stages::{GitMetadata, SequenceStage};
+    use rustassert::fs::{FileNode, TmpTestFolder};
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    fn page(path: &str, draft: Option<bool>, publishing_date: Option<i64>, expiry_date: Option<i64>) -> Arc<TestPage> {
+        Arc::new(TestPage {
+            path: vec![path.to_string()],
+            metadata: Some(Metadata {
+                publishing_date,
+                expiry_date,
+                draft,
+                ..Default::default()
+            }),
+            content: format!("{} content", path),
+        })
+    }
+
+    fn paths(bundle: &Arc<dyn PageBundle>) -> Vec<String> {
+        bundle.pages().iter().map(|p| p.path().join("/")).collect()
+    }
+
+    #[test]
+    fn drop_draft_pages_unless_drafts_are_built() {
+        let draft_bundle = || -> Arc<dyn PageBundle> {
+            Arc::new(VecBundle {
+                p: vec![
+                    page("f1.md", Some(true), None, None),
+                    page("f2.md", Some(false), None, None),
+                    Arc::new(TestPage {
+                        path: vec!["f3.md".to_string()],
+                        metadata: None,
+                        content: "f3 content".to_string(),
+                    }),
+                ],
+            })
+        };
+        let result = Executor::drop_unpublished(draft_bundle(), &Env::test());
+        assert_eq!(paths(&result), vec!["f2.md", "f3.md"]);
+
+        let env = Env::test();
+        env.insert(BUILD_DRAFTS_KEY.to_string(), Value::Bool(true));
+        let result = Executor::drop_unpublished(draft_bundle(), &env);
+        assert_eq!(paths(&result), vec!["f1.md", "f2.md", "f3.md"]);
+
+        let index = BundleIndex::from(&result);
+        let drafts = index.all_pages.iter().map(|p| p.metadata.as_ref().map(|m| m.draft)).collect::<Vec<_>>();
+        assert_eq!(drafts, vec![Some(true), Some(false), None]);
+    }
+
+    #[test]
+    fn drop_future_and_expired_pages_relative_to_build_time() {
+        // 2021-10-20T00:00:00Z
+        let build_time = 1634688000;
+        let dated_bundle = || -> Arc<dyn PageBundle> {
+            Arc::new(VecBundle {
+                p: vec![
+                    page("published.md", None, Some(build_time - 10), None),
+                    page("future.md", None, Some(build_time + 10), None),
+                    page("expired.md", None, Some(build_time - 10), Some(build_time)),
+                    page("not_expired.md", None, None, Some(build_time + 10)),
+                ],
+            })
+        };
+
+        let env = Env::test();
+        // the parse error detail depends on the chrono version
+        assert!(env.set_build_time("20 oct").unwrap_err().to_string().starts_with("invalid build time 20 oct : "));
+        env.set_build_time("2021-10-20").unwrap();
+        let result = Executor::drop_unpublished(dated_bundle(), &env);
+        assert_eq!(paths(&result), vec!["published.md", "not_expired.md"]);
+
+        env.insert(BUILD_FUTURE_KEY.to_string(), Value::Bool(true));
+        let result = Executor::drop_unpublished(dated_bundle(), &env);
+        assert_eq!(paths(&result), vec!["published.md", "future.md", "not_expired.md"]);
+    }
+
+    #[test]
+    fn mount_source_dir_at_root_next_to_mounted_sources() {
+        let test_folder = TmpTestFolder::new().unwrap();
+        for (dir, file) in &[("src", "index.md"), ("shared", "logo.svg"), ("other", "logo.svg")] {
+            test_folder
+                .write(&FileNode::Dir {
+                    name: dir.to_string(),
+                    sub: vec![FileNode::new_file(file, vec![])],
+                })
+                .unwrap();
+        }
+        let root = test_folder.get_path();
+        let params = |mounts: Vec<(&str, &str)>| ExecutorParams {
+            input_dir: Some(root.join("src")),
+            output_dir: Some(root.join("out")),
+            mounts: mounts
+                .into_iter()
+                .map(|(source, prefix)| MountConfig {
+                    source: root.join(source).to_string_lossy().to_string(),
+                    prefix: prefix.to_string(),
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        let executor = Executor::new(params(vec![("shared", "/assets/")])).unwrap();
+        let mut loaded = paths(&executor.loader.load(&executor.env).unwrap());
+        loaded.sort();
+        assert_eq!(loaded, vec!["assets/logo.svg", "index.md"]);
+        assert_eq!(executor.env.mounts(), vec![(vec![], root.join("src")), (vec!["assets".to_string()], root.join("shared"))]);
+
+        // git metadata is read from each mounted source repository
+        let stage = GitMetadataStageMaker.make(None, &Value::None, &executor.env).unwrap();
+        let git_stages = stage.as_any().unwrap().downcast_ref::<SequenceStage>().unwrap().stages.iter().map(|s| {
+            let git_stage = s.as_any().unwrap().downcast_ref::<GitMetadata>().unwrap();
+            (git_stage.page_prefix.clone(), git_stage.repo_path.clone())
+        });
+        assert_eq!(
+            git_stages.collect::<Vec<(Vec<String>, PathBuf)>>(),
+            vec![(vec!["assets".to_string()], root.join("shared")), (vec![], root.join("src"))]
+        );
+
+        // a root mount does not silently replace the source dir
+        let err = Executor::new(params(vec![("shared", "/")])).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            format!("{} and {} are both mounted on /", root.join("src").to_string_lossy(), root.join("shared").to_string_lossy())
+        );
+
+        let err = Executor::new(params(vec![("shared", "assets"), ("other", "/assets")])).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            format!("{} and {} are both mounted on /assets", root.join("shared").to_string_lossy(), root.join("other").to_string_lossy())
+        );
+    }
+}
@@ -1,16 +1,18 @@
 use crate::cli::writer::Writer;
-use crate::pages::{BundleIndex, Env, Page, PageBundle, PageIndex};
+use crate::cli::BuildCache;
+use crate::pages::{page_content_key, BundleIndex, Env, Page, PageBundle};
 use crate::pages_error::PagesError;
 use crate::stages::PageGeneratorBag;
 use rayon::prelude::*;
 use std::collections::HashSet;
 use std::fs::{create_dir_all, remove_dir_all, remove_file, File};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::{fs, io};
 
 pub struct FsWriter {
     path: PathBuf,
+    cache: Option<BuildCache>,
 }
 
 impl FsWriter {
@@ -18,15 +20,42 @@ impl FsWriter {
         if !path.exists() {
             fs::create_dir(&path)?;
         }
-        Ok(Self { path })
+        Ok(Self { path, cache: None })
+    }
+
+    pub fn new_with_cache(path: PathBuf, cache: BuildCache) -> anyhow::Result<Self> {
+        if !path.exists() {
+            fs::create_dir(&path)?;
+        }
+        Ok(Self { path, cache: Some(cache) })
+    }
+}
+
+impl FsWriter {
+    // removes directories left empty by a removed file, up to the output directory
+    fn prune_empty_dirs(&self, removed_file: &Path, env: &Env) -> anyhow::Result<()> {
+        let mut dir = removed_file.parent();
+        while let Some(d) = dir {
+            if d == self.path || !d.starts_with(&self.path) || fs::read_dir(d)?.next().is_some() {
+                break;
+            }
+            env.print_vv("FS Writer", &format!("removing empty directory {}", d.to_string_lossy()));
+            fs::remove_dir(d)?;
+            dir = d.parent();
+        }
+        Ok(())
     }
 }
 
 impl Writer for FsWriter {
     fn write(&self, bundle: &Arc<dyn PageBundle>, env: &Env, gen_bag: &Arc<dyn PageGeneratorBag>) -> anyhow::Result<()> {
         env.print_v("FS Writer", "start writing pages");
+        let incremental = match &self.cache {
+            Some(cache) => cache.has_previous_build()?,
+            None => false,
+        };
         // Clean output directory
-        if self.path.exists() {
+        if self.path.exists() && !incremental {
             let existing_paths = fs::read_dir(&self.path)?;
 
             for path in existing_paths {
@@ -40,7 +69,7 @@ impl Writer for FsWriter {
             }
         }
         // Make output index
-        let output_index = BundleIndex::from(bundle);
+        let output_index = BundleIndex::new(bundle, &env.taxonomies());
 
         // Get pages and generator pages
         let mut pages: Vec<Arc<dyn Page>> = bundle.pages().to_vec();
@@ -67,26 +96,76 @@ impl Writer for FsWriter {
             create_dir_all(&file_path)?;
         }
 
+        // Env digest invalidates cached pages depending on it
+        let env_digest = match &self.cache {
+            Some(_) => env.values_digest()?,
+            None => String::new(),
+        };
+
         // Write pages
-        pages
+        let written = pages
             .par_iter()
             .map(|p| {
-                let page_index = PageIndex::from(p);
+                let page_index = output_index.page_index(p);
                 let mut file_path = self.path.clone();
                 let path = p.path();
                 if path.is_empty() {
-                    return Ok(());
+                    return Ok(None);
                 }
                 for v in path {
                     file_path.push(v);
                 }
+                if let Some(cache) = &self.cache {
+                    let page_key = match p.fingerprint() {
+                        Some(fingerprint) => Some(page_content_key(&fingerprint, &page_index, p.metadata(), &output_index, &env_digest)?),
+                        None => None,
+                    };
+                    let page_path = path.join("/");
+                    let fresh = match &page_key {
+                        Some(k) => cache.is_fresh(&page_path, k)? && file_path.exists(),
+                        None => false,
+                    };
+                    cache.record(page_path, page_key)?;
+                    if fresh {
+                        env.print_vvv("FS Writer", &format!("unchanged output file {}", &file_path.to_string_lossy()));
+                        return Ok(Some(false));
+                    }
+                }
                 let mut file = File::create(&file_path)?;
                 let mut reader = p.open(&page_index, &output_index, env)?;
                 env.print_vv("FS Writer", &format!("writing output file {}", &file_path.to_string_lossy()));
                 io::copy(&mut reader, &mut file)?;
-                Ok(())
+                Ok(Some(true))
             })
-            .collect::<anyhow::Result<Vec<()>>>()?;
+            .collect::<anyhow::Result<Vec<Option<bool>>>>()?;
+
+        if let Some(cache) = &self.cache {
+            // Remove outputs of previous build that are no longer produced
+            let current_paths: HashSet<String> = pages.iter().map(|p| p.path().join("/")).collect();
+            for previous_path in cache.previous_paths()? {
+                if current_paths.contains(&previous_path) {
+                    continue;
+                }
+                let mut file_path = self.path.clone();
+                for v in previous_path.split('/') {
+                    file_path.push(v);
+                }
+                if file_path.is_file() {
+                    env.print_vv("FS Writer", &format!("removing {}", file_path.to_string_lossy()));
+                    remove_file(&file_path)?;
+                    self.prune_empty_dirs(&file_path, env)?;
+                }
+            }
+            cache.save()?;
+            env.print_v(
+                "FS Writer",
+                &format!(
+                    "{} pages written, {} unchanged pages skipped",
+                    written.iter().filter(|w| **w == Some(true)).count(),
+                    written.iter().filter(|w| **w == Some(false)).count()
+                ),
+            );
+        }
 
         Ok(())
     }
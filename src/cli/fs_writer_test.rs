@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use crate::cli::{BuildCache, FsWriter, Writer};
+    use crate::pages::test_page::TestPage;
+    use crate::pages::{Env, PageBundle, VecBundle};
+    use crate::stages::PageGeneratorBagImpl;
+    use rustassert::fs::TmpTestFolder;
+    use std::fs;
+    use std::sync::Arc;
+
+    fn bundle(pages: &[(&str, &str)]) -> Arc<dyn PageBundle> {
+        Arc::new(VecBundle {
+            p: pages
+                .iter()
+                .map(|(path, content)| {
+                    Arc::new(TestPage {
+                        path: path.split('/').map(|s| s.to_string()).collect(),
+                        metadata: None,
+                        content: content.to_string(),
+                    }) as Arc<_>
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn write_all_pages_without_cache() {
+        let test_folder = TmpTestFolder::new().unwrap();
+        let output = test_folder.get_path().join("output");
+        let writer = FsWriter::new(output.clone()).unwrap();
+
+        writer
+            .write(&bundle(&[("a.html", "a content"), ("d/b.html", "b content")]), &Env::test(), &PageGeneratorBagImpl::new())
+            .unwrap();
+
+        assert_eq!(fs::read_to_string(output.join("a.html")).unwrap(), "a content");
+        assert_eq!(fs::read_to_string(output.join("d").join("b.html")).unwrap(), "b content");
+    }
+
+    #[test]
+    fn skip_unchanged_pages_when_cache_is_enabled() {
+        let test_folder = TmpTestFolder::new().unwrap();
+        let output = test_folder.get_path().join("output");
+        let cache_path = test_folder.get_path().join("cache.json");
+        let writer = FsWriter::new_with_cache(output.clone(), BuildCache::load(cache_path.clone(), "build".to_string()).unwrap()).unwrap();
+
+        writer
+            .write(&bundle(&[("a.html", "a content"), ("b.html", "b content")]), &Env::test(), &PageGeneratorBagImpl::new())
+            .unwrap();
+        assert!(cache_path.exists());
+
+        // tampered outputs are kept when sources did not change
+        fs::write(output.join("a.html"), "tampered a").unwrap();
+        fs::write(output.join("b.html"), "tampered b").unwrap();
+
+        writer
+            .write(&bundle(&[("a.html", "a content"), ("b.html", "new b content")]), &Env::test(), &PageGeneratorBagImpl::new())
+            .unwrap();
+
+        assert_eq!(fs::read_to_string(output.join("a.html")).unwrap(), "tampered a");
+        assert_eq!(fs::read_to_string(output.join("b.html")).unwrap(), "new b content");
+    }
+
+    #[test]
+    fn reload_cache_from_disk_and_remove_stale_outputs() {
+        let test_folder = TmpTestFolder::new().unwrap();
+        let output = test_folder.get_path().join("output");
+        let cache_path = test_folder.get_path().join("cache.json");
+
+        let writer = FsWriter::new_with_cache(output.clone(), BuildCache::load(cache_path.clone(), "build".to_string()).unwrap()).unwrap();
+        writer
+            .write(&bundle(&[("a.html", "a content"), ("b.html", "b content")]), &Env::test(), &PageGeneratorBagImpl::new())
+            .unwrap();
+        fs::write(output.join("a.html"), "tampered a").unwrap();
+
+        let writer = FsWriter::new_with_cache(output.clone(), BuildCache::load(cache_path.clone(), "build".to_string()).unwrap()).unwrap();
+        writer.write(&bundle(&[("a.html", "a content")]), &Env::test(), &PageGeneratorBagImpl::new()).unwrap();
+
+        assert_eq!(fs::read_to_string(output.join("a.html")).unwrap(), "tampered a");
+        assert!(!output.join("b.html").exists());
+    }
+
+    #[test]
+    fn prune_directories_left_empty_by_stale_outputs() {
+        let test_folder = TmpTestFolder::new().unwrap();
+        let output = test_folder.get_path().join("output");
+        let cache_path = test_folder.get_path().join("cache.json");
+
+        let writer = FsWriter::new_with_cache(output.clone(), BuildCache::load(cache_path.clone(), "build".to_string()).unwrap()).unwrap();
+        writer
+            .write(
+                &bundle(&[("d/a.html", "a content"), ("d/e/f/b.html", "b content"), ("g/c.html", "c content")]),
+                &Env::test(),
+                &PageGeneratorBagImpl::new(),
+            )
+            .unwrap();
+        writer.write(&bundle(&[("d/a.html", "a content")]), &Env::test(), &PageGeneratorBagImpl::new()).unwrap();
+
+        assert!(output.join("d").join("a.html").exists());
+        assert!(!output.join("d").join("e").exists());
+        assert!(!output.join("g").exists());
+        assert!(output.exists());
+    }
+
+    #[test]
+    fn rewrite_all_pages_when_build_digest_changes() {
+        let test_folder = TmpTestFolder::new().unwrap();
+        let output = test_folder.get_path().join("output");
+        let cache_path = test_folder.get_path().join("cache.json");
+
+        let writer = FsWriter::new_with_cache(output.clone(), BuildCache::load(cache_path.clone(), "build 1".to_string()).unwrap()).unwrap();
+        writer.write(&bundle(&[("a.html", "a content")]), &Env::test(), &PageGeneratorBagImpl::new()).unwrap();
+        fs::write(output.join("a.html"), "tampered a").unwrap();
+
+        let writer = FsWriter::new_with_cache(output.clone(), BuildCache::load(cache_path, "build 2".to_string()).unwrap()).unwrap();
+        writer.write(&bundle(&[("a.html", "a content")]), &Env::test(), &PageGeneratorBagImpl::new()).unwrap();
+
+        assert_eq!(fs::read_to_string(output.join("a.html")).unwrap(), "a content");
+    }
+}
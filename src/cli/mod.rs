@@ -1,7 +1,16 @@
+mod build_cache;
 mod execute;
+mod execute_test;
 mod fs_writer;
+mod fs_writer_test;
+mod serve;
+mod serve_test;
+mod watch;
+mod watch_test;
 mod writer;
 
+pub use self::build_cache::*;
 pub use self::execute::*;
 pub use self::fs_writer::*;
+pub use self::serve::*;
 pub use self::writer::*;
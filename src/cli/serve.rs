@@ -0,0 +1,176 @@
+use crate::pages::PageIndex;
+use crate::pages_error::PagesError;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use tiny_http::{Header, Response, Server};
+
+const BUILD_VERSION_URI: &str = "/_elepages/build_version";
+const NOT_FOUND_PAGE: &str = "404.html";
+
+#[derive(Clone)]
+pub struct PreviewServer {
+    output_dir: PathBuf,
+    build_version: Arc<AtomicU64>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PreviewResponse {
+    pub status: u16,
+    pub content_type: String,
+    pub location: Option<String>,
+    pub content: Vec<u8>,
+}
+
+impl PreviewServer {
+    pub fn new(output_dir: PathBuf) -> Self {
+        Self {
+            output_dir,
+            build_version: Default::default(),
+        }
+    }
+
+    // html pages served before this call are reloaded by the injected script
+    pub fn notify_build(&self) {
+        self.build_version.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn start(&self, port: u16) -> anyhow::Result<()> {
+        let server = Server::http(("127.0.0.1", port)).map_err(|e| PagesError::Exec(format!("cannot listen on port {} ({})", port, e)))?;
+        let preview_server = self.clone();
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                let response = match preview_server.respond(request.url()) {
+                    Ok(r) => r,
+                    Err(err) => PreviewResponse {
+                        status: 500,
+                        content_type: "text/plain; charset=utf-8".to_string(),
+                        location: None,
+                        content: err.to_string().into_bytes(),
+                    },
+                };
+                let mut http_response = Response::from_data(response.content)
+                    .with_status_code(response.status)
+                    .with_header(Header::from_bytes("Content-Type", response.content_type).unwrap());
+                if let Some(location) = response.location {
+                    http_response = http_response.with_header(Header::from_bytes("Location", location).unwrap());
+                }
+                // client disconnections are not server errors
+                let _ = request.respond(http_response);
+            }
+        });
+        Ok(())
+    }
+
+    pub fn respond(&self, url: &str) -> anyhow::Result<PreviewResponse> {
+        let uri = urlencoding::decode(url.split(['?', '#']).next().unwrap_or("/"))?.to_string();
+        if uri == BUILD_VERSION_URI {
+            return Ok(PreviewResponse {
+                status: 200,
+                content_type: "text/plain; charset=utf-8".to_string(),
+                location: None,
+                content: self.build_version.load(Ordering::SeqCst).to_string().into_bytes(),
+            });
+        }
+
+        let candidates = PageIndex::paths_from_uri(&uri);
+        // paths escaping output dir are never served
+        if candidates.iter().flatten().any(|p| Path::new(p).components().any(|c| !matches!(c, Component::Normal(_)))) {
+            return self.not_found();
+        }
+        for candidate in candidates {
+            let file_path = self.file_path(&candidate);
+            if file_path.is_file() {
+                return self.file_response(200, &file_path);
+            }
+            if file_path.is_dir() && !uri.ends_with('/') {
+                return Ok(PreviewResponse {
+                    status: 301,
+                    content_type: "text/plain; charset=utf-8".to_string(),
+                    location: Some(format!("{}/", uri)),
+                    content: vec![],
+                });
+            }
+        }
+        self.not_found()
+    }
+
+    fn not_found(&self) -> anyhow::Result<PreviewResponse> {
+        let not_found_path = self.output_dir.join(NOT_FOUND_PAGE);
+        if not_found_path.is_file() {
+            return self.file_response(404, &not_found_path);
+        }
+        Ok(PreviewResponse {
+            status: 404,
+            content_type: "text/plain; charset=utf-8".to_string(),
+            location: None,
+            content: b"page not found".to_vec(),
+        })
+    }
+
+    fn file_path(&self, page_path: &[String]) -> PathBuf {
+        let mut file_path = self.output_dir.clone();
+        for p in page_path {
+            file_path.push(p);
+        }
+        file_path
+    }
+
+    fn file_response(&self, status: u16, file_path: &Path) -> anyhow::Result<PreviewResponse> {
+        let content_type = content_type(file_path);
+        let mut content = fs::read(file_path)?;
+        if content_type.starts_with("text/html") {
+            content = self.inject_reload_script(content);
+        }
+        Ok(PreviewResponse {
+            status,
+            content_type: content_type.to_string(),
+            location: None,
+            content,
+        })
+    }
+
+    fn inject_reload_script(&self, content: Vec<u8>) -> Vec<u8> {
+        let script = format!(
+            r#"<script>
+(function () {{
+  var version = "{}";
+  setInterval(function () {{
+    fetch("{}").then(function (r) {{ return r.text(); }}).then(function (v) {{
+      if (v !== version) {{ location.reload(); }}
+    }}).catch(function () {{}});
+  }}, 1000);
+}})();
+</script>
+"#,
+            self.build_version.load(Ordering::SeqCst),
+            BUILD_VERSION_URI
+        );
+        let html = String::from_utf8_lossy(&content);
+        match html.rfind("</body>") {
+            Some(i) => format!("{}{}{}", &html[..i], script, &html[i..]).into_bytes(),
+            None => format!("{}{}", html, script).into_bytes(),
+        }
+    }
+}
+
+fn content_type(file_path: &Path) -> &'static str {
+    match file_path.extension().map(|e| e.to_string_lossy().to_lowercase()).as_deref() {
+        Some("html") | Some("htm") => "text/html; charset=utf-8",
+        Some("css") => "text/css; charset=utf-8",
+        Some("js") => "application/javascript; charset=utf-8",
+        Some("json") => "application/json",
+        Some("xml") => "application/xml",
+        Some("txt") => "text/plain; charset=utf-8",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("ico") => "image/x-icon",
+        Some("woff") => "font/woff",
+        Some("woff2") => "font/woff2",
+        _ => "application/octet-stream",
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use crate::cli::PreviewServer;
+    use rustassert::fs::TmpTestFolder;
+    use std::fs;
+
+    fn output_folder() -> TmpTestFolder {
+        let test_folder = TmpTestFolder::new().unwrap();
+        fs::create_dir_all(test_folder.get_path().join("dir")).unwrap();
+        fs::write(test_folder.get_path().join("index.html"), "<html><body>root</body></html>").unwrap();
+        fs::write(test_folder.get_path().join("dir").join("index.html"), "dir index").unwrap();
+        fs::write(test_folder.get_path().join("dir").join("f.css"), "css content").unwrap();
+        test_folder
+    }
+
+    #[test]
+    fn serve_pages_from_their_uri() {
+        let test_folder = output_folder();
+        let server = PreviewServer::new(test_folder.get_path().to_path_buf());
+
+        let root = server.respond("/").unwrap();
+        assert_eq!(root.status, 200);
+        assert_eq!(root.content_type, "text/html; charset=utf-8");
+        let root_content = String::from_utf8(root.content).unwrap();
+        assert!(root_content.starts_with("<html><body>root<script>"));
+        assert!(root_content.ends_with("</script>\n</body></html>"));
+
+        let dir = server.respond("/dir/?q=1").unwrap();
+        assert_eq!(dir.status, 200);
+        assert!(String::from_utf8(dir.content).unwrap().starts_with("dir index<script>"));
+
+        let css = server.respond("/dir/f.css").unwrap();
+        assert_eq!(css.status, 200);
+        assert_eq!(css.content_type, "text/css; charset=utf-8");
+        assert_eq!(css.content, b"css content".to_vec());
+
+        let redirect = server.respond("/dir").unwrap();
+        assert_eq!(redirect.status, 301);
+        assert_eq!(redirect.location, Some("/dir/".to_string()));
+    }
+
+    #[test]
+    fn serve_not_found_page_for_missing_pages() {
+        let test_folder = output_folder();
+        let server = PreviewServer::new(test_folder.get_path().to_path_buf());
+
+        let missing = server.respond("/missing.html").unwrap();
+        assert_eq!(missing.status, 404);
+        assert_eq!(missing.content, b"page not found".to_vec());
+
+        fs::write(test_folder.get_path().join("404.html"), "custom not found").unwrap();
+        let missing = server.respond("/dir/../../index.html").unwrap();
+        assert_eq!(missing.status, 404);
+        assert!(String::from_utf8(missing.content).unwrap().starts_with("custom not found<script>"));
+    }
+
+    #[test]
+    fn increment_build_version_after_each_build() {
+        let test_folder = output_folder();
+        let server = PreviewServer::new(test_folder.get_path().to_path_buf());
+
+        assert_eq!(server.respond("/_elepages/build_version").unwrap().content, b"0".to_vec());
+        assert!(String::from_utf8(server.respond("/").unwrap().content).unwrap().contains("var version = \"0\";"));
+
+        server.notify_build();
+        assert_eq!(server.respond("/_elepages/build_version").unwrap().content, b"1".to_vec());
+        assert!(String::from_utf8(server.respond("/").unwrap().content).unwrap().contains("var version = \"1\";"));
+    }
+}
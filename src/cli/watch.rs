@@ -0,0 +1,180 @@
+use crate::cli::{Execution, Executor};
+use crate::config::ValueError;
+use crate::stages::Stage;
+use crate::utilities::{is_ignored, IGNORE_FILE_NAME};
+use notify::{watcher, DebouncedEvent, RecursiveMode, Watcher};
+use std::env::current_dir;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::sync::Arc;
+use std::time::Duration;
+
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(200);
+
+impl Executor {
+    // builds once then rebuilds each time a watched source, template or config file changes
+    // build errors are printed and do not stop watching
+    pub fn watch(&mut self, on_execution: &dyn Fn(&Execution, &Executor)) -> anyhow::Result<()> {
+        let mut stage = self.make_stage()?;
+        self.watch_build(&stage, on_execution);
+
+        let (tx, rx) = channel();
+        let mut fs_watcher = watcher(tx, DEBOUNCE_DELAY)?;
+        let mut watched_paths: Vec<PathBuf> = vec![];
+        self.update_watches(&mut fs_watcher, &mut watched_paths, self.watch_candidates(&stage))?;
+        // config parent dir is watched instead of the file itself as editors may replace it on save
+        if let Some(config_dir) = self.config_path.as_ref().and_then(|p| p.parent()) {
+            if !watched_paths.iter().any(|w| config_dir.starts_with(w)) {
+                fs_watcher.watch(config_dir, RecursiveMode::NonRecursive)?;
+                self.env.print_v("watch", &format!("watching {}", self.config_path.as_ref().unwrap().to_string_lossy()));
+            }
+        }
+
+        let mut filter = WatchFilter {
+            watched_paths,
+            unwatched_paths: stage.unwatched_paths(),
+            output_dir: self.output_dir.clone(),
+            cache_path: self.cache_path.clone(),
+            config_path: self.config_path.clone(),
+            use_gitignore: self.env.use_gitignore(),
+        };
+        loop {
+            let changed_paths = next_changes(&rx, DEBOUNCE_DELAY, &filter)?;
+            if changed_paths.is_empty() {
+                continue;
+            }
+            for path in &changed_paths {
+                self.env.print_vv("watch", &format!("{} changed", path.to_string_lossy()));
+            }
+            if let Some(config_path) = &self.config_path {
+                if changed_paths.contains(config_path) {
+                    match self.reload_config() {
+                        Ok(new_stage) => stage = new_stage,
+                        Err(err) => {
+                            self.env.print_error("watch", &format!("config reload failed: {}", err));
+                            continue;
+                        }
+                    }
+                    // the new config may read templates or assets from other dirs
+                    self.update_watches(&mut fs_watcher, &mut filter.watched_paths, self.watch_candidates(&stage))?;
+                    filter.unwatched_paths = stage.unwatched_paths();
+                }
+            }
+            self.watch_build(&stage, on_execution);
+        }
+    }
+
+    // existing loader and stage paths, paths under another candidate being covered by it
+    pub(crate) fn watch_candidates(&self, stage: &Arc<dyn Stage>) -> Vec<PathBuf> {
+        let mut candidates = self.loader.watched_paths();
+        candidates.extend(stage.watched_paths());
+        let mut result: Vec<PathBuf> = vec![];
+        for path in candidates {
+            if !path.exists() || result.iter().any(|w| path.starts_with(w)) {
+                continue;
+            }
+            result.push(path);
+        }
+        result
+    }
+
+    // watches new candidates and stops watching paths that are no longer candidates
+    fn update_watches<W: Watcher>(&self, fs_watcher: &mut W, watched_paths: &mut Vec<PathBuf>, candidates: Vec<PathBuf>) -> anyhow::Result<()> {
+        let config_dir = self.config_path.as_ref().and_then(|p| p.parent());
+        for path in watched_paths.iter().filter(|w| !candidates.contains(w)) {
+            // the config dir stays watched
+            if matches!(config_dir, Some(config_dir) if config_dir.starts_with(path)) {
+                continue;
+            }
+            // the path may have been removed along with its watch
+            let _ = fs_watcher.unwatch(path);
+            self.env.print_v("watch", &format!("stopped watching {}", path.to_string_lossy()));
+        }
+        for path in candidates.iter().filter(|c| !watched_paths.contains(c)) {
+            fs_watcher.watch(path, RecursiveMode::Recursive)?;
+            self.env.print_v("watch", &format!("watching {}", path.to_string_lossy()));
+        }
+        *watched_paths = candidates;
+        Ok(())
+    }
+
+    fn watch_build(&self, stage: &Arc<dyn Stage>, on_execution: &dyn Fn(&Execution, &Executor)) {
+        match self.execute_stage(Some(stage)) {
+            Ok(execution) => on_execution(&execution, self),
+            Err(err) => self.env.print_error("watch", &format!("build failed: {}", err)),
+        }
+    }
+
+    pub(crate) fn reload_config(&mut self) -> anyhow::Result<Arc<dyn Stage>> {
+        let config_path = self.config_path.clone().unwrap();
+        self.env.print_v("watch", &format!("reloading config {}", config_path.to_string_lossy()));
+        // mounts are only read at startup
+        let site_config = Executor::read_config(&current_dir()?, &config_path)?;
+        let taxonomies = site_config.taxonomies().to_vec();
+        let (_, stage_config) = site_config.into_parts();
+        let stage = self.maker.make(None, &stage_config, &self.env).map_err(|e| ValueError::prefix(e, "stages"))?;
+        Executor::set_taxonomies(&self.env, &taxonomies);
+        self.writer = Executor::make_writer(&self.output_dir, &self.cache_path, &stage_config)?;
+        self.stage_config = stage_config;
+        Ok(stage)
+    }
+}
+
+// decides which file events trigger a rebuild
+pub(crate) struct WatchFilter {
+    pub(crate) watched_paths: Vec<PathBuf>,
+    pub(crate) unwatched_paths: Vec<PathBuf>,
+    pub(crate) output_dir: PathBuf,
+    pub(crate) cache_path: Option<PathBuf>,
+    pub(crate) config_path: Option<PathBuf>,
+    pub(crate) use_gitignore: bool,
+}
+
+impl WatchFilter {
+    pub(crate) fn is_relevant_change(&self, path: &Path) -> bool {
+        // outputs, cache file and stage generated files are written by the build itself
+        if path.starts_with(&self.output_dir) || Some(path) == self.cache_path.as_deref() || self.unwatched_paths.iter().any(|u| path.starts_with(u)) {
+            return false;
+        }
+        if Some(path) == self.config_path.as_deref() {
+            return true;
+        }
+        let (watched_path, relative_path) = match self.watched_paths.iter().find_map(|w| path.strip_prefix(w).ok().map(|r| (w, r))) {
+            None => return false,
+            Some(v) => v,
+        };
+        // ignore files changes the loaded files
+        let file_name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+        if file_name == IGNORE_FILE_NAME || (self.use_gitignore && file_name == ".gitignore") {
+            return true;
+        }
+        // hidden files and dirs (.git, editor swap files...) and ignored files under watched paths are skipped as when loading
+        !relative_path.components().any(|c| c.as_os_str().to_string_lossy().starts_with('.')) && !is_ignored(watched_path, path, self.use_gitignore)
+    }
+}
+
+// blocks until some relevant path changes, then drains events following each other by less than delay
+pub(crate) fn next_changes(rx: &Receiver<DebouncedEvent>, delay: Duration, filter: &WatchFilter) -> anyhow::Result<Vec<PathBuf>> {
+    let mut changed_paths = vec![];
+    let mut event = rx.recv()?;
+    loop {
+        for path in event_paths(event) {
+            if filter.is_relevant_change(&path) && !changed_paths.contains(&path) {
+                changed_paths.push(path);
+            }
+        }
+        event = match rx.recv_timeout(delay) {
+            Ok(e) => e,
+            Err(RecvTimeoutError::Timeout) => return Ok(changed_paths),
+            Err(err) => return Err(err.into()),
+        };
+    }
+}
+
+fn event_paths(event: DebouncedEvent) -> Vec<PathBuf> {
+    match event {
+        DebouncedEvent::Create(p) | DebouncedEvent::Write(p) | DebouncedEvent::Chmod(p) | DebouncedEvent::Remove(p) => vec![p],
+        DebouncedEvent::Rename(from, to) => vec![from, to],
+        _ => vec![],
+    }
+}
@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod tests {
+    use crate::cli::watch::{next_changes, WatchFilter};
+    use crate::cli::{Executor, ExecutorParams};
+    use crate::commands::NpmRunner;
+    use crate::pages::Env;
+    use crate::stages::{HbsStage, Stage};
+    use notify::DebouncedEvent;
+    use rustassert::fs::{FileNode, TmpTestFolder};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    #[derive(Debug)]
+    struct NoNpmRunner;
+
+    impl NpmRunner for NoNpmRunner {
+        fn install(&self, _: &Path, _: &Env) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn run(&self, _: &Path, _: &str, _: &Env) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn file(name: &str, content: &str) -> FileNode {
+        FileNode::File {
+            name: name.to_string(),
+            content: content.as_bytes().to_vec(),
+            open_options: None,
+        }
+    }
+
+    fn filter(root: &Path, unwatched_paths: Vec<PathBuf>) -> WatchFilter {
+        WatchFilter {
+            watched_paths: vec![root.join("src"), root.join("tpl")],
+            unwatched_paths,
+            output_dir: root.join("out"),
+            cache_path: Some(root.join("cache.json")),
+            config_path: Some(root.join("pages.yaml")),
+            use_gitignore: false,
+        }
+    }
+
+    #[test]
+    fn skip_outputs_hidden_ignored_and_stage_generated_files() {
+        let test_folder = TmpTestFolder::new().unwrap();
+        test_folder
+            .write(&FileNode::Dir {
+                name: "src".to_string(),
+                sub: vec![
+                    file(".elepagesignore", "drafts/\n*.tmp\n"),
+                    file(".gitignore", "private.md\n"),
+                    file("private.md", ""),
+                    FileNode::Dir {
+                        name: "drafts".to_string(),
+                        sub: vec![file("d.md", "")],
+                    },
+                ],
+            })
+            .unwrap();
+        test_folder
+            .write(&FileNode::Dir {
+                name: "tpl".to_string(),
+                sub: vec![file("package.json", r#"{"scripts": {"build": "b"}, "buildOutputDir": "dist"}"#)],
+            })
+            .unwrap();
+        let root = test_folder.get_path();
+        let stage = HbsStage::new_with_npm_runner("hbs".to_string(), root.join("tpl"), Box::new(NoNpmRunner));
+        assert_eq!(stage.unwatched_paths(), vec![root.join("tpl/node_modules"), root.join("tpl/package-lock.json"), root.join("tpl/dist")]);
+        let filter = filter(root, stage.unwatched_paths());
+
+        assert!(filter.is_relevant_change(&root.join("src/a.md")));
+        assert!(filter.is_relevant_change(&root.join("tpl/page.hbs")));
+        assert!(filter.is_relevant_change(&root.join("pages.yaml")));
+        assert!(filter.is_relevant_change(&root.join("src/.elepagesignore")));
+        // .gitignore files only apply with use_gitignore
+        assert!(filter.is_relevant_change(&root.join("src/private.md")));
+        assert!(!filter.is_relevant_change(&root.join("src/.gitignore")));
+
+        assert!(!filter.is_relevant_change(&root.join("out/a.html")));
+        assert!(!filter.is_relevant_change(&root.join("cache.json")));
+        assert!(!filter.is_relevant_change(&root.join("other.yaml")));
+        assert!(!filter.is_relevant_change(&root.join("src/.git/index")));
+        assert!(!filter.is_relevant_change(&root.join("src/drafts/d.md")));
+        assert!(!filter.is_relevant_change(&root.join("src/a.md.tmp")));
+        assert!(!filter.is_relevant_change(&root.join("tpl/node_modules/lib/index.js")));
+        assert!(!filter.is_relevant_change(&root.join("tpl/package-lock.json")));
+        assert!(!filter.is_relevant_change(&root.join("tpl/dist/page.hbs")));
+
+        let gitignore_filter = WatchFilter { use_gitignore: true, ..filter };
+        assert!(!gitignore_filter.is_relevant_change(&root.join("src/private.md")));
+        assert!(gitignore_filter.is_relevant_change(&root.join("src/.gitignore")));
+    }
+
+    #[test]
+    fn collect_relevant_changes_of_successive_events() {
+        let root = PathBuf::from("/site");
+        let filter = filter(&root, vec![]);
+        let (tx, rx) = channel();
+        tx.send(DebouncedEvent::Write(root.join("src/a.md"))).unwrap();
+        tx.send(DebouncedEvent::Write(root.join("out/a.html"))).unwrap();
+        tx.send(DebouncedEvent::Create(root.join("src/a.md"))).unwrap();
+        tx.send(DebouncedEvent::Rename(root.join("src/b.md"), root.join("src/c.md"))).unwrap();
+        tx.send(DebouncedEvent::Rescan).unwrap();
+
+        assert_eq!(
+            next_changes(&rx, Duration::from_millis(20), &filter).unwrap(),
+            vec![root.join("src/a.md"), root.join("src/b.md"), root.join("src/c.md")]
+        );
+
+        // output only events give no change
+        tx.send(DebouncedEvent::Write(root.join("out/a.html"))).unwrap();
+        assert!(next_changes(&rx, Duration::from_millis(20), &filter).unwrap().is_empty());
+
+        drop(tx);
+        assert!(next_changes(&rx, Duration::from_millis(20), &filter).is_err());
+    }
+    #[test]
+    fn reload_taxonomies_with_config() {
+        let test_folder = TmpTestFolder::new().unwrap();
+        test_folder
+            .write(&FileNode::Dir {
+                name: "src".to_string(),
+                sub: vec![file("a.md", "a content")],
+            })
+            .unwrap();
+        let root = test_folder.get_path();
+        let config_path = root.join("pages.yaml");
+        fs::write(&config_path, "{taxonomies: [categories], stages: md}").unwrap();
+        let mut executor = Executor::new(ExecutorParams {
+            input_dir: Some(root.join("src")),
+            output_dir: Some(root.join("out")),
+            config_path: Some(config_path.clone()),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(executor.env.taxonomies(), vec!["categories"]);
+
+        fs::write(&config_path, "{taxonomies: [difficulty], stages: md}").unwrap();
+        executor.reload_config().unwrap();
+        assert_eq!(executor.env.taxonomies(), vec!["difficulty"]);
+
+        fs::write(&config_path, "md").unwrap();
+        executor.reload_config().unwrap();
+        assert!(executor.env.taxonomies().is_empty());
+    }
+
+    #[test]
+    fn watch_template_dirs_of_reloaded_config() {
+        let test_folder = TmpTestFolder::new().unwrap();
+        for dir in &["src", "tpl1", "tpl2"] {
+            test_folder
+                .write(&FileNode::Dir {
+                    name: dir.to_string(),
+                    sub: vec![file("page.hbs", "{{page_content}}")],
+                })
+                .unwrap();
+        }
+        let root = test_folder.get_path();
+        let config_path = root.join("pages.yaml");
+        let config = |tpl: &str| format!("[md, {{type: handlebars, config: '{}'}}]", root.join(tpl).to_string_lossy());
+        fs::write(&config_path, config("tpl1")).unwrap();
+        let mut executor = Executor::new(ExecutorParams {
+            input_dir: Some(root.join("src")),
+            output_dir: Some(root.join("out")),
+            config_path: Some(config_path.clone()),
+            ..Default::default()
+        })
+        .unwrap();
+        let stage = executor.make_stage().unwrap();
+        assert_eq!(executor.watch_candidates(&stage), vec![root.join("src"), root.join("tpl1")]);
+
+        fs::write(&config_path, config("tpl2")).unwrap();
+        let stage = executor.reload_config().unwrap();
+        assert_eq!(executor.watch_candidates(&stage), vec![root.join("src"), root.join("tpl2")]);
+    }
+}
@@ -1,4 +1,7 @@
+mod value_de;
+mod value_de_test;
 mod values;
 mod values_test;
 
+pub use self::value_de::*;
 pub use self::values::*;
@@ -0,0 +1,135 @@
+use crate::config::Value;
+use serde::de::value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer};
+use serde::de::{DeserializeOwned, IntoDeserializer, Visitor};
+use serde::{de, forward_to_deserialize_any};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+// value deserialization error located by the path of the faulty field, e.g. stages[3].config.remote
+#[derive(Debug, PartialEq)]
+pub struct ValueError {
+    pub path: String,
+    pub message: String,
+}
+
+impl ValueError {
+    // prefixes the path of value errors, other errors are located at prefix
+    pub fn prefix(err: anyhow::Error, prefix: &str) -> anyhow::Error {
+        match err.downcast::<ValueError>() {
+            Ok(e) => ValueError {
+                path: if e.path.is_empty() {
+                    prefix.to_string()
+                } else if e.path.starts_with('[') {
+                    format!("{}{}", prefix, e.path)
+                } else {
+                    format!("{}.{}", prefix, e.path)
+                },
+                message: e.message,
+            }
+            .into(),
+            Err(err) => ValueError {
+                path: prefix.to_string(),
+                message: format!("{:#}", err),
+            }
+            .into(),
+        }
+    }
+}
+
+impl Display for ValueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            return f.write_str(&self.message);
+        }
+        f.write_fmt(format_args!("{}: {}", self.path, self.message))
+    }
+}
+
+impl Error for ValueError {}
+
+impl de::Error for ValueError {
+    fn custom<T: Display>(msg: T) -> Self {
+        ValueError {
+            path: String::new(),
+            message: msg.to_string(),
+        }
+    }
+}
+
+// deserializes a config value into any serde type, absent values are read as empty maps so that struct defaults apply
+pub fn from_value<T: DeserializeOwned>(value: Value) -> anyhow::Result<T> {
+    serde_path_to_error::deserialize(value).map_err(|e| {
+        let path = e.path().to_string();
+        let inner = e.into_inner();
+        ValueError {
+            path: if path == "." { inner.path } else { path },
+            message: inner.message,
+        }
+        .into()
+    })
+}
+
+impl<'de> IntoDeserializer<'de, ValueError> for Value {
+    type Deserializer = Value;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Value {
+    type Error = ValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Value::Null | Value::None => visitor.visit_unit(),
+            Value::String(s) => visitor.visit_string(s),
+            Value::I32(i) => visitor.visit_i32(i),
+            Value::I64(i) => visitor.visit_i64(i),
+            Value::U64(u) => visitor.visit_u64(u),
+            Value::F64(f) => visitor.visit_f64(f),
+            Value::Bool(b) => visitor.visit_bool(b),
+            Value::Map(m) => {
+                let mut map = MapDeserializer::new(m.into_iter());
+                let result = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(result)
+            }
+            Value::Vec(v) => {
+                let mut seq = SeqDeserializer::new(v.into_iter());
+                let result = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(result)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Value::Null | Value::None => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(self, _: &'static str, _: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Value::None => Value::Map(HashMap::new()).deserialize_any(visitor),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _: &'static str, _: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Value::String(s) => visitor.visit_enum(s.into_deserializer()),
+            Value::Map(m) => visitor.visit_enum(MapAccessDeserializer::new(MapDeserializer::new(m.into_iter()))),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map identifier ignored_any
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::{from_value, Value, ValueError};
+    use crate::maker::{Maker, StageValue};
+    use crate::pages::Env;
+    use indoc::indoc;
+    use serde::Deserialize;
+    use std::array::IntoIter;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Default, Deserialize, PartialEq)]
+    #[serde(default, deny_unknown_fields)]
+    struct RemoteConfig {
+        url: String,
+        depth: Option<u64>,
+    }
+
+    #[derive(Debug, Default, Deserialize, PartialEq)]
+    #[serde(default, deny_unknown_fields)]
+    struct TestConfig {
+        strict: bool,
+        ratio: f64,
+        tags: Vec<String>,
+        remotes: Vec<RemoteConfig>,
+        labels: HashMap<String, String>,
+    }
+
+    #[test]
+    fn deserialize_typed_config_from_value() {
+        let value: Value = serde_yaml::from_str(indoc! {"
+            ---
+            strict: true
+            ratio: 1.5
+            tags: [t1, t2]
+            remotes:
+              - url: https://remote
+                depth: 3
+            labels:
+              k: v
+        "})
+        .unwrap();
+
+        assert_eq!(
+            from_value::<TestConfig>(value).unwrap(),
+            TestConfig {
+                strict: true,
+                ratio: 1.5,
+                tags: vec!["t1".to_string(), "t2".to_string()],
+                remotes: vec![RemoteConfig {
+                    url: "https://remote".to_string(),
+                    depth: Some(3),
+                }],
+                labels: IntoIter::new([("k".to_string(), "v".to_string())]).collect(),
+            }
+        );
+        assert_eq!(from_value::<TestConfig>(Value::None).unwrap(), TestConfig::default());
+    }
+
+    #[test]
+    fn return_err_with_path_of_invalid_field() {
+        let value: Value = serde_yaml::from_str("{remotes: [{url: a}, {url: b, depth: deep}]}").unwrap();
+        let err = from_value::<TestConfig>(value).err().unwrap();
+        assert_eq!(err.to_string(), "remotes[1].depth: invalid type: string \"deep\", expected u64");
+
+        let value: Value = serde_yaml::from_str("{strict: true, remote: a}").unwrap();
+        let err = from_value::<TestConfig>(value).err().unwrap();
+        assert_eq!(err.downcast_ref::<ValueError>().unwrap().path, "remote");
+    }
+
+    #[test]
+    fn prefix_err_with_stage_location() {
+        let config: StageValue = serde_yaml::from_str(indoc! {"
+            ---
+            - md
+            - append:
+                type: handlebars
+                config:
+                  remote: 3
+        "})
+        .unwrap();
+
+        let err = Maker::default().make(None, &config, &Env::test()).err().unwrap();
+        assert_eq!(err.to_string(), "[1].append.config.remote: invalid type: integer `3`, expected a string");
+
+        // stage makers and selectors errors are located as well
+        for (stage, expected) in &[
+            ("{name: renamed, stage: unknown}", "[1].stage: stage unknown not found"),
+            ("{type: handlebars}", "[1].config: cannot find configuration"),
+            ("{copy: {}, dest: a}", "[1].copy: cannot parse selector"),
+        ] {
+            let config: StageValue = serde_yaml::from_str(&format!("[md, {}]", stage)).unwrap();
+            let err = Maker::default().make(None, &config, &Env::test()).err().unwrap();
+            assert_eq!(&err.to_string(), expected);
+        }
+    }
+}
@@ -3,12 +3,18 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+// untagged variants are tried in order : explicit nulls are parsed as Null (None being the absent value)
+// and numbers as the first variant able to hold them
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(untagged)]
 pub enum Value {
+    Null,
     None,
     String(String),
     I32(i32),
+    I64(i64),
+    U64(u64),
+    F64(f64),
     Bool(bool),
     Map(HashMap<String, Value>),
     Vec(Vec<Value>),
@@ -33,6 +39,59 @@ impl FromValue for String {
     }
 }
 
+impl FromValue for bool {
+    fn from_value(value: Value) -> anyhow::Result<Self> {
+        if let Value::Bool(b) = value {
+            return Ok(b);
+        }
+        Err(PagesError::ValueParsing("expecting Value::Bool".to_string()).into())
+    }
+}
+
+impl FromValue for i64 {
+    fn from_value(value: Value) -> anyhow::Result<Self> {
+        match value {
+            Value::I32(i) => Ok(i as i64),
+            Value::I64(i) => Ok(i),
+            Value::U64(u) if u <= i64::MAX as u64 => Ok(u as i64),
+            _ => Err(PagesError::ValueParsing("expecting an integer Value".to_string()).into()),
+        }
+    }
+}
+
+impl FromValue for u64 {
+    fn from_value(value: Value) -> anyhow::Result<Self> {
+        match value {
+            Value::I32(i) if i >= 0 => Ok(i as u64),
+            Value::I64(i) if i >= 0 => Ok(i as u64),
+            Value::U64(u) => Ok(u),
+            _ => Err(PagesError::ValueParsing("expecting a positive integer Value".to_string()).into()),
+        }
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(value: Value) -> anyhow::Result<Self> {
+        match value {
+            Value::I32(i) => Ok(i as f64),
+            Value::I64(i) => Ok(i as f64),
+            Value::U64(u) => Ok(u as f64),
+            Value::F64(f) => Ok(f),
+            _ => Err(PagesError::ValueParsing("expecting a number Value".to_string()).into()),
+        }
+    }
+}
+
+// null and absent values are read as None
+impl<T: FromValue> FromValue for Option<T> {
+    fn from_value(value: Value) -> anyhow::Result<Self> {
+        match value {
+            Value::Null | Value::None => Ok(None),
+            _ => Ok(Some(T::from_value(value)?)),
+        }
+    }
+}
+
 impl FromValue for PathBuf {
     fn from_value(value: Value) -> anyhow::Result<Self> {
         if let Value::String(s) = value {
@@ -2,6 +2,10 @@
 mod tests {
     use crate::config::{FromValue, Value};
     use crate::pages_error::PagesError;
+    use indoc::indoc;
+    use std::array::IntoIter;
+    use std::collections::HashMap;
+    use std::iter::FromIterator;
 
     #[test]
     fn string_from_value() {
@@ -31,4 +35,47 @@ mod tests {
             PagesError::ValueParsing("expecting Value::String".to_string())
         );
     }
+
+    #[test]
+    fn parse_numbers_and_null_into_matching_variants() {
+        let value: Value = serde_yaml::from_str(indoc! {"
+            small: 2
+            negative: -3000000000
+            large: 18000000000000000000
+            weight: 2.5
+            nothing: null
+        "})
+        .unwrap();
+        assert_eq!(
+            value,
+            Value::Map(HashMap::from_iter(IntoIter::new([
+                ("small".to_string(), Value::I32(2)),
+                ("negative".to_string(), Value::I64(-3000000000)),
+                ("large".to_string(), Value::U64(18000000000000000000)),
+                ("weight".to_string(), Value::F64(2.5)),
+                ("nothing".to_string(), Value::Null),
+            ])))
+        );
+        assert_eq!(
+            serde_json::to_value(&value).unwrap(),
+            serde_json::json!({"small": 2, "negative": -3000000000i64, "large": 18000000000000000000u64, "weight": 2.5, "nothing": null})
+        );
+    }
+
+    #[test]
+    fn numbers_from_value() {
+        assert_eq!(i64::from_value(Value::I32(-2)).unwrap(), -2);
+        assert_eq!(i64::from_value(Value::U64(5)).unwrap(), 5);
+        assert!(i64::from_value(Value::U64(u64::MAX)).is_err());
+        assert_eq!(u64::from_value(Value::I64(3000000000)).unwrap(), 3000000000);
+        assert!(u64::from_value(Value::I32(-1)).is_err());
+        assert_eq!(f64::from_value(Value::I32(2)).unwrap(), 2.0);
+        assert_eq!(f64::from_value(Value::F64(2.5)).unwrap(), 2.5);
+        assert_eq!(
+            f64::from_value(Value::String("2.5".to_string())).unwrap_err().downcast::<PagesError>().unwrap(),
+            PagesError::ValueParsing("expecting a number Value".to_string())
+        );
+        assert_eq!(<Option<f64>>::from_value(Value::Null).unwrap(), None);
+        assert_eq!(<Option<f64>>::from_value(Value::F64(1.5)).unwrap(), Some(1.5));
+    }
 }
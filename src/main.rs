@@ -1,7 +1,9 @@
-use clap::{App, Arg, ArgMatches};
-use elepages::cli::{Execution, Executor, ExecutorParams};
+use clap::{App, Arg, ArgMatches, SubCommand};
+use elepages::cli::{Execution, Executor, ExecutorParams, PreviewServer};
 use elepages::config::Value;
+use elepages::maker::MountConfig;
 use elepages::pages::{Env, PrintLevel, PRINT_LEVEL_VVV};
+use elepages::remote::GitReference;
 use elepages::stages::ProcessingResult;
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -10,78 +12,211 @@ fn main() {
     let matches = App::new("Ele pages")
         .version("0.1")
         .about("Flexible static pages generator")
-        .arg(Arg::with_name("source").long("source").help("source directory to be parsed").takes_value(true))
-        .arg(
-            Arg::with_name("destination")
-                .long("destination")
-                .help("destination directory where output pages will be written")
-                .takes_value(true),
+        .args(&executor_args())
+        .subcommand(
+            SubCommand::with_name("serve")
+                .about("serves output pages on localhost, rebuilding and reloading them on changes")
+                .args(&executor_args())
+                .arg(
+                    Arg::with_name("port")
+                        .long("port")
+                        .help("local http port (default 8080)")
+                        .takes_value(true)
+                        // 0 would bind an ephemeral port while the printed url says 0
+                        .validator(|v| match v.parse::<u16>() {
+                            Ok(port) if port > 0 => Ok(()),
+                            _ => Err(format!("invalid port `{}`, expected a number between 1 and 65535", v)),
+                        }),
+                ),
         )
-        .arg(Arg::with_name("config").long("config").help("stages configuration file (yaml/json formats)").takes_value(true))
-        .arg(Arg::with_name("git_path").long("git-path").help("git metadata path").takes_value(true))
-        .arg(
-            Arg::with_name("handlebars_str_config")
-                .long("handlebars")
-                .help("handlebars template local path or remote git url")
-                .takes_value(true),
-        )
-        .arg(
-            Arg::with_name("handlebars_path_config")
-                .long("handlebars-path")
-                .help("handlebars path config. This is local template path or remote git relative folder path")
-                .takes_value(true),
-        )
-        .arg(
-            Arg::with_name("handlebars_remote_config")
-                .long("handlebars-remote")
-                .help("handlebars git remote config")
-                .takes_value(true),
-        )
-        .arg(
-            Arg::with_name("handlebars_remote_commit_config")
-                .long("handlebars-commit")
-                .help("handlebars git remote commit config")
-                .takes_value(true),
-        )
-        .arg(
-            Arg::with_name("handlebars_remote_tag_config")
-                .long("handlebars-tag")
-                .help("handlebars git remote tag config")
-                .takes_value(true),
-        )
-        .arg(
-            Arg::with_name("handlebars_remote_branch_config")
-                .long("handlebars-branch")
-                .help("handlebars git remote branch config")
-                .takes_value(true),
-        )
-        .arg(Arg::with_name("v").short("v").multiple(true).help("Sets the level of verbosity"))
         .get_matches();
+    let (matches, serve) = match matches.subcommand_matches("serve") {
+        Some(serve_matches) => (serve_matches, true),
+        None => (&matches, false),
+    };
 
     let params = ExecutorParams {
         input_dir: matches.value_of("source").map(PathBuf::from),
         output_dir: matches.value_of("destination").map(PathBuf::from),
         config_path: matches.value_of("config").map(PathBuf::from),
         git_repo_path_config: matches.value_of("git_path").map(|v| v.to_string()),
-        handlebars_config: make_handlebars_config(&matches),
+        handlebars_config: make_handlebars_config(matches),
         print_level: match matches.occurrences_of("v") {
             0 => None,
             1 => Some(PrintLevel::V),
             2 => Some(PrintLevel::VV),
             _ => Some(PrintLevel::VVV),
         },
+        cache_path: matches.value_of("cache").map(PathBuf::from),
+        source_reference: make_source_reference(matches),
+        use_gitignore: matches.is_present("gitignore"),
+        mounts: make_mounts(matches),
+        // validated by clap
+        content_cache_limit: matches.value_of("content_cache").and_then(content_cache_limit),
+        build_drafts: matches.is_present("drafts") || std::env::var("ELEPAGES_DRAFTS").map(|v| v == "1" || v == "true").unwrap_or(false),
+        build_future: matches.is_present("build_future"),
+        build_time: matches.value_of("build_time").map(|v| v.to_string()),
+        default_lang: matches.value_of("default_lang").map(|v| v.to_string()),
     };
 
-    let executor = Executor::new(params).unwrap();
+    let mut executor = Executor::new(params).unwrap();
     executor.env.print_vv("main", "program started");
+    if serve {
+        // validated by clap
+        let port = matches.value_of("port").and_then(|p| p.parse::<u16>().ok()).unwrap_or(8080);
+        let server = PreviewServer::new(executor.output_dir.clone());
+        if let Err(err) = server.start(port) {
+            executor.env.print_error("serve", &err.to_string());
+            std::process::exit(1);
+        }
+        println!("serving {} on http://localhost:{}/", executor.output_dir.to_string_lossy(), port);
+        let on_execution = |execution: &Execution, executor: &Executor| {
+            print_execution(execution, &executor.env);
+            server.notify_build();
+        };
+        if let Err(err) = executor.watch(&on_execution) {
+            panic!("{}", err)
+        }
+        return;
+    }
+    if matches.is_present("watch") {
+        if let Err(err) = executor.watch(&|execution, executor| print_execution(execution, &executor.env)) {
+            panic!("{}", err)
+        }
+        return;
+    }
     let execution_result = executor.execute();
     match execution_result {
         Err(err) => panic!("{}", err),
-        Ok(execution) => print_execution(execution, &executor.env),
+        Ok(execution) => print_execution(&execution, &executor.env),
     }
     executor.env.print_v("main", "finished !");
 }
 
+fn executor_args() -> Vec<Arg<'static, 'static>> {
+    vec![
+        Arg::with_name("source").long("source").help("source directory to be parsed").takes_value(true),
+        Arg::with_name("destination")
+            .long("destination")
+            .help("destination directory where output pages will be written")
+            .takes_value(true),
+        Arg::with_name("mount")
+            .long("mount")
+            .help("additional source directory mounted under a path prefix, as <dir>=<prefix> (e.g. ../shared/assets=/assets), the source directory being mounted on / ; without --source, a directory mounted on / replaces the current one")
+            .takes_value(true)
+            .validator(|v| match v.split_once('=') {
+                Some((dir, _)) if !dir.is_empty() => Ok(()),
+                _ => Err(format!("invalid mount `{}`, expected <dir>=<prefix>", v)),
+            })
+            .multiple(true)
+            .number_of_values(1),
+        Arg::with_name("config").long("config").help("stages configuration file (yaml/json formats)").takes_value(true),
+        Arg::with_name("git_path").long("git-path").help("git metadata path").takes_value(true),
+        Arg::with_name("handlebars_str_config")
+            .long("handlebars")
+            .help("handlebars template local path or remote git url")
+            .takes_value(true),
+        Arg::with_name("handlebars_path_config")
+            .long("handlebars-path")
+            .help("handlebars path config. This is local template path or remote git relative folder path")
+            .takes_value(true),
+        Arg::with_name("handlebars_remote_config")
+            .long("handlebars-remote")
+            .help("handlebars git remote config")
+            .takes_value(true),
+        Arg::with_name("handlebars_remote_commit_config")
+            .long("handlebars-commit")
+            .help("handlebars git remote commit config")
+            .takes_value(true),
+        Arg::with_name("handlebars_remote_tag_config")
+            .long("handlebars-tag")
+            .help("handlebars git remote tag config")
+            .takes_value(true),
+        Arg::with_name("handlebars_remote_branch_config")
+            .long("handlebars-branch")
+            .help("handlebars git remote branch config")
+            .takes_value(true),
+        Arg::with_name("cache")
+            .long("cache")
+            .help("build cache file enabling incremental builds (unchanged pages are not rewritten)")
+            .takes_value(true),
+        Arg::with_name("content_cache")
+            .long("content-cache")
+            .help("memory limit in MB of rendered page contents kept across repeated opens and watch rebuilds")
+            .takes_value(true)
+            .validator(|v| match content_cache_limit(&v) {
+                Some(_) => Ok(()),
+                None => Err(format!("invalid memory limit `{}`, expected a number of MB", v)),
+            }),
+        Arg::with_name("source_commit")
+            .long("source-commit")
+            .help("load source pages from this git commit instead of the working tree")
+            .takes_value(true)
+            .conflicts_with_all(&["source_branch", "source_tag"]),
+        Arg::with_name("source_branch")
+            .long("source-branch")
+            .help("load source pages from this git branch instead of the working tree")
+            .takes_value(true)
+            .conflicts_with("source_tag"),
+        Arg::with_name("source_tag")
+            .long("source-tag")
+            .help("load source pages from this git tag instead of the working tree")
+            .takes_value(true),
+        Arg::with_name("gitignore")
+            .long("gitignore")
+            .help("also ignore source and template files matched by .gitignore files (.elepagesignore files are always used)"),
+        Arg::with_name("drafts")
+            .long("drafts")
+            .help("also build pages marked as draft (can also be enabled with ELEPAGES_DRAFTS=1)"),
+        Arg::with_name("build_future")
+            .long("build-future")
+            .help("also build pages whose publishing date is after the build time"),
+        Arg::with_name("build_time")
+            .long("build-time")
+            .help("reference time deciding which pages are published or expired, as a date or rfc3339 date time (default now)")
+            .takes_value(true),
+        Arg::with_name("default_lang")
+            .long("default-lang")
+            .help("locale whose pages are kept at the output root by the localize stage, pages in other locales are moved under /<lang>/")
+            .takes_value(true),
+        Arg::with_name("watch")
+            .long("watch")
+            .help("keep running and rebuild when sources, templates or stages configuration change"),
+        Arg::with_name("v").short("v").multiple(true).help("Sets the level of verbosity"),
+    ]
+}
+
+fn make_mounts(matches: &ArgMatches) -> Vec<MountConfig> {
+    matches
+        .values_of("mount")
+        .map(|values| {
+            // validated by clap
+            values
+                .filter_map(|v| v.split_once('='))
+                .map(|(source, prefix)| MountConfig {
+                    source: source.to_string(),
+                    prefix: prefix.to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// content cache memory limit in bytes
+fn content_cache_limit(mb: &str) -> Option<usize> {
+    mb.parse::<usize>().ok().and_then(|v| v.checked_mul(1024 * 1024))
+}
+
+fn make_source_reference(matches: &ArgMatches) -> Option<GitReference> {
+    if let Some(v) = matches.value_of("source_commit") {
+        return Some(GitReference::Commit(v.to_string()));
+    }
+    if let Some(v) = matches.value_of("source_branch") {
+        return Some(GitReference::Branch(v.to_string()));
+    }
+    matches.value_of("source_tag").map(|v| GitReference::Tag(v.to_string()))
+}
+
 fn make_handlebars_config(matches: &ArgMatches) -> Option<Value> {
     if let Some(v) = matches.value_of("handlebars_str_config") {
         return Some(Value::String(v.to_string()));
@@ -115,7 +250,7 @@ fn make_handlebars_config(matches: &ArgMatches) -> Option<Value> {
     None
 }
 
-fn print_execution(execution: Execution, env: &Env) {
+fn print_execution(execution: &Execution, env: &Env) {
     env.print_v("main", &format!("loading duration : {} millis", execution.loading_elapsed.as_millis()));
     env.print_v("main", &format!("stage making duration : {} millis", execution.stage_making_elapsed.as_millis()));
     env.print_v("main", &format!("processing duration : {} millis", execution.processing_elapsed.as_millis()));
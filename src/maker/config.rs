@@ -1,32 +1,17 @@
-use crate::config::Value;
-use serde::{Deserialize, Serialize};
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
-#[serde(untagged)]
-pub enum DateQueryConfig {
-    BeforeDate {
-        #[serde(alias = "beforeDate")]
-        before_date: String,
-    },
-    AfterDate {
-        #[serde(alias = "afterDate")]
-        after_date: String,
-    },
-    BeforeTime {
-        #[serde(alias = "beforeTime")]
-        before_time: String,
-    },
-    AfterTime {
-        #[serde(alias = "afterTime")]
-        after_time: String,
-    },
-}
+use crate::config::{from_value, Value};
+pub use crate::pages::DateQueryConfig;
+use crate::pages::{BundleQuery, DataQuery, DateFilter};
+use serde::{de, Deserialize, Deserializer, Serialize};
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(untagged)]
 pub enum SelectorConfig {
     PathShortCut(String),
     ConjunctionSelectorConfig(Vec<SelectorConfig>),
+    // template query language : `{query: {pathRegex: '^docs/'}}`
+    Query {
+        query: BundleQuery,
+    },
     Base {
         path: Option<String>,
         tag: Option<String>,
@@ -34,6 +19,13 @@ pub enum SelectorConfig {
         ext: Option<String>,
         author: Option<String>,
         publishing: Option<DateQueryConfig>,
+        #[serde(alias = "lastEdit")]
+        last_edit: Option<Box<DateFilter>>,
+        #[serde(alias = "titleContains")]
+        title_contains: Option<String>,
+        data: Option<Box<DataQuery>>,
+        #[serde(alias = "hasMetadata")]
+        has_metadata: Option<bool>,
     },
     Conjunction {
         and: Vec<SelectorConfig>,
@@ -50,7 +42,7 @@ pub enum SelectorConfig {
 #[serde(untagged)]
 pub enum ComposeUnitConfig {
     Create { append: StageValue },
-    Replace { by: StageValue, replace: SelectorConfig },
+    Replace { by: Box<StageValue>, replace: SelectorConfig },
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug)]
@@ -77,7 +69,7 @@ pub enum StageValue {
     ProcessorStage {
         #[serde(alias = "type")]
         processor_type: String,
-        #[serde(default)]
+        #[serde(default, deserialize_with = "deserialize_stage_config")]
         config: Value,
     },
     ProcessorWithoutConfigStage(String),
@@ -96,3 +88,85 @@ pub enum StageValue {
         ignore_selector: SelectorConfig,
     },
 }
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct MountConfig {
+    pub source: String,
+    #[serde(default)]
+    pub prefix: String,
+}
+
+// config file content : either a stage value or site settings (mounted sources, taxonomies) with their stages
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(untagged)]
+pub enum SiteConfig {
+    Site { mounts: Vec<MountConfig>, taxonomies: Vec<String>, stages: StageValue },
+    Stages(StageValue),
+}
+
+// taxonomies are written to all_<name>.json and pages_by_<name>.json, next to the tags and authors indexes
+const RESERVED_TAXONOMIES: [&str; 5] = ["pages", "tags", "tag", "authors", "author"];
+
+#[derive(Deserialize)]
+struct SiteSettings {
+    #[serde(default)]
+    mounts: Vec<MountConfig>,
+    #[serde(default)]
+    taxonomies: Vec<String>,
+    stages: StageValue,
+}
+
+// maps with a `stages` key are site settings, their errors being located by path (e.g. mounts[1]: missing field `source`)
+impl<'de> Deserialize<'de> for SiteConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let is_site = matches!(&value, Value::Map(m) if m.contains_key("stages"));
+        if !is_site {
+            return from_value(value).map(SiteConfig::Stages).map_err(de::Error::custom);
+        }
+        let settings: SiteSettings = from_value(value).map_err(de::Error::custom)?;
+        for (i, taxonomy) in settings.taxonomies.iter().enumerate() {
+            if RESERVED_TAXONOMIES.contains(&taxonomy.as_str()) {
+                return Err(de::Error::custom(format!("taxonomies[{}]: taxonomy name `{}` is reserved", i, taxonomy)));
+            }
+            if taxonomy.is_empty() || taxonomy.contains(|c: char| c == '/' || c == '\\' || c.is_whitespace()) {
+                return Err(de::Error::custom(format!("taxonomies[{}]: invalid taxonomy name `{}`", i, taxonomy)));
+            }
+        }
+        Ok(SiteConfig::Site {
+            mounts: settings.mounts,
+            taxonomies: settings.taxonomies,
+            stages: settings.stages,
+        })
+    }
+}
+
+impl SiteConfig {
+    pub fn taxonomies(&self) -> &[String] {
+        match self {
+            SiteConfig::Site { taxonomies, .. } => taxonomies,
+            SiteConfig::Stages(_) => &[],
+        }
+    }
+
+    pub fn into_parts(self) -> (Vec<MountConfig>, StageValue) {
+        match self {
+            SiteConfig::Site { mounts, stages, .. } => (mounts, stages),
+            SiteConfig::Stages(stages) => (vec![], stages),
+        }
+    }
+}
+
+// a null stage config is handled as a missing one
+fn deserialize_stage_config<'de, D>(deserializer: D) -> Result<Value, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Value::deserialize(deserializer)? {
+        Value::Null => Value::None,
+        value => value,
+    })
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::config::Value;
-    use crate::maker::config::{ComposeUnitConfig, StageValue};
+    use crate::maker::config::{ComposeUnitConfig, MountConfig, SiteConfig, StageValue};
     use crate::maker::{DateQueryConfig, SelectorConfig};
     use indoc::indoc;
     use std::array::IntoIter;
@@ -64,7 +64,11 @@ mod tests {
                     tags: None,
                     ext: None,
                     author: None,
-                    publishing: None
+                    publishing: None,
+                    last_edit: None,
+                    title_contains: None,
+                    data: None,
+                    has_metadata: None,
                 },
                 dest: "moved/dest".to_string(),
             }
@@ -86,6 +90,10 @@ mod tests {
                     tags: None,
                     ext: None,
                     author: None,
+                    last_edit: None,
+                    title_contains: None,
+                    data: None,
+                    has_metadata: None,
                 },
             }
         );
@@ -119,7 +127,11 @@ mod tests {
                     tags: None,
                     ext: None,
                     author: None,
-                    publishing: None
+                    publishing: None,
+                    last_edit: None,
+                    title_contains: None,
+                    data: None,
+                    has_metadata: None,
                 },
                 by: Box::new(StageValue::ProcessorWithoutConfigStage("stage_type".to_string()))
             }
@@ -240,18 +252,103 @@ mod tests {
                         append: StageValue::ProcessorWithoutConfigStage("stage_type_4".to_string())
                     },
                     ComposeUnitConfig::Replace {
-                        by: StageValue::ProcessorWithoutConfigStage("stage_type_5".to_string()),
+                        by: Box::new(StageValue::ProcessorWithoutConfigStage("stage_type_5".to_string())),
                         replace: SelectorConfig::Base {
                             ext: Some(".md".to_string()),
                             path: None,
                             tag: None,
                             tags: None,
                             author: None,
-                            publishing: None
+                            publishing: None,
+                            last_edit: None,
+                            title_contains: None,
+                            data: None,
+                            has_metadata: None,
                         }
                     }
                 ]
             }
         );
     }
+
+    #[test]
+    fn parse_site_configs() {
+        let stages_only: SiteConfig = serde_yaml::from_str(indoc! {"
+            ---
+            - shadow
+            - md
+        "})
+        .unwrap();
+
+        assert_eq!(
+            stages_only.into_parts(),
+            (
+                vec![],
+                StageValue::Sequence(vec![
+                    StageValue::ProcessorWithoutConfigStage("shadow".to_string()),
+                    StageValue::ProcessorWithoutConfigStage("md".to_string())
+                ])
+            )
+        );
+
+        let mounted: SiteConfig = serde_yaml::from_str(indoc! {"
+            ---
+            mounts:
+              - source: docs
+                prefix: /
+              - source: ../shared/assets
+                prefix: /assets
+              - source: blog
+            stages: md
+        "})
+        .unwrap();
+
+        assert_eq!(
+            mounted.into_parts(),
+            (
+                vec![
+                    MountConfig {
+                        source: "docs".to_string(),
+                        prefix: "/".to_string()
+                    },
+                    MountConfig {
+                        source: "../shared/assets".to_string(),
+                        prefix: "/assets".to_string()
+                    },
+                    MountConfig {
+                        source: "blog".to_string(),
+                        prefix: "".to_string()
+                    },
+                ],
+                StageValue::ProcessorWithoutConfigStage("md".to_string())
+            )
+        );
+
+        let with_taxonomies: SiteConfig = serde_yaml::from_str(indoc! {"
+            ---
+            taxonomies: [categories, difficulty]
+            stages: md
+        "})
+        .unwrap();
+
+        assert_eq!(with_taxonomies.taxonomies(), &["categories".to_string(), "difficulty".to_string()]);
+        assert_eq!(with_taxonomies.into_parts(), (vec![], StageValue::ProcessorWithoutConfigStage("md".to_string())));
+
+        let err = serde_yaml::from_str::<SiteConfig>(indoc! {"
+            ---
+            mounts:
+              - source: docs
+              - prefix: /assets
+            stages: md
+        "})
+        .unwrap_err();
+        assert_eq!(err.to_string(), "mounts[1]: missing field `source`");
+
+        // taxonomy names are used in index file names
+        let taxonomies_err = |taxonomies: &str| serde_yaml::from_str::<SiteConfig>(&format!("{{taxonomies: {}, stages: md}}", taxonomies)).unwrap_err().to_string();
+        assert_eq!(taxonomies_err("[categories, tags]"), "taxonomies[1]: taxonomy name `tags` is reserved");
+        assert_eq!(taxonomies_err("[pages]"), "taxonomies[0]: taxonomy name `pages` is reserved");
+        assert_eq!(taxonomies_err("[a/b]"), "taxonomies[0]: invalid taxonomy name `a/b`");
+        assert_eq!(taxonomies_err("['']"), "taxonomies[0]: invalid taxonomy name ``");
+    }
 }
@@ -1,16 +1,19 @@
-use crate::config::{FromValue, Value};
+use crate::config::{from_value, FromValue, Value, ValueError};
 use crate::maker::config::{ComposeUnitConfig, StageValue};
-use crate::maker::{DateQueryConfig, SelectorConfig};
-use crate::pages::{AuthorSelector, DateQuery, Env, ExtSelector, Logical, PathSelector, PublishingDateSelector, Selector, TagSelector, ROOT_PATH_KEY};
+use crate::maker::SelectorConfig;
+use crate::pages::{is_selector_expr, AuthorSelector, BundleQuery, DateQuery, Env, ExtSelector, Logical, MetadataSelector, PathSelector, PublishingDateSelector, Selector, TagSelector, ROOT_PATH_KEY};
 use crate::pages_error::PagesError;
 use crate::remote::{GitReference, GitRemote};
-use crate::stages::{AppendStage, ComposeStage, ComposeUnit, CopyCut, GitMetadata, HbsStage, IndexStage, MdStage, PathGenerator, ReplaceStage, SequenceStage, ShadowPages, Stage, UnionStage};
-use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use crate::stages::{
+    AppendStage, ComposeStage, ComposeUnit, CopyCut, DataType, FrontMatterStage, GitMetadata, HbsStage, IndexStage, LocalizeStage, MdStage, MetadataSchema, PathGenerator, ReplaceStage, SequenceStage,
+    ShadowPages, Stage, UnionStage, ValidateStage,
+};
+use serde::Deserialize;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
-use std::time::SystemTime;
 
 pub trait StageMaker {
     fn make(&self, name: Option<&str>, config: &Value, env: &Env) -> anyhow::Result<Arc<dyn Stage>>;
@@ -22,27 +25,53 @@ pub struct Maker {
 
 pub struct GitMetadataStageMaker;
 pub struct IndexesStageMaker;
+pub struct LocalizeStageMaker;
 pub struct MdStageMaker;
+pub struct FrontMatterStageMaker;
 pub struct ShadowStageMaker;
 pub struct HbsStageMaker;
 pub struct PathGeneratorStageMaker;
+pub struct ValidateStageMaker;
 
 impl StageMaker for GitMetadataStageMaker {
     fn make(&self, name: Option<&str>, config: &Value, env: &Env) -> anyhow::Result<Arc<dyn Stage>> {
         let root_path_value: Value = env.get(ROOT_PATH_KEY).ok_or_else(|| PagesError::ElementNotFound("root_path not found in env".to_string()))?;
         let root_path: PathBuf = FromValue::from_value(root_path_value)?;
+        let name = name.unwrap_or("git metadata stage");
         let (repo_path, pages_rel_path) = match config {
             Value::String(config_repo_path) => {
                 let p = PathBuf::from_str(config_repo_path)?;
                 let r = root_path.as_path().canonicalize()?.strip_prefix(&p.canonicalize()?)?.to_path_buf();
                 (p, Some(r))
             }
-            _ => (root_path, None),
+            _ => (root_path.clone(), None),
         };
-        Ok(Arc::new(GitMetadata {
-            name: name.unwrap_or("git metadata stage").to_string(),
-            repo_path,
-            pages_rel_path,
+        let mounts = env.mounts();
+        if mounts.is_empty() {
+            return Ok(Arc::new(GitMetadata {
+                name: name.to_string(),
+                repo_path,
+                pages_rel_path,
+                page_prefix: vec![],
+            }));
+        }
+        // each mounted source is read from its own repository, deepest prefixes first so that they take their pages before the root source
+        let mut mounts = mounts;
+        mounts.sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+        Ok(Arc::new(SequenceStage {
+            name: name.to_string(),
+            stages: mounts
+                .into_iter()
+                .map(|(page_prefix, source)| {
+                    let (repo_path, pages_rel_path) = if source == root_path { (repo_path.clone(), pages_rel_path.clone()) } else { (source, None) };
+                    Arc::new(GitMetadata {
+                        name: format!("{} /{}", name, page_prefix.join("/")),
+                        repo_path,
+                        pages_rel_path,
+                        page_prefix,
+                    }) as Arc<dyn Stage>
+                })
+                .collect(),
         }))
     }
 }
@@ -55,6 +84,14 @@ impl StageMaker for IndexesStageMaker {
     }
 }
 
+impl StageMaker for LocalizeStageMaker {
+    fn make(&self, name: Option<&str>, _: &Value, _: &Env) -> anyhow::Result<Arc<dyn Stage>> {
+        Ok(Arc::new(LocalizeStage {
+            name: name.unwrap_or("localize stage").to_string(),
+        }))
+    }
+}
+
 impl StageMaker for MdStageMaker {
     fn make(&self, name: Option<&str>, _: &Value, _: &Env) -> anyhow::Result<Arc<dyn Stage>> {
         Ok(Arc::new(MdStage {
@@ -63,12 +100,32 @@ impl StageMaker for MdStageMaker {
     }
 }
 
+impl StageMaker for FrontMatterStageMaker {
+    fn make(&self, name: Option<&str>, _: &Value, _: &Env) -> anyhow::Result<Arc<dyn Stage>> {
+        Ok(Arc::new(FrontMatterStage {
+            name: name.unwrap_or("front matter stage").to_string(),
+        }))
+    }
+}
+
 impl StageMaker for ShadowStageMaker {
     fn make(&self, name: Option<&str>, _: &Value, _: &Env) -> anyhow::Result<Arc<dyn Stage>> {
         Ok(Arc::new(ShadowPages::default(name.unwrap_or("shadow pages stage").to_string())))
     }
 }
 
+// unknown keys are reported by the stage maker instead of being rejected, as older configs may have some
+#[derive(Deserialize)]
+struct HandlebarsStageMakerMapConfig {
+    path: Option<String>,
+    remote: Option<String>,
+    commit: Option<String>,
+    branch: Option<String>,
+    tag: Option<String>,
+}
+
+const HANDLEBARS_CONFIG_KEYS: [&str; 5] = ["path", "remote", "commit", "branch", "tag"];
+
 struct HandlebarsStageMakerConfig {
     path: Option<String>,
     remote: Option<String>,
@@ -91,29 +148,30 @@ impl FromValue for HandlebarsStageMakerConfig {
                 git_reference: None,
             });
         }
-        if let Ok(m) = <HashMap<String, String>>::from_value(value) {
-            let git_reference = if let Some(r) = m.get("commit").map(|v| GitReference::Commit(v.to_string())) {
-                Some(r)
-            } else if let Some(r) = m.get("branch").map(|v| GitReference::Branch(v.to_string())) {
-                Some(r)
-            } else if let Some(r) = m.get("tag").map(|v| GitReference::Tag(v.to_string())) {
-                Some(r)
-            } else {
-                None
-            };
-            return Ok(HandlebarsStageMakerConfig {
-                path: m.get("path").cloned(),
-                remote: m.get("remote").cloned(),
-                git_reference,
-            });
-        }
-        Err(PagesError::ValueParsing("expecting Value::String or Value::Map".to_string()).into())
+        let m: HandlebarsStageMakerMapConfig = from_value(value)?;
+        let git_reference = if let Some(r) = m.commit.map(GitReference::Commit) {
+            Some(r)
+        } else if let Some(r) = m.branch.map(GitReference::Branch) {
+            Some(r)
+        } else {
+            m.tag.map(GitReference::Tag)
+        };
+        Ok(HandlebarsStageMakerConfig {
+            path: m.path,
+            remote: m.remote,
+            git_reference,
+        })
     }
 }
 
 impl StageMaker for HbsStageMaker {
     fn make(&self, name: Option<&str>, config: &Value, env: &Env) -> anyhow::Result<Arc<dyn Stage>> {
         let template_path: PathBuf;
+        if let Value::Map(m) = config {
+            for key in m.keys().filter(|k| !HANDLEBARS_CONFIG_KEYS.contains(&k.as_str())) {
+                env.print_error(name.unwrap_or("handlebars stage"), &format!("unknown config field {} ignored", key));
+            }
+        }
         let hbs_config = HandlebarsStageMakerConfig::from_value(config.clone())?;
         if let Some(remote) = hbs_config.remote {
             let home_dir = dirs::home_dir().ok_or_else(|| PagesError::ElementNotFound("cannot locate home directory".to_string()))?;
@@ -139,67 +197,69 @@ impl StageMaker for PathGeneratorStageMaker {
     }
 }
 
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct ValidateStageMakerConfig {
+    strict: bool,
+    required: Vec<RequiredField>,
+    tags: Option<Vec<String>>,
+    authors: Option<Vec<String>>,
+    data: HashMap<String, DataType>,
+}
+
+#[derive(Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum RequiredField {
+    Title,
+    Summary,
+}
+
+impl StageMaker for ValidateStageMaker {
+    fn make(&self, name: Option<&str>, config: &Value, _: &Env) -> anyhow::Result<Arc<dyn Stage>> {
+        let validate_config: ValidateStageMakerConfig = from_value(config.clone())?;
+        Ok(Arc::new(ValidateStage {
+            name: name.unwrap_or("validate stage").to_string(),
+            schema: MetadataSchema {
+                required_title: validate_config.required.contains(&RequiredField::Title),
+                required_summary: validate_config.required.contains(&RequiredField::Summary),
+                allowed_tags: validate_config.tags.map(|tags| tags.into_iter().collect()),
+                known_authors: validate_config.authors.map(|authors| authors.into_iter().collect()),
+                data_types: validate_config.data,
+            },
+            strict: validate_config.strict,
+        }))
+    }
+}
+
 impl Maker {
     pub fn default() -> Self {
         let mut processor_stage_makers = HashMap::new();
 
         processor_stage_makers.insert("git_metadata".into(), Box::new(GitMetadataStageMaker) as Box<dyn StageMaker>);
         processor_stage_makers.insert("indexes".into(), Box::new(IndexesStageMaker) as Box<dyn StageMaker>);
+        processor_stage_makers.insert("localize".into(), Box::new(LocalizeStageMaker) as Box<dyn StageMaker>);
         processor_stage_makers.insert("md".into(), Box::new(MdStageMaker) as Box<dyn StageMaker>);
+        processor_stage_makers.insert("front_matter".into(), Box::new(FrontMatterStageMaker) as Box<dyn StageMaker>);
         processor_stage_makers.insert("shadow".into(), Box::new(ShadowStageMaker) as Box<dyn StageMaker>);
         processor_stage_makers.insert("handlebars".into(), Box::new(HbsStageMaker) as Box<dyn StageMaker>);
         processor_stage_makers.insert("path_generator".into(), Box::new(PathGeneratorStageMaker) as Box<dyn StageMaker>);
+        processor_stage_makers.insert("validate".into(), Box::new(ValidateStageMaker) as Box<dyn StageMaker>);
 
         Maker { processor_stage_makers }
     }
 
-    fn make_date_config(date_config: &DateQueryConfig) -> anyhow::Result<DateQuery> {
-        match date_config {
-            DateQueryConfig::BeforeDate { before_date } => {
-                if before_date == "now" {
-                    let ts = DateTime::<Utc>::from(SystemTime::now()).date().and_hms(0, 0, 0).timestamp();
-                    return Ok(DateQuery::Before(ts));
-                }
-                let ts = NaiveDate::from_str(before_date)?.and_hms(0, 0, 0).timestamp();
-                Ok(DateQuery::Before(ts))
-            }
-            DateQueryConfig::AfterDate { after_date } => {
-                if after_date == "now" {
-                    let ts = DateTime::<Utc>::from(SystemTime::now()).date().and_hms(23, 59, 59).timestamp();
-                    return Ok(DateQuery::After(ts));
-                }
-                let ts = NaiveDate::from_str(after_date)?.and_hms(23, 59, 59).timestamp();
-                Ok(DateQuery::After(ts))
-            }
-            DateQueryConfig::BeforeTime { before_time } => {
-                if before_time == "now" {
-                    let ts = DateTime::<Utc>::from(SystemTime::now()).timestamp();
-                    return Ok(DateQuery::Before(ts));
-                }
-                let ts = NaiveDateTime::from_str(before_time)?.timestamp();
-                Ok(DateQuery::Before(ts))
-            }
-            DateQueryConfig::AfterTime { after_time } => {
-                if after_time == "now" {
-                    let ts = DateTime::<Utc>::from(SystemTime::now()).timestamp();
-                    return Ok(DateQuery::After(ts));
-                }
-                let ts = NaiveDateTime::from_str(after_time)?.timestamp();
-                Ok(DateQuery::After(ts))
-            }
-        }
-    }
-
     fn make_selector(config: &SelectorConfig) -> anyhow::Result<Arc<dyn Selector>> {
         let selector: Arc<dyn Selector> = match config {
             SelectorConfig::Conjunction { and } => Arc::new(Logical::And(and.iter().map(|sc| Maker::make_selector(sc)).collect::<anyhow::Result<Vec<Arc<dyn Selector>>>>()?)) as Arc<dyn Selector>,
             SelectorConfig::Disjunction { or } => Arc::new(Logical::Or(or.iter().map(|sc| Maker::make_selector(sc)).collect::<anyhow::Result<Vec<Arc<dyn Selector>>>>()?)) as Arc<dyn Selector>,
             SelectorConfig::Not { not } => Arc::new(Logical::Not(Maker::make_selector(not)?)) as Arc<dyn Selector>,
 
+            SelectorConfig::PathShortCut(expr) if is_selector_expr(expr)? => Maker::make_query_selector(&BundleQuery::from_str(expr)?),
             SelectorConfig::PathShortCut(path) => Arc::new(PathSelector {
                 query: path.split('/').map(|s| s.to_string()).collect(),
             }) as Arc<dyn Selector>,
             SelectorConfig::ConjunctionSelectorConfig(and) => Maker::make_selector(&SelectorConfig::Conjunction { and: and.to_vec() })?,
+            SelectorConfig::Query { query } => Arc::new(query.clone()) as Arc<dyn Selector>,
             SelectorConfig::Base {
                 path,
                 tag,
@@ -207,6 +267,10 @@ impl Maker {
                 ext,
                 author,
                 publishing,
+                last_edit,
+                title_contains,
+                data,
+                has_metadata,
             } => {
                 let mut selectors: Vec<Arc<dyn Selector>> = vec![];
                 if let Some(v) = path {
@@ -232,7 +296,20 @@ impl Maker {
                     selectors.push(Arc::new(AuthorSelector { author: v.to_string() }) as Arc<dyn Selector>)
                 }
                 if let Some(v) = publishing {
-                    selectors.push(Arc::new(PublishingDateSelector { query: Maker::make_date_config(v)? }) as Arc<dyn Selector>)
+                    selectors.push(Arc::new(PublishingDateSelector { query: DateQuery::try_from(v)? }) as Arc<dyn Selector>)
+                }
+                // same keys and semantics as the template query language
+                if let Some(v) = last_edit {
+                    selectors.push(Arc::new(BundleQuery::LastEdit { last_edit: v.clone() }) as Arc<dyn Selector>)
+                }
+                if let Some(v) = title_contains {
+                    selectors.push(Arc::new(BundleQuery::TitleContains { title_contains: v.to_string() }) as Arc<dyn Selector>)
+                }
+                if let Some(v) = data {
+                    selectors.push(Arc::new(BundleQuery::Data { data: v.clone() }) as Arc<dyn Selector>)
+                }
+                if let Some(v) = has_metadata {
+                    selectors.push(Arc::new(MetadataSelector { has_metadata: *v }) as Arc<dyn Selector>)
                 }
                 if selectors.is_empty() {
                     return Err(PagesError::ValueParsing("cannot parse selector".to_string()).into());
@@ -248,31 +325,60 @@ impl Maker {
         Ok(selector)
     }
 
+    // selector expressions are built on native selectors when one exists
+    fn make_query_selector(query: &BundleQuery) -> Arc<dyn Selector> {
+        match query {
+            BundleQuery::And { and } => Arc::new(Logical::And(and.iter().map(Maker::make_query_selector).collect())),
+            BundleQuery::Or { or } => Arc::new(Logical::Or(or.iter().map(Maker::make_query_selector).collect())),
+            BundleQuery::Not { not } => Arc::new(Logical::Not(Maker::make_query_selector(not))),
+            BundleQuery::Path { path } => Arc::new(PathSelector {
+                query: path.split('/').map(|s| s.to_string()).collect(),
+            }),
+            BundleQuery::Tag { tag } => Arc::new(TagSelector { tag: tag.to_string() }),
+            BundleQuery::Author { author } => Arc::new(AuthorSelector { author: author.to_string() }),
+            _ => Arc::new(query.clone()),
+        }
+    }
+
     pub fn make(&self, name: Option<&str>, stage_config: &StageValue, env: &Env) -> anyhow::Result<Arc<dyn Stage>> {
         let stage = match stage_config {
-            StageValue::Named { name, stage } => self.make(Some(name), stage, env)?,
+            StageValue::Named { name, stage } => self.make(Some(name), stage, env).map_err(|e| ValueError::prefix(e, "stage"))?,
             StageValue::Sequence(values) => Arc::new(SequenceStage {
                 name: name.unwrap_or("sequence stage").to_string(),
-                stages: values.iter().map(|value| self.make(None, value, env)).collect::<anyhow::Result<Vec<Arc<dyn Stage>>>>()?,
+                stages: values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| self.make(None, value, env).map_err(|e| ValueError::prefix(e, &format!("[{}]", i))))
+                    .collect::<anyhow::Result<Vec<Arc<dyn Stage>>>>()?,
             }) as Arc<dyn Stage>,
             StageValue::Union { union: values } => Arc::new(UnionStage {
                 name: name.unwrap_or("union stage").to_string(),
-                stages: values.iter().map(|value| self.make(None, value, env)).collect::<anyhow::Result<Vec<Arc<dyn Stage>>>>()?,
+                stages: values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| self.make(None, value, env).map_err(|e| ValueError::prefix(e, &format!("union[{}]", i))))
+                    .collect::<anyhow::Result<Vec<Arc<dyn Stage>>>>()?,
                 parallel: true,
             }) as Arc<dyn Stage>,
             StageValue::Composition { compose: configs } => Arc::new(ComposeStage {
                 name: name.unwrap_or("compose stage").to_string(),
                 units: configs
                     .iter()
-                    .map(|value| {
+                    .enumerate()
+                    .map(|(i, value)| {
                         Ok(match value {
-                            ComposeUnitConfig::Create { append: value } => Arc::new(ComposeUnit::CreateNewSet(self.make(None, value, env)?)),
+                            ComposeUnitConfig::Create { append: value } => Arc::new(ComposeUnit::CreateNewSet(
+                                self.make(None, value, env).map_err(|e| ValueError::prefix(e, &format!("compose[{}].append", i)))?,
+                            )),
                             ComposeUnitConfig::Replace {
                                 replace: selector_config,
                                 by: stage_value,
                             } => {
-                                let selector = Maker::make_selector(selector_config)?;
-                                Arc::new(ComposeUnit::ReplaceSubSet(selector, self.make(None, stage_value, env)?))
+                                let selector = Maker::make_selector(selector_config).map_err(|e| ValueError::prefix(e, &format!("compose[{}].replace", i)))?;
+                                Arc::new(ComposeUnit::ReplaceSubSet(
+                                    selector,
+                                    self.make(None, stage_value, env).map_err(|e| ValueError::prefix(e, &format!("compose[{}].by", i)))?,
+                                ))
                             }
                         })
                     })
@@ -284,7 +390,7 @@ impl Maker {
                     .processor_stage_makers
                     .get(processor_type)
                     .ok_or_else(|| PagesError::ElementNotFound(format!("stage {} not found", processor_type)))?;
-                stage_maker.make(name, config, env)? as Arc<dyn Stage>
+                stage_maker.make(name, config, env).map_err(|e| ValueError::prefix(e, "config"))? as Arc<dyn Stage>
             }
             StageValue::ProcessorWithoutConfigStage(processor_type) => {
                 let stage_maker = self
@@ -294,7 +400,7 @@ impl Maker {
                 stage_maker.make(name, &Value::None, env)? as Arc<dyn Stage>
             }
             StageValue::Copy { dest, copy_selector } => {
-                let selector = Maker::make_selector(copy_selector)?;
+                let selector = Maker::make_selector(copy_selector).map_err(|e| ValueError::prefix(e, "copy"))?;
                 Arc::new(CopyCut::Copy {
                     name: name.unwrap_or("copy stage").to_string(),
                     selector,
@@ -302,7 +408,7 @@ impl Maker {
                 })
             }
             StageValue::Move { dest, move_selector } => {
-                let selector = Maker::make_selector(move_selector)?;
+                let selector = Maker::make_selector(move_selector).map_err(|e| ValueError::prefix(e, "move"))?;
                 Arc::new(CopyCut::Move {
                     name: name.unwrap_or("move stage").to_string(),
                     selector,
@@ -310,7 +416,7 @@ impl Maker {
                 })
             }
             StageValue::Ignore { ignore_selector } => {
-                let selector = Maker::make_selector(ignore_selector)?;
+                let selector = Maker::make_selector(ignore_selector).map_err(|e| ValueError::prefix(e, "ignore"))?;
                 Arc::new(CopyCut::Ignore {
                     name: name.unwrap_or("ignore stage").to_string(),
                     selector,
@@ -318,12 +424,12 @@ impl Maker {
             }
             StageValue::Append { append } => Arc::new(AppendStage {
                 name: name.unwrap_or("append stage").to_string(),
-                inner: self.make(None, append, env)?,
+                inner: self.make(None, append, env).map_err(|e| ValueError::prefix(e, "append"))?,
             }),
             StageValue::Replace { replace, by } => Arc::new(ReplaceStage {
                 name: name.unwrap_or("replace stage").to_string(),
-                inner: self.make(None, by, env)?,
-                selector: Maker::make_selector(replace)?,
+                inner: self.make(None, by, env).map_err(|e| ValueError::prefix(e, "by"))?,
+                selector: Maker::make_selector(replace).map_err(|e| ValueError::prefix(e, "replace"))?,
             }),
         };
 
@@ -2,11 +2,15 @@
 mod tests {
     use crate::config::Value;
     use crate::maker::{Maker, StageValue};
-    use crate::pages::{DateQuery, Env, ExtSelector, Logical, PathSelector, PublishingDateSelector, TagSelector, ROOT_PATH_KEY};
+    use crate::pages::{AuthorSelector, BundleQuery, DateQuery, Env, ExtSelector, Logical, MetadataSelector, PathSelector, PublishingDateSelector, TagSelector, ROOT_PATH_KEY};
     use crate::stages::ComposeUnit::{CreateNewSet, ReplaceSubSet};
-    use crate::stages::{AppendStage, ComposeStage, CopyCut, GitMetadata, HbsStage, IndexStage, MdStage, PathGenerator, ReplaceStage, SequenceStage, ShadowPages, Stage, UnionStage};
+    use crate::stages::{
+        AppendStage, ComposeStage, CopyCut, DataType, FrontMatterStage, GitMetadata, HbsStage, IndexStage, MdStage, MetadataSchema, PathGenerator, ReplaceStage, SequenceStage, ShadowPages, Stage,
+        UnionStage, ValidateStage,
+    };
     use chrono::{DateTime, Utc};
     use indoc::indoc;
+    use std::array::IntoIter;
     use std::path::PathBuf;
     use std::str::FromStr;
 
@@ -47,6 +51,13 @@ mod tests {
             panic!("should downcast to MdStage");
         }
 
+        let front_matter_stage_config: StageValue = serde_yaml::from_str("front_matter").unwrap();
+        let front_matter_stage = Maker::default().make(None, &front_matter_stage_config, &Env::test()).unwrap();
+        assert_eq!(front_matter_stage.name(), "front matter stage");
+        if let None = front_matter_stage.as_any().unwrap().downcast_ref::<FrontMatterStage>() {
+            panic!("should downcast to FrontMatterStage");
+        }
+
         let hb_stage_config: StageValue = serde_yaml::from_str("{type: 'handlebars', config: 'd/e' }").unwrap();
         let hb_stage = Maker::default().make(None, &hb_stage_config, &env).unwrap();
         assert_eq!(hb_stage.name(), "handlebars stage");
@@ -236,6 +247,44 @@ mod tests {
         };
     }
 
+    #[test]
+    fn build_validate_stage() {
+        let config: StageValue = serde_yaml::from_str(indoc! {"
+            ---
+            type: validate
+            config:
+              strict: true
+              required: [title]
+              tags: [t1, t2]
+              authors: [a1]
+              data:
+                weight: number
+        "})
+        .unwrap();
+
+        let stage = Maker::default().make(None, &config, &Env::test()).unwrap();
+
+        let validate_stage = stage.as_any().unwrap().downcast_ref::<ValidateStage>().expect("ValidateStage");
+        assert_eq!(validate_stage.name, "validate stage");
+        assert!(validate_stage.strict);
+        assert_eq!(
+            validate_stage.schema,
+            MetadataSchema {
+                required_title: true,
+                required_summary: false,
+                allowed_tags: Some(IntoIter::new(["t1".to_string(), "t2".to_string()]).collect()),
+                known_authors: Some(IntoIter::new(["a1".to_string()]).collect()),
+                data_types: IntoIter::new([("weight".to_string(), DataType::Number)]).collect(),
+            }
+        );
+
+        let config: StageValue = serde_yaml::from_str("{type: validate, config: {required: [titel]}}").unwrap();
+        assert_eq!(
+            Maker::default().make(None, &config, &Env::test()).err().unwrap().to_string(),
+            "config.required[0]: unknown variant `titel`, expected `title` or `summary`"
+        );
+    }
+
     #[test]
     fn build_append_stage() {
         let config: StageValue = serde_yaml::from_str(indoc! {"
@@ -329,6 +378,132 @@ mod tests {
         }
     }
 
+    #[test]
+    fn build_data_and_metadata_selector_stages() {
+        let config: StageValue = serde_yaml::from_str(indoc! {"
+            ---
+            - move: {data: {key: kind, eq: tutorial}, lastEdit: {beforeDate: '2021-10-20'}}
+              dest: 'tutorials'
+            - ignore: [{titleContains: 'draft'}, {hasMetadata: true}]
+        "})
+        .unwrap();
+
+        let stage = Maker::default().make(None, &config, &Env::test()).unwrap();
+        let seq = stage.as_any().unwrap().downcast_ref::<SequenceStage>().expect("SequenceStage");
+
+        match seq.stages[0].as_any().unwrap().downcast_ref::<CopyCut>().expect("CopyCut 0") {
+            CopyCut::Move { selector, .. } => match selector.as_any().unwrap().downcast_ref::<Logical>().expect("Logical") {
+                Logical::And(and) => {
+                    match and[0].as_any().unwrap().downcast_ref::<BundleQuery>().expect("BundleQuery") {
+                        BundleQuery::LastEdit { last_edit } => {
+                            assert_eq!(last_edit.query, DateQuery::Before(DateTime::<Utc>::from_str("2021-10-20T00:00:00+00:00").unwrap().timestamp()))
+                        }
+                        _ => panic!("BundleQuery::LastEdit"),
+                    }
+                    match and[1].as_any().unwrap().downcast_ref::<BundleQuery>().expect("BundleQuery") {
+                        BundleQuery::Data { data } => {
+                            assert_eq!(data.key, "kind");
+                            assert_eq!(data.eq, Some(Value::String("tutorial".to_string())));
+                        }
+                        _ => panic!("BundleQuery::Data"),
+                    }
+                }
+                _ => panic!("Logical::And"),
+            },
+            _ => panic!("CopyCut::Move"),
+        }
+
+        match seq.stages[1].as_any().unwrap().downcast_ref::<CopyCut>().expect("CopyCut 1") {
+            CopyCut::Ignore { selector, .. } => match selector.as_any().unwrap().downcast_ref::<Logical>().expect("Logical") {
+                Logical::And(and) => {
+                    assert_eq!(
+                        and[0].as_any().unwrap().downcast_ref::<BundleQuery>(),
+                        Some(&BundleQuery::TitleContains { title_contains: "draft".to_string() })
+                    );
+                    assert!(and[1].as_any().unwrap().downcast_ref::<MetadataSelector>().expect("MetadataSelector").has_metadata);
+                }
+                _ => panic!("Logical::And"),
+            },
+            _ => panic!("CopyCut::Ignore"),
+        }
+    }
+
+    #[test]
+    fn build_selector_expression_stage() {
+        let config: StageValue = serde_yaml::from_str(indoc! {"
+            ---
+            ignore: 'ext:.md and not (tag:draft or author:bob)'
+        "})
+        .unwrap();
+
+        let stage = Maker::default().make(None, &config, &Env::test()).unwrap();
+        match stage.as_any().unwrap().downcast_ref::<CopyCut>().expect("CopyCut") {
+            CopyCut::Ignore { selector, .. } => match selector.as_any().unwrap().downcast_ref::<Logical>().expect("Logical") {
+                Logical::And(and) => {
+                    assert_eq!(and[0].as_any().unwrap().downcast_ref::<BundleQuery>(), Some(&BundleQuery::Ext { ext: ".md".to_string() }));
+                    match and[1].as_any().unwrap().downcast_ref::<Logical>().expect("Logical") {
+                        Logical::Not(not) => match not.as_any().unwrap().downcast_ref::<Logical>().expect("Logical") {
+                            Logical::Or(or) => {
+                                assert_eq!(or[0].as_any().unwrap().downcast_ref::<TagSelector>().expect("TagSelector").tag, "draft");
+                                assert_eq!(or[1].as_any().unwrap().downcast_ref::<AuthorSelector>().expect("AuthorSelector").author, "bob");
+                            }
+                            _ => panic!("Logical::Or"),
+                        },
+                        _ => panic!("Logical::Not"),
+                    }
+                }
+                _ => panic!("Logical::And"),
+            },
+            _ => panic!("CopyCut::Ignore"),
+        }
+
+        let invalid: StageValue = serde_yaml::from_str("copy: 'tag:a and (ext:.md'\ndest: 'a'").unwrap();
+        assert_eq!(
+            Maker::default().make(None, &invalid, &Env::test()).err().unwrap().to_string(),
+            "copy: unexpected end of expression, expected `)` at column 19"
+        );
+        let ambiguous: StageValue = serde_yaml::from_str("ignore: 'not drafts/**'").unwrap();
+        assert!(Maker::default()
+            .make(None, &ambiguous, &Env::test())
+            .err()
+            .unwrap()
+            .to_string()
+            .starts_with("ignore: ambiguous selector `not drafts/**`"));
+    }
+
+    #[test]
+    fn build_query_selector_stage() {
+        let config: StageValue = serde_yaml::from_str(indoc! {"
+            ---
+            ignore: {query: {and: [{ext: '.md'}, {publishing: {betweenDates: ['2021-10-01', '2021-10-31']}}]}}
+        "})
+        .unwrap();
+
+        let stage = Maker::default().make(None, &config, &Env::test()).unwrap();
+        match stage.as_any().unwrap().downcast_ref::<CopyCut>().expect("CopyCut") {
+            CopyCut::Ignore { selector, .. } => {
+                let query = selector.as_any().unwrap().downcast_ref::<BundleQuery>().expect("BundleQuery");
+                match query {
+                    BundleQuery::And { and } => {
+                        assert_eq!(and[0], BundleQuery::Ext { ext: ".md".to_string() });
+                        match &and[1] {
+                            BundleQuery::Publishing { publishing } => assert_eq!(
+                                publishing.query,
+                                DateQuery::Between(
+                                    DateTime::<Utc>::from_str("2021-10-01T00:00:00+00:00").unwrap().timestamp() - 1,
+                                    DateTime::<Utc>::from_str("2021-10-31T23:59:59+00:00").unwrap().timestamp() + 1
+                                )
+                            ),
+                            _ => panic!("BundleQuery::Publishing"),
+                        }
+                    }
+                    _ => panic!("BundleQuery::And"),
+                }
+            }
+            _ => panic!("CopyCut::Ignore"),
+        }
+    }
+
     #[test]
     fn build_named_sequence_stage() {
         let config: StageValue = serde_yaml::from_str(indoc! {"
@@ -0,0 +1,101 @@
+use crate::config::{from_value, Value};
+use crate::pages::{Author, Env, Metadata};
+use crate::pages_error::PagesError;
+use serde::{Deserialize, Serialize};
+use std::array::IntoIter;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+pub struct AuthorProfile {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default, alias = "displayName")]
+    pub display_name: Option<String>,
+    #[serde(default = "Vec::default")]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub bio: Option<String>,
+    #[serde(default)]
+    pub avatar: Option<String>,
+    #[serde(default = "BTreeMap::default")]
+    pub links: BTreeMap<String, String>,
+}
+
+// known authors profiles, looked up by id, display name or alias (git names and emails)
+#[derive(Debug, Default)]
+pub struct AuthorRegistry {
+    profiles: Vec<Arc<AuthorProfile>>,
+    by_alias: HashMap<String, usize>,
+}
+
+impl AuthorRegistry {
+    // registry file content : profiles by author id
+    pub fn from_value(value: Value) -> anyhow::Result<Self> {
+        let profiles: BTreeMap<String, AuthorProfile> = from_value(value)?;
+        AuthorRegistry::new(profiles.into_iter().map(|(id, profile)| AuthorProfile { id, ..profile }).collect())
+    }
+
+    pub fn new(profiles: Vec<AuthorProfile>) -> anyhow::Result<Self> {
+        let mut result = AuthorRegistry::default();
+        for (pos, profile) in profiles.into_iter().enumerate() {
+            let keys = IntoIter::new([Some(&profile.id), profile.display_name.as_ref()]).flatten().chain(profile.aliases.iter());
+            for key in keys {
+                if let Some(other) = result.by_alias.insert(key.to_lowercase(), pos) {
+                    if other != pos {
+                        return Err(PagesError::Conflict(format!("author alias {} used by both {} and {}", key, result.profiles[other].id, profile.id)).into());
+                    }
+                }
+            }
+            result.profiles.push(Arc::new(profile));
+        }
+        Ok(result)
+    }
+
+    // registry loaded by the shadow stage, if any
+    pub fn from_env(env: &Env) -> anyhow::Result<Option<Self>> {
+        match env.get(AUTHORS_KEY) {
+            None => Ok(None),
+            Some(value) => Ok(Some(AuthorRegistry::from_value(value)?)),
+        }
+    }
+
+    pub fn profiles(&self) -> &[Arc<AuthorProfile>] {
+        &self.profiles
+    }
+
+    pub fn get(&self, name_or_alias: &str) -> Option<&Arc<AuthorProfile>> {
+        self.by_alias.get(&name_or_alias.to_lowercase()).map(|pos| &self.profiles[*pos])
+    }
+
+    // registered author matching the author name or one of its contacts, authors not found are returned unchanged
+    pub fn resolve(&self, author: &Author) -> Author {
+        let profile = match self.get(&author.name).or_else(|| author.contacts.iter().find_map(|c| self.get(c))) {
+            None => return author.clone(),
+            Some(p) => p,
+        };
+        Author {
+            name: profile.id.clone(),
+            contacts: author.contacts.clone(),
+            profile: Some(Arc::clone(profile)),
+        }
+    }
+
+    // resolves metadata authors, authors resolved to the same profile are merged
+    pub fn resolve_metadata(&self, metadata: &Metadata) -> anyhow::Result<Metadata> {
+        let mut authors: HashSet<Arc<Author>> = HashSet::new();
+        for author in &metadata.authors {
+            let resolved = self.resolve(author);
+            let merged = match authors.get(&resolved) {
+                None => resolved,
+                Some(existing) => resolved.merge(existing)?,
+            };
+            authors.replace(Arc::new(merged));
+        }
+        let mut result = metadata.clone();
+        result.authors = authors;
+        Ok(result)
+    }
+}
+
+pub const AUTHORS_KEY: &str = "_authors";
@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::Value;
+    use crate::pages::{Author, AuthorProfile, AuthorRegistry, Metadata};
+    use indoc::indoc;
+    use std::array::IntoIter;
+    use std::collections::{BTreeMap, HashSet};
+    use std::sync::Arc;
+
+    fn registry() -> AuthorRegistry {
+        let value: Value = serde_yaml::from_str(indoc! {"
+            ---
+            jdoe:
+              display_name: John Doe
+              aliases: [j.doe, jdoe@corp]
+              bio: docs maintainer
+              avatar: /img/jdoe.png
+              links:
+                github: https://github.com/jdoe
+            asmith:
+              aliases: [Alice]
+        "})
+        .unwrap();
+        AuthorRegistry::from_value(value).unwrap()
+    }
+
+    fn author(name: &str, contacts: &[&str]) -> Author {
+        Author {
+            name: name.to_string(),
+            contacts: contacts.iter().map(|c| c.to_string()).collect(),
+            profile: None,
+        }
+    }
+
+    #[test]
+    fn load_profiles_by_id() {
+        let registry = registry();
+        assert_eq!(
+            registry.profiles().iter().map(|p| p.as_ref().clone()).collect::<Vec<_>>(),
+            vec![
+                AuthorProfile {
+                    id: "asmith".to_string(),
+                    display_name: None,
+                    aliases: vec!["Alice".to_string()],
+                    bio: None,
+                    avatar: None,
+                    links: BTreeMap::default(),
+                },
+                AuthorProfile {
+                    id: "jdoe".to_string(),
+                    display_name: Some("John Doe".to_string()),
+                    aliases: vec!["j.doe".to_string(), "jdoe@corp".to_string()],
+                    bio: Some("docs maintainer".to_string()),
+                    avatar: Some("/img/jdoe.png".to_string()),
+                    links: IntoIter::new([("github".to_string(), "https://github.com/jdoe".to_string())]).collect(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_authors_by_id_display_name_alias_or_contact() {
+        let registry = registry();
+        for a in &[author("jdoe", &[]), author("john doe", &[]), author("J.Doe", &[]), author("John", &["jdoe@corp"])] {
+            let resolved = registry.resolve(a);
+            assert_eq!(resolved.name, "jdoe");
+            assert_eq!(resolved.profile.unwrap().display_name, Some("John Doe".to_string()));
+        }
+        assert_eq!(registry.resolve(&author("unknown", &["u@corp"])), author("unknown", &["u@corp"]));
+        assert!(registry.resolve(&author("unknown", &[])).profile.is_none());
+    }
+
+    #[test]
+    fn merge_authors_resolved_to_the_same_profile() {
+        let registry = registry();
+        let metadata = Metadata {
+            authors: IntoIter::new([Arc::new(author("John Doe", &["john@home"])), Arc::new(author("jdoe", &["jdoe@corp"])), Arc::new(author("Alice", &[]))]).collect(),
+            ..Default::default()
+        };
+
+        let resolved = registry.resolve_metadata(&metadata).unwrap();
+        let mut names = resolved.authors.iter().map(|a| a.name.to_string()).collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, vec!["asmith", "jdoe"]);
+        let jdoe = resolved.authors.iter().find(|a| a.name == "jdoe").unwrap();
+        assert_eq!(jdoe.contacts, IntoIter::new(["john@home".to_string(), "jdoe@corp".to_string()]).collect::<HashSet<_>>());
+    }
+
+    #[test]
+    fn return_err_when_alias_is_shared_by_two_authors() {
+        let value: Value = serde_yaml::from_str("{jdoe: {aliases: [jd]}, jdupont: {aliases: [JD]}}").unwrap();
+        assert_eq!(AuthorRegistry::from_value(value).err().unwrap().to_string(), "author alias JD used by both jdoe and jdupont");
+    }
+}
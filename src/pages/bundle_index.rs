@@ -1,19 +1,191 @@
 use crate::config::Value;
-use crate::pages::{Author, Metadata, Page, PageBundle, PathSelector};
+use crate::pages::{contains_text, glob_match_path, unlocalized_path, Author, DataQuery, DateFilter, LazyDigest, Metadata, Page, PageBundle, PathRegex, SectionTree, Selector, SortBy};
 use chrono::{DateTime, Datelike, NaiveDateTime, Timelike, Utc};
-use serde::{Deserialize, Serialize};
+use once_cell::sync::OnceCell;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::any::Any;
 use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap};
+use std::str::FromStr;
 use std::sync::Arc;
 use urlencoding::encode;
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BundleIndex {
-    pub all_authors: HashSet<Author>,
-    pub all_tags: HashSet<String>,
+    pub all_authors: BTreeSet<Author>,
+    pub all_tags: BTreeSet<String>,
+    pub all_langs: BTreeSet<String>,
     pub all_pages: Vec<PageIndex>,
     pub pages_by_author: HashMap<String, Vec<PageRef>>,
     pub pages_by_tag: HashMap<String, Vec<PageRef>>,
+    pub pages_by_lang: HashMap<String, Vec<PageRef>>,
+    // declared taxonomies terms and pages, by taxonomy name
+    pub all_terms: HashMap<String, BTreeSet<String>>,
+    pub pages_by_term: HashMap<String, HashMap<String, Vec<PageRef>>>,
+    #[serde(skip)]
+    pub digest: LazyDigest,
+    #[serde(skip)]
+    pub page_positions: LazyIndex<HashMap<Vec<String>, usize>>,
+    #[serde(skip)]
+    pub translation_positions: LazyIndex<HashMap<String, Vec<usize>>>,
+    #[serde(skip)]
+    pub sections: LazyIndex<SectionTree>,
+}
+
+// lookup structure lazily derived from the index pages
+#[derive(Debug, Clone)]
+pub struct LazyIndex<T>(OnceCell<T>);
+
+impl<T> Default for LazyIndex<T> {
+    fn default() -> Self {
+        LazyIndex(OnceCell::new())
+    }
+}
+
+// lazily derived digest and lookup structures are left out of index equality
+impl PartialEq for BundleIndex {
+    fn eq(&self, other: &Self) -> bool {
+        self.all_authors == other.all_authors
+            && self.all_tags == other.all_tags
+            && self.all_langs == other.all_langs
+            && self.all_pages == other.all_pages
+            && self.pages_by_author == other.pages_by_author
+            && self.pages_by_tag == other.pages_by_tag
+            && self.pages_by_lang == other.pages_by_lang
+            && self.all_terms == other.all_terms
+            && self.pages_by_term == other.pages_by_term
+    }
+}
+
+// pages sharing prev / next links
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NavigationScope {
+    // pages of the same directory
+    Directory,
+    // parts of the same series
+    Series,
+    // all dated pages
+    Date,
+}
+
+impl BundleIndex {
+    // index digest is computed once as the index is not modified once built
+    pub fn digest(&self) -> anyhow::Result<&str> {
+        self.digest.get_or_compute(self)
+    }
+
+    // other locales versions of a page, sorted by lang
+    pub fn translations(&self, page: &PageIndex) -> Vec<&PageIndex> {
+        let (lang, key) = match &page.metadata {
+            Some(MetadataIndex {
+                lang: Some(lang),
+                translation_key: Some(key),
+                ..
+            }) => (lang, key),
+            _ => return vec![],
+        };
+        // localized pages positions by translation key
+        let positions = self.translation_positions.0.get_or_init(|| {
+            let mut positions: HashMap<String, Vec<usize>> = HashMap::new();
+            for (pos, p) in self.all_pages.iter().enumerate() {
+                if let Some(MetadataIndex {
+                    lang: Some(_),
+                    translation_key: Some(key),
+                    ..
+                }) = &p.metadata
+                {
+                    positions.entry(key.to_string()).or_default().push(pos);
+                }
+            }
+            positions
+        });
+        let mut result: Vec<&PageIndex> = positions
+            .get(key)
+            .map(|positions| {
+                positions
+                    .iter()
+                    .map(|pos| &self.all_pages[*pos])
+                    .filter(|p| p.metadata.as_ref().and_then(|m| m.lang.as_ref()) != Some(lang))
+                    .collect()
+            })
+            .unwrap_or_default();
+        result.sort_by(|a, b| a.metadata.as_ref().map(|m| &m.lang).cmp(&b.metadata.as_ref().map(|m| &m.lang)));
+        result
+    }
+
+    // indexed version of a page, with its navigation and series links, or a standalone index for pages not in the bundle index
+    pub fn page_index(&self, page: &Arc<dyn Page>) -> PageIndex {
+        let positions = self
+            .page_positions
+            .0
+            .get_or_init(|| self.all_pages.iter().enumerate().map(|(pos, p)| (p.page_ref.path.clone(), pos)).collect());
+        match positions.get(page.path()) {
+            Some(pos) => self.all_pages[*pos].clone(),
+            None => PageIndex::from(page),
+        }
+    }
+
+    // sections built from the directories of indexed pages
+    pub fn section_tree(&self) -> &SectionTree {
+        self.sections.0.get_or_init(|| SectionTree::from(self.all_pages.as_slice()))
+    }
+
+    // sets series part numbers and prev / next links of indexed pages
+    fn link_pages(&mut self) {
+        let mut series: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut scopes: HashMap<(NavigationScope, Vec<String>), Vec<usize>> = HashMap::new();
+        for (pos, page) in self.all_pages.iter().enumerate() {
+            let m = match &page.metadata {
+                None => continue,
+                Some(m) => m,
+            };
+            if let Some(name) = &m.series {
+                series.entry(name.to_string()).or_insert_with(Vec::new).push(pos);
+            }
+            let scope = m.navigation.unwrap_or(if m.series.is_some() { NavigationScope::Series } else { NavigationScope::Date });
+            let key = match scope {
+                NavigationScope::Directory => page.page_ref.path[..page.page_ref.path.len().saturating_sub(1)].to_vec(),
+                NavigationScope::Series => match &m.series {
+                    Some(name) => vec![name.to_string()],
+                    None => continue,
+                },
+                NavigationScope::Date if m.publishing_date.is_some() => vec![],
+                NavigationScope::Date => continue,
+            };
+            scopes.entry((scope, key)).or_insert_with(Vec::new).push(pos);
+        }
+
+        for (name, mut group) in series {
+            group.sort_by(|a, b| self.navigation_order(*a, *b));
+            let total = group.len();
+            for (i, pos) in group.iter().enumerate() {
+                let part = self.all_pages[*pos].metadata.as_ref().and_then(|m| m.series_part).unwrap_or(i as u32 + 1);
+                self.all_pages[*pos].series = Some(SeriesIndex { name: name.clone(), part, total });
+            }
+        }
+
+        for (_, mut group) in scopes {
+            group.sort_by(|a, b| self.navigation_order(*a, *b));
+            for (i, pos) in group.iter().enumerate() {
+                let prev = if i > 0 { Some(self.all_pages[group[i - 1]].page_ref.clone()) } else { None };
+                let next = group.get(i + 1).map(|p| self.all_pages[*p].page_ref.clone());
+                self.all_pages[*pos].prev = prev;
+                self.all_pages[*pos].next = next;
+            }
+        }
+    }
+
+    // navigation order : series part, then publishing date, then path
+    fn navigation_order(&self, a: usize, b: usize) -> Ordering {
+        let key = |pos: usize| {
+            let page = &self.all_pages[pos];
+            let m = page.metadata.as_ref();
+            (m.and_then(|m| m.series_part), m.and_then(|m| m.publishing_date.as_ref()).map(|d| d.timestamp), &page.page_ref.path)
+        };
+        key(a).cmp(&key(b))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, PartialEq)]
@@ -26,6 +198,16 @@ pub struct PageIndex {
     pub page_ref: PageRef,
     pub page_uri: String,
     pub metadata: Option<MetadataIndex>,
+    pub prev: Option<PageRef>,
+    pub next: Option<PageRef>,
+    pub series: Option<SeriesIndex>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SeriesIndex {
+    pub name: String,
+    pub part: u32,
+    pub total: usize,
 }
 
 #[derive(Debug, Clone, Serialize, PartialEq)]
@@ -76,14 +258,27 @@ pub struct MetadataIndex {
     #[serde(alias = "urlTitle")]
     pub url_title: Option<String>,
     pub summary: Option<String>,
-    #[serde(default = "HashSet::default")]
-    pub authors: HashSet<String>,
-    #[serde(default = "HashSet::default")]
-    pub tags: HashSet<String>,
+    #[serde(default = "BTreeSet::default")]
+    pub authors: BTreeSet<String>,
+    #[serde(default = "BTreeSet::default")]
+    pub tags: BTreeSet<String>,
     #[serde(default, alias = "publishingDate")]
     pub publishing_date: Option<DateIndex>,
     #[serde(default, alias = "lastEditDate")]
     pub last_edit_date: Option<DateIndex>,
+    #[serde(default, alias = "expiryDate")]
+    pub expiry_date: Option<DateIndex>,
+    pub draft: bool,
+    #[serde(default)]
+    pub lang: Option<String>,
+    #[serde(default, alias = "translationKey")]
+    pub translation_key: Option<String>,
+    #[serde(default)]
+    pub series: Option<String>,
+    #[serde(default, alias = "seriesPart")]
+    pub series_part: Option<u32>,
+    #[serde(default)]
+    pub navigation: Option<NavigationScope>,
     #[serde(default = "HashMap::default")]
     pub data: HashMap<String, Value>,
 }
@@ -98,45 +293,115 @@ impl From<&Metadata> for MetadataIndex {
             tags: m.tags.iter().map(|v| v.to_string()).collect(),
             publishing_date: m.publishing_date.map(DateIndex::from),
             last_edit_date: m.last_edit_date.map(DateIndex::from),
+            expiry_date: m.expiry_date.map(DateIndex::from),
+            draft: m.is_draft(),
+            lang: m.lang.as_ref().map(|v| v.to_string()),
+            translation_key: m.translation_key.as_ref().map(|v| v.to_string()),
+            series: m.series.as_ref().map(|v| v.to_string()),
+            series_part: m.series_part,
+            navigation: m.navigation,
             data: m.data.clone(),
         }
     }
 }
 
-impl From<&Arc<dyn Page>> for PageIndex {
-    fn from(page: &Arc<dyn Page>) -> Self {
-        let page_path = page.path();
-        let page_uri: String = if let Some(last) = page_path.last() {
+impl PageIndex {
+    pub fn uri_from_path(page_path: &[String]) -> String {
+        if let Some(last) = page_path.last() {
             if last == "index.html" || last == "index.htm" {
                 if page_path.len() == 1 {
-                    "/".to_string()
-                } else {
-                    "/".to_string() + &(page_path[0..page_path.len() - 1].join("/")) + "/"
+                    return "/".to_string();
                 }
-            } else {
-                "/".to_string() + &page_path.join("/")
+                return "/".to_string() + &(page_path[0..page_path.len() - 1].join("/")) + "/";
             }
-        } else {
-            "/".to_string() + &page_path.join("/")
-        };
+        }
+        "/".to_string() + &page_path.join("/")
+    }
+
+    // reverse of uri_from_path : candidate page paths matching an uri
+    pub fn paths_from_uri(page_uri: &str) -> Vec<Vec<String>> {
+        let page_path: Vec<String> = page_uri.split('/').filter(|s| !s.is_empty()).map(|s| s.to_string()).collect();
+        if page_uri.ends_with('/') {
+            return ["index.html", "index.htm"]
+                .iter()
+                .map(|index| {
+                    let mut index_path = page_path.clone();
+                    index_path.push(index.to_string());
+                    index_path
+                })
+                .collect();
+        }
+        vec![page_path]
+    }
+}
+
+impl From<&Arc<dyn Page>> for PageIndex {
+    fn from(page: &Arc<dyn Page>) -> Self {
+        let page_path = page.path();
+        let mut metadata = page.metadata().map(MetadataIndex::from);
+        // localized pages without translation key are linked to the translations sharing their unlocalized path
+        if let Some(m) = &mut metadata {
+            if let (Some(lang), None) = (&m.lang, &m.translation_key) {
+                m.translation_key = Some(unlocalized_path(page_path, lang).join("/"));
+            }
+        }
 
         PageIndex {
             page_ref: PageRef { path: page_path.to_vec() },
-            page_uri,
-            metadata: page.metadata().map(MetadataIndex::from),
+            page_uri: PageIndex::uri_from_path(page_path),
+            metadata,
+            prev: None,
+            next: None,
+            series: None,
         }
     }
 }
 
+// terms of a page for a taxonomy : a single data value or a list of values
+pub fn taxonomy_terms(data: &HashMap<String, Value>, taxonomy: &str) -> Vec<String> {
+    let term = |value: &Value| match value {
+        Value::String(v) => Some(v.to_string()),
+        Value::I32(v) => Some(v.to_string()),
+        Value::I64(v) => Some(v.to_string()),
+        Value::U64(v) => Some(v.to_string()),
+        Value::Bool(v) => Some(v.to_string()),
+        _ => None,
+    };
+    match data.get(taxonomy) {
+        Some(Value::Vec(values)) => values.iter().filter_map(term).collect(),
+        Some(value) => term(value).into_iter().collect(),
+        None => vec![],
+    }
+}
+
 impl From<&Arc<dyn PageBundle>> for BundleIndex {
     fn from(bundle: &Arc<dyn PageBundle>) -> Self {
+        BundleIndex::new(bundle, &[])
+    }
+}
+
+impl BundleIndex {
+    // index of bundle pages, terms of the declared taxonomies are read from pages data
+    pub fn new(bundle: &Arc<dyn PageBundle>, taxonomies: &[String]) -> Self {
         let mut result = BundleIndex {
             all_authors: Default::default(),
             all_tags: Default::default(),
+            all_langs: Default::default(),
             all_pages: vec![],
             pages_by_author: Default::default(),
             pages_by_tag: Default::default(),
+            pages_by_lang: Default::default(),
+            all_terms: Default::default(),
+            pages_by_term: Default::default(),
+            digest: Default::default(),
+            page_positions: Default::default(),
+            translation_positions: Default::default(),
+            sections: Default::default(),
         };
+        for taxonomy in taxonomies {
+            result.all_terms.insert(taxonomy.to_string(), BTreeSet::new());
+            result.pages_by_term.insert(taxonomy.to_string(), HashMap::new());
+        }
         for page in bundle.pages() {
             let page_index = PageIndex::from(page);
             let page_ref = page_index.page_ref.clone();
@@ -158,6 +423,22 @@ impl From<&Arc<dyn PageBundle>> for BundleIndex {
                     result.all_authors.insert(author.as_ref().clone());
                     result.pages_by_author.entry(author.name.to_string()).or_insert_with(Vec::new).push(page_ref.clone());
                 }
+
+                if let Some(lang) = &metadata.lang {
+                    result.all_langs.insert(lang.to_string());
+                    result.pages_by_lang.entry(lang.to_string()).or_insert_with(Vec::new).push(page_ref.clone());
+                }
+
+                for taxonomy in taxonomies {
+                    for term in taxonomy_terms(&metadata.data, taxonomy) {
+                        if let Some(terms) = result.all_terms.get_mut(taxonomy) {
+                            terms.insert(term.to_string());
+                        }
+                        if let Some(pages) = result.pages_by_term.get_mut(taxonomy) {
+                            pages.entry(term).or_insert_with(Vec::new).push(page_ref.clone());
+                        }
+                    }
+                }
             }
         }
         result.all_pages.sort_by(|a, b| match (&a.metadata, &b.metadata) {
@@ -166,34 +447,89 @@ impl From<&Arc<dyn PageBundle>> for BundleIndex {
             (Some(_), None) => Ordering::Less,
             _ => Ordering::Equal,
         });
+        result.link_pages();
         result
     }
 }
 
+// window and order of query results : sorting is set here rather than on BundleQuery,
+// as queries are filters also used as stage selectors where pages have no order
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BundlePagination {
     pub skip: Option<usize>,
     pub limit: Option<usize>,
+    // pages are sorted by descending publishing date unless set
+    #[serde(default, alias = "sortBy")]
+    pub sort_by: Option<SortBy>,
 }
 
+// also parsed from selector expressions, see Deserialize impl below ; results are ordered by BundlePagination
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
-#[serde(untagged)]
+#[serde(untagged, remote = "Self")]
 pub enum BundleQuery {
-    Path { path: String },
-    Tag { tag: String },
-    Tags { tags: Vec<String> },
-    Author { author: String },
-    Authors { authors: Vec<String> },
-    And { and: Vec<BundleQuery> },
-    Or { or: Vec<BundleQuery> },
-    Not { not: Box<BundleQuery> },
+    Path {
+        path: String,
+    },
+    Tag {
+        tag: String,
+    },
+    Tags {
+        tags: Vec<String>,
+    },
+    Author {
+        author: String,
+    },
+    Authors {
+        authors: Vec<String>,
+    },
+    Lang {
+        lang: String,
+    },
+    Taxonomy {
+        taxonomy: String,
+        term: String,
+    },
+    Ext {
+        ext: String,
+    },
+    Publishing {
+        publishing: Box<DateFilter>,
+    },
+    LastEdit {
+        #[serde(alias = "lastEdit")]
+        last_edit: Box<DateFilter>,
+    },
+    TitleContains {
+        #[serde(alias = "titleContains")]
+        title_contains: String,
+    },
+    SummaryContains {
+        #[serde(alias = "summaryContains")]
+        summary_contains: String,
+    },
+    Data {
+        data: Box<DataQuery>,
+    },
+    PathRegex {
+        #[serde(alias = "pathRegex")]
+        path_regex: Box<PathRegex>,
+    },
+    And {
+        and: Vec<BundleQuery>,
+    },
+    Or {
+        or: Vec<BundleQuery>,
+    },
+    Not {
+        not: Box<BundleQuery>,
+    },
     Always,
 }
 
 impl BundleQuery {
     pub fn do_match(&self, page: &PageIndex) -> bool {
         match self {
-            BundleQuery::Path { path } => PathSelector::select_page(&page.page_ref.path, &path.split('/').map(|s| s.to_string()).collect::<Vec<String>>()),
+            BundleQuery::Path { path } => glob_match_path(&page.page_ref.path, &path.split('/').map(|s| s.to_string()).collect::<Vec<String>>()),
             BundleQuery::Tag { tag } => {
                 if let Some(m) = &page.metadata {
                     for t in &m.tags {
@@ -222,6 +558,24 @@ impl BundleQuery {
                 and: authors.iter().map(|author| BundleQuery::Author { author: author.clone() }).collect(),
             }
             .do_match(page),
+            BundleQuery::Lang { lang } => matches!(&page.metadata, Some(MetadataIndex { lang: Some(l), .. }) if l == lang),
+            BundleQuery::Taxonomy { taxonomy, term } => match &page.metadata {
+                Some(m) => taxonomy_terms(&m.data, taxonomy).contains(term),
+                None => false,
+            },
+            BundleQuery::Ext { ext } => page.page_ref.path.last().map(|name| name.ends_with(ext.as_str())).unwrap_or(false),
+            BundleQuery::Publishing { publishing } => match page.metadata.as_ref().and_then(|m| m.publishing_date.as_ref()) {
+                Some(date) => publishing.query.match_query(&date.timestamp),
+                None => false,
+            },
+            BundleQuery::LastEdit { last_edit } => match page.metadata.as_ref().and_then(|m| m.last_edit_date.as_ref()) {
+                Some(date) => last_edit.query.match_query(&date.timestamp),
+                None => false,
+            },
+            BundleQuery::TitleContains { title_contains } => contains_text(page.metadata.as_ref().and_then(|m| m.title.as_ref()), title_contains),
+            BundleQuery::SummaryContains { summary_contains } => contains_text(page.metadata.as_ref().and_then(|m| m.summary.as_ref()), summary_contains),
+            BundleQuery::Data { data } => page.metadata.as_ref().map(|m| data.match_data(&m.data)).unwrap_or(false),
+            BundleQuery::PathRegex { path_regex } => path_regex.match_path(&page.page_ref.path),
             BundleQuery::And { and } => {
                 for q in and {
                     if !q.do_match(page) {
@@ -244,8 +598,52 @@ impl BundleQuery {
     }
 }
 
+impl Serialize for BundleQuery {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        BundleQuery::serialize(self, serializer)
+    }
+}
+
+// strings are selector expressions : `tag:rust and not path:drafts/**`
+impl<'de> Deserialize<'de> for BundleQuery {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum QueryOrExpr {
+            Expr(String),
+            Query(#[serde(deserialize_with = "BundleQuery::deserialize")] BundleQuery),
+        }
+        match QueryOrExpr::deserialize(deserializer)? {
+            QueryOrExpr::Expr(expr) => BundleQuery::from_str(&expr).map_err(D::Error::custom),
+            QueryOrExpr::Query(query) => Ok(query),
+        }
+    }
+}
+
+// stage side use of the query language
+impl Selector for BundleQuery {
+    fn select(&self, page: &Arc<dyn Page>) -> bool {
+        self.do_match(&PageIndex::from(page))
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+}
+
 impl BundleIndex {
     pub fn query(&self, q: &BundleQuery, p: &BundlePagination) -> Vec<&PageIndex> {
+        if let Some(sort_by) = &p.sort_by {
+            let mut matched = self.all_pages.iter().filter(|page| q.do_match(page)).collect::<Vec<&PageIndex>>();
+            sort_by.sort(&mut matched);
+            return matched.into_iter().skip(p.skip.unwrap_or(0)).take(p.limit.unwrap_or(usize::MAX)).collect();
+        }
         let mut result = vec![];
         let mut matched_counter = 0;
         for page in &self.all_pages {
@@ -2,9 +2,9 @@
 mod tests {
     use crate::config::Value;
     use crate::pages::test_page::TestPage;
-    use crate::pages::{Author, BundleIndex, BundlePagination, BundleQuery, DateIndex, Metadata, MetadataIndex, PageBundle, PageIndex, PageRef, VecBundle};
+    use crate::pages::{Author, BundleIndex, BundlePagination, BundleQuery, DateIndex, Metadata, MetadataIndex, NavigationScope, Page, PageBundle, PageIndex, PageRef, SeriesIndex, VecBundle};
     use std::array::IntoIter;
-    use std::collections::{HashMap, HashSet};
+    use std::collections::{BTreeSet, HashMap, HashSet};
     use std::iter::FromIterator;
     use std::sync::Arc;
 
@@ -19,11 +19,12 @@ mod tests {
                     authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                         name: "f1 author".to_string(),
                         contacts: Default::default(),
+                        profile: None,
                     })])),
                     tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
                     publishing_date: Some(1637582000),
                     last_edit_date: Some(1637581000),
-                    data: HashMap::default(),
+                    ..Default::default()
                 }),
                 content: String::new(),
             })],
@@ -43,8 +44,8 @@ mod tests {
                         title: Some("f1 & xyz title".to_string()),
                         url_title: Some("f1_%26_xyz_title".to_string()),
                         summary: Some("f1 summary".to_string()),
-                        authors: HashSet::from_iter(IntoIter::new(["f1 author".to_string()])),
-                        tags: HashSet::from_iter(IntoIter::new(["t1".to_string(), "t2".to_string(), "t3".to_string()])),
+                        authors: BTreeSet::from_iter(IntoIter::new(["f1 author".to_string()])),
+                        tags: BTreeSet::from_iter(IntoIter::new(["t1".to_string(), "t2".to_string(), "t3".to_string()])),
                         publishing_date: Some(DateIndex {
                             timestamp: 1637582000,
                             i_year: 2021,
@@ -77,14 +78,25 @@ mod tests {
                             i_minute: 36,
                             i_second: 40
                         }),
+                        expiry_date: None,
+                        draft: false,
+                        lang: None,
+                        translation_key: None,
+                        series: None,
+                        series_part: None,
+                        navigation: None,
                         data: HashMap::default(),
-                    })
+                    }),
+                    prev: None,
+                    next: None,
+                    series: None,
                 }],
-                all_authors: HashSet::from_iter(IntoIter::new([Author {
+                all_authors: BTreeSet::from_iter(IntoIter::new([Author {
                     name: "f1 author".to_string(),
-                    contacts: Default::default()
+                    contacts: Default::default(),
+                    profile: None,
                 }])),
-                all_tags: HashSet::from_iter(IntoIter::new(["t1".to_string(), "t2".to_string(), "t3".to_string()])),
+                all_tags: BTreeSet::from_iter(IntoIter::new(["t1".to_string(), "t2".to_string(), "t3".to_string()])),
                 pages_by_author: HashMap::from_iter(IntoIter::new([(
                     "f1 author".to_string(),
                     vec![PageRef {
@@ -111,6 +123,14 @@ mod tests {
                         }]
                     ),
                 ])),
+                all_langs: BTreeSet::default(),
+                pages_by_lang: HashMap::default(),
+                all_terms: Default::default(),
+                pages_by_term: Default::default(),
+                digest: Default::default(),
+                page_positions: Default::default(),
+                translation_positions: Default::default(),
+                sections: Default::default(),
             }
         );
     }
@@ -127,10 +147,9 @@ mod tests {
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "f1 author".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([
                             ("a".to_string(), Value::String("a".to_string())),
                             (
@@ -139,6 +158,7 @@ mod tests {
                             ),
                             ("c".to_string(), Value::I32(10)),
                         ])),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -156,16 +176,16 @@ mod tests {
                             Arc::new(Author {
                                 name: "f3 author 1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "f3 author 2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t3".to_string()), Arc::new("t4".to_string())])),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -177,11 +197,9 @@ mod tests {
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "f3 author 1".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
-                        tags: HashSet::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -203,10 +221,17 @@ mod tests {
                             title: Some("f1 title".to_string()),
                             url_title: Some("f1_title".to_string()),
                             summary: Some("f1 summary".to_string()),
-                            authors: HashSet::from_iter(IntoIter::new(["f1 author".to_string()])),
-                            tags: HashSet::from_iter(IntoIter::new(["t1".to_string(), "t2".to_string(), "t3".to_string()])),
+                            authors: BTreeSet::from_iter(IntoIter::new(["f1 author".to_string()])),
+                            tags: BTreeSet::from_iter(IntoIter::new(["t1".to_string(), "t2".to_string(), "t3".to_string()])),
                             publishing_date: None,
                             last_edit_date: None,
+                            expiry_date: None,
+                            draft: false,
+                            lang: None,
+                            translation_key: None,
+                            series: None,
+                            series_part: None,
+                            navigation: None,
                             data: HashMap::from_iter(IntoIter::new([
                                 ("a".to_string(), Value::String("a".to_string())),
                                 (
@@ -215,7 +240,10 @@ mod tests {
                                 ),
                                 ("c".to_string(), Value::I32(10)),
                             ])),
-                        })
+                        }),
+                        prev: None,
+                        next: None,
+                        series: None,
                     },
                     PageIndex {
                         page_ref: PageRef { path: vec!["f3".to_string()] },
@@ -224,12 +252,22 @@ mod tests {
                             title: Some("f3 title".to_string()),
                             url_title: Some("f3_title".to_string()),
                             summary: Some("f3 summary".to_string()),
-                            authors: HashSet::from_iter(IntoIter::new(["f3 author 1".to_string(), "f3 author 2".to_string()])),
-                            tags: HashSet::from_iter(IntoIter::new(["t3".to_string(), "t4".to_string()])),
+                            authors: BTreeSet::from_iter(IntoIter::new(["f3 author 1".to_string(), "f3 author 2".to_string()])),
+                            tags: BTreeSet::from_iter(IntoIter::new(["t3".to_string(), "t4".to_string()])),
                             publishing_date: None,
                             last_edit_date: None,
+                            expiry_date: None,
+                            draft: false,
+                            lang: None,
+                            translation_key: None,
+                            series: None,
+                            series_part: None,
+                            navigation: None,
                             data: HashMap::default(),
-                        })
+                        }),
+                        prev: None,
+                        next: None,
+                        series: None,
                     },
                     PageIndex {
                         page_ref: PageRef { path: vec!["f4".to_string()] },
@@ -238,17 +276,30 @@ mod tests {
                             title: Some("f4 title".to_string()),
                             url_title: Some("f4_title".to_string()),
                             summary: Some("f4 summary".to_string()),
-                            authors: HashSet::from_iter(IntoIter::new(["f3 author 1".to_string()])),
-                            tags: HashSet::default(),
+                            authors: BTreeSet::from_iter(IntoIter::new(["f3 author 1".to_string()])),
+                            tags: BTreeSet::default(),
                             publishing_date: None,
                             last_edit_date: None,
+                            expiry_date: None,
+                            draft: false,
+                            lang: None,
+                            translation_key: None,
+                            series: None,
+                            series_part: None,
+                            navigation: None,
                             data: HashMap::default(),
-                        })
+                        }),
+                        prev: None,
+                        next: None,
+                        series: None,
                     },
                     PageIndex {
                         page_ref: PageRef { path: vec!["f2".to_string()] },
                         page_uri: "/f2".to_string(),
-                        metadata: None
+                        metadata: None,
+                        prev: None,
+                        next: None,
+                        series: None,
                     },
                 ],
                 pages_by_tag: HashMap::from_iter(IntoIter::new([
@@ -285,22 +336,33 @@ mod tests {
                     ("f3 author 1".to_string(), vec![PageRef { path: vec!["f3".to_string()] }, PageRef { path: vec!["f4".to_string()] }]),
                     ("f3 author 2".to_string(), vec![PageRef { path: vec!["f3".to_string()] }]),
                 ])),
-                all_tags: HashSet::from_iter(IntoIter::new(["t1".to_string(), "t2".to_string(), "t3".to_string(), "t4".to_string()])),
+                all_tags: BTreeSet::from_iter(IntoIter::new(["t1".to_string(), "t2".to_string(), "t3".to_string(), "t4".to_string()])),
 
-                all_authors: HashSet::from_iter(IntoIter::new([
+                all_authors: BTreeSet::from_iter(IntoIter::new([
                     Author {
                         name: "f1 author".to_string(),
-                        contacts: Default::default()
+                        contacts: Default::default(),
+                        profile: None,
                     },
                     Author {
                         name: "f3 author 1".to_string(),
-                        contacts: Default::default()
+                        contacts: Default::default(),
+                        profile: None,
                     },
                     Author {
                         name: "f3 author 2".to_string(),
-                        contacts: Default::default()
+                        contacts: Default::default(),
+                        profile: None,
                     }
-                ]))
+                ])),
+                all_langs: BTreeSet::default(),
+                pages_by_lang: HashMap::default(),
+                all_terms: Default::default(),
+                pages_by_term: Default::default(),
+                digest: Default::default(),
+                page_positions: Default::default(),
+                translation_positions: Default::default(),
+                sections: Default::default(),
             }
         );
     }
@@ -312,39 +374,24 @@ mod tests {
                 Arc::new(TestPage {
                     path: vec!["f1".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(1637583000),
-                        last_edit_date: None,
-                        data: Default::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
                 Arc::new(TestPage {
                     path: vec!["f2".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(1637582000),
-                        last_edit_date: None,
-                        data: Default::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
                 Arc::new(TestPage {
                     path: vec!["f3".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(1637584000),
-                        last_edit_date: None,
-                        data: Default::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -383,8 +430,18 @@ mod tests {
                                 i_second: 40
                             }),
                             last_edit_date: None,
+                            expiry_date: None,
+                            draft: false,
+                            lang: None,
+                            translation_key: None,
+                            series: None,
+                            series_part: None,
+                            navigation: None,
                             data: Default::default()
-                        })
+                        }),
+                        prev: Some(PageRef { path: vec!["f1".to_string()] }),
+                        next: None,
+                        series: None,
                     },
                     PageIndex {
                         page_ref: PageRef { path: vec!["f1".to_string()] },
@@ -412,8 +469,18 @@ mod tests {
                                 i_second: 00
                             }),
                             last_edit_date: None,
+                            expiry_date: None,
+                            draft: false,
+                            lang: None,
+                            translation_key: None,
+                            series: None,
+                            series_part: None,
+                            navigation: None,
                             data: Default::default()
-                        })
+                        }),
+                        prev: Some(PageRef { path: vec!["f2".to_string()] }),
+                        next: Some(PageRef { path: vec!["f3".to_string()] }),
+                        series: None,
                     },
                     PageIndex {
                         page_ref: PageRef { path: vec!["f2".to_string()] },
@@ -441,14 +508,32 @@ mod tests {
                                 i_second: 20
                             }),
                             last_edit_date: None,
+                            expiry_date: None,
+                            draft: false,
+                            lang: None,
+                            translation_key: None,
+                            series: None,
+                            series_part: None,
+                            navigation: None,
                             data: Default::default()
-                        })
+                        }),
+                        prev: None,
+                        next: Some(PageRef { path: vec!["f1".to_string()] }),
+                        series: None,
                     },
                 ],
                 pages_by_tag: HashMap::default(),
                 pages_by_author: HashMap::default(),
-                all_tags: HashSet::default(),
-                all_authors: HashSet::default()
+                all_tags: BTreeSet::default(),
+                all_authors: BTreeSet::default(),
+                all_langs: BTreeSet::default(),
+                pages_by_lang: HashMap::default(),
+                all_terms: Default::default(),
+                pages_by_term: Default::default(),
+                digest: Default::default(),
+                page_positions: Default::default(),
+                translation_positions: Default::default(),
+                sections: Default::default(),
             }
         );
     }
@@ -471,16 +556,17 @@ mod tests {
                             Arc::new(Author {
                                 name: "f2 author 1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "f2 author 2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t3".to_string()), Arc::new("t4".to_string())])),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -495,11 +581,22 @@ mod tests {
                     page_ref: PageRef { path: vec!["f1".to_string()] },
                     page_uri: "/f1".to_string(),
                     metadata: None,
+                    prev: None,
+                    next: None,
+                    series: None,
                 }],
-                all_authors: HashSet::default(),
-                all_tags: HashSet::default(),
+                all_authors: BTreeSet::default(),
+                all_tags: BTreeSet::default(),
                 pages_by_author: HashMap::default(),
                 pages_by_tag: HashMap::default(),
+                all_langs: BTreeSet::default(),
+                pages_by_lang: HashMap::default(),
+                all_terms: Default::default(),
+                pages_by_term: Default::default(),
+                digest: Default::default(),
+                page_positions: Default::default(),
+                translation_positions: Default::default(),
+                sections: Default::default(),
             }
         );
     }
@@ -516,11 +613,10 @@ mod tests {
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "f1 author".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -533,16 +629,16 @@ mod tests {
                             Arc::new(Author {
                                 name: "f3 author 1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "f3 author 2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t3".to_string()), Arc::new("t4".to_string())])),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -554,11 +650,9 @@ mod tests {
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "f3 author".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
-                        tags: HashSet::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -572,25 +666,47 @@ mod tests {
 
         let bundle_index = BundleIndex::from(&vec_bundle);
 
-        let result = bundle_index.query(&BundleQuery::Always, &BundlePagination { skip: None, limit: None });
+        let result = bundle_index.query(
+            &BundleQuery::Always,
+            &BundlePagination {
+                skip: None,
+                limit: None,
+                sort_by: None,
+            },
+        );
         assert_eq!(result, bundle_index.all_pages.iter().collect::<Vec<&PageIndex>>());
 
         let result = bundle_index.query(
             &BundleQuery::Or {
                 or: vec![BundleQuery::Author { author: "f3 author".to_string() }, BundleQuery::Author { author: "f1 author".to_string() }],
             },
-            &BundlePagination { skip: None, limit: None },
+            &BundlePagination {
+                skip: None,
+                limit: None,
+                sort_by: None,
+            },
         );
         assert_eq!(result, vec![bundle_index.all_pages.get(0).unwrap(), bundle_index.all_pages.get(2).unwrap()]);
 
-        let result = bundle_index.query(&BundleQuery::Tag { tag: "t1".to_string() }, &BundlePagination { skip: None, limit: None });
+        let result = bundle_index.query(
+            &BundleQuery::Tag { tag: "t1".to_string() },
+            &BundlePagination {
+                skip: None,
+                limit: None,
+                sort_by: None,
+            },
+        );
         assert_eq!(result, vec![bundle_index.all_pages.get(0).unwrap()]);
 
         let result = bundle_index.query(
             &BundleQuery::And {
                 and: vec![BundleQuery::Tag { tag: "t3".to_string() }, BundleQuery::Tag { tag: "t2".to_string() }],
             },
-            &BundlePagination { skip: None, limit: None },
+            &BundlePagination {
+                skip: None,
+                limit: None,
+                sort_by: None,
+            },
         );
         assert_eq!(result, vec![bundle_index.all_pages.get(0).unwrap()]);
     }
@@ -607,11 +723,10 @@ mod tests {
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "f1 author".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -629,16 +744,16 @@ mod tests {
                             Arc::new(Author {
                                 name: "f3 author 1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "f3 author 2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t3".to_string()), Arc::new("t4".to_string())])),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -650,11 +765,9 @@ mod tests {
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "f3 author".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
-                        tags: HashSet::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -663,16 +776,291 @@ mod tests {
 
         let bundle_index = BundleIndex::from(&vec_bundle);
 
-        let result = bundle_index.query(&BundleQuery::Always, &BundlePagination { skip: None, limit: None });
+        let result = bundle_index.query(
+            &BundleQuery::Always,
+            &BundlePagination {
+                skip: None,
+                limit: None,
+                sort_by: None,
+            },
+        );
         assert_eq!(result, bundle_index.all_pages.iter().collect::<Vec<&PageIndex>>());
 
-        let result = bundle_index.query(&BundleQuery::Always, &BundlePagination { skip: None, limit: Some(2) });
+        let result = bundle_index.query(
+            &BundleQuery::Always,
+            &BundlePagination {
+                skip: None,
+                limit: Some(2),
+                sort_by: None,
+            },
+        );
         assert_eq!(result, vec![bundle_index.all_pages.get(0).unwrap(), bundle_index.all_pages.get(1).unwrap()]);
 
-        let result = bundle_index.query(&BundleQuery::Always, &BundlePagination { skip: Some(1), limit: Some(1) });
+        let result = bundle_index.query(
+            &BundleQuery::Always,
+            &BundlePagination {
+                skip: Some(1),
+                limit: Some(1),
+                sort_by: None,
+            },
+        );
         assert_eq!(result, vec![bundle_index.all_pages.get(1).unwrap()]);
 
-        let result = bundle_index.query(&BundleQuery::Always, &BundlePagination { skip: Some(2), limit: None });
+        let result = bundle_index.query(
+            &BundleQuery::Always,
+            &BundlePagination {
+                skip: Some(2),
+                limit: None,
+                sort_by: None,
+            },
+        );
         assert_eq!(result, vec![bundle_index.all_pages.get(2).unwrap(), bundle_index.all_pages.get(3).unwrap()]);
     }
+
+    fn localized_page(path: &str, lang: &str, translation_key: Option<&str>) -> Arc<dyn Page> {
+        Arc::new(TestPage {
+            path: path.split('/').map(|s| s.to_string()).collect(),
+            metadata: Some(Metadata {
+                lang: Some(Arc::new(lang.to_string())),
+                translation_key: translation_key.map(|k| Arc::new(k.to_string())),
+                ..Default::default()
+            }),
+            content: String::new(),
+        })
+    }
+
+    #[test]
+    fn index_localized_pages_and_their_translations() {
+        let vec_bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                localized_page("docs/intro.html", "en", None),
+                localized_page("fr/docs/intro.html", "fr", None),
+                localized_page("de/docs/intro.de.html", "de", None),
+                localized_page("docs/setup.html", "en", Some("setup")),
+                localized_page("fr/docs/installation.html", "fr", Some("setup")),
+                localized_page("fr/docs/faq.html", "fr", None),
+            ],
+        });
+
+        let bundle_index = BundleIndex::from(&vec_bundle);
+        assert_eq!(bundle_index.all_langs, BTreeSet::from_iter(IntoIter::new(["en".to_string(), "fr".to_string(), "de".to_string()])));
+        assert_eq!(
+            bundle_index.pages_by_lang.get("fr"),
+            Some(&vec![
+                PageRef {
+                    path: vec!["fr".to_string(), "docs".to_string(), "intro.html".to_string()]
+                },
+                PageRef {
+                    path: vec!["fr".to_string(), "docs".to_string(), "installation.html".to_string()]
+                },
+                PageRef {
+                    path: vec!["fr".to_string(), "docs".to_string(), "faq.html".to_string()]
+                },
+            ])
+        );
+
+        let uris = |pages: Vec<&PageIndex>| pages.iter().map(|p| p.page_uri.to_string()).collect::<Vec<String>>();
+        let page = |uri: &str| bundle_index.all_pages.iter().find(|p| p.page_uri == uri).unwrap();
+        assert_eq!(uris(bundle_index.translations(page("/docs/intro.html"))), vec!["/de/docs/intro.de.html", "/fr/docs/intro.html"]);
+        assert_eq!(uris(bundle_index.translations(page("/fr/docs/intro.html"))), vec!["/de/docs/intro.de.html", "/docs/intro.html"]);
+        assert_eq!(uris(bundle_index.translations(page("/fr/docs/installation.html"))), vec!["/docs/setup.html"]);
+        assert!(bundle_index.translations(page("/fr/docs/faq.html")).is_empty());
+
+        let result = bundle_index.query(
+            &BundleQuery::And {
+                and: vec![BundleQuery::Lang { lang: "fr".to_string() }, BundleQuery::Path { path: "**/intro.html".to_string() }],
+            },
+            &BundlePagination {
+                skip: None,
+                limit: None,
+                sort_by: None,
+            },
+        );
+        assert_eq!(uris(result), vec!["/fr/docs/intro.html"]);
+    }
+
+    fn navigable_page(path: &str, publishing_date: Option<i64>, series: Option<(&str, Option<u32>)>, navigation: Option<NavigationScope>) -> Arc<dyn Page> {
+        Arc::new(TestPage {
+            path: path.split('/').map(|s| s.to_string()).collect(),
+            metadata: Some(Metadata {
+                publishing_date,
+                series: series.map(|(name, _)| Arc::new(name.to_string())),
+                series_part: series.and_then(|(_, part)| part),
+                navigation,
+                ..Default::default()
+            }),
+            content: String::new(),
+        })
+    }
+
+    #[test]
+    fn link_pages_by_date_directory_and_series() {
+        let vec_bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                navigable_page("blog/b.html", Some(300), None, None),
+                navigable_page("blog/a.html", Some(100), None, None),
+                navigable_page("news/c.html", Some(200), None, None),
+                navigable_page("undated.html", None, None, None),
+                navigable_page("docs/2.html", None, None, Some(NavigationScope::Directory)),
+                navigable_page("docs/1.html", None, None, Some(NavigationScope::Directory)),
+                navigable_page("rust/setup.html", Some(400), Some(("rust", Some(1))), None),
+                navigable_page("rust/macros.html", Some(500), Some(("rust", Some(3))), None),
+                navigable_page("rust/traits.html", Some(600), Some(("rust", Some(2))), None),
+            ],
+        });
+
+        let bundle_index = BundleIndex::from(&vec_bundle);
+        let page_ref = |path: &str| PageRef {
+            path: path.split('/').map(|s| s.to_string()).collect(),
+        };
+        let links = |path: &str| {
+            let page = bundle_index.page_index(&vec_bundle.pages().iter().find(|p| p.path().join("/") == path).unwrap().clone());
+            (page.prev, page.next)
+        };
+        assert_eq!(links("blog/a.html"), (None, Some(page_ref("news/c.html"))));
+        assert_eq!(links("news/c.html"), (Some(page_ref("blog/a.html")), Some(page_ref("blog/b.html"))));
+        assert_eq!(links("blog/b.html"), (Some(page_ref("news/c.html")), None));
+        assert_eq!(links("undated.html"), (None, None));
+        assert_eq!(links("docs/1.html"), (None, Some(page_ref("docs/2.html"))));
+        assert_eq!(links("docs/2.html"), (Some(page_ref("docs/1.html")), None));
+        assert_eq!(links("rust/setup.html"), (None, Some(page_ref("rust/traits.html"))));
+        assert_eq!(links("rust/traits.html"), (Some(page_ref("rust/setup.html")), Some(page_ref("rust/macros.html"))));
+        assert_eq!(links("rust/macros.html"), (Some(page_ref("rust/traits.html")), None));
+
+        let series = |uri: &str| bundle_index.all_pages.iter().find(|p| p.page_uri == uri).unwrap().series.clone();
+        assert_eq!(
+            series("/rust/traits.html"),
+            Some(SeriesIndex {
+                name: "rust".to_string(),
+                part: 2,
+                total: 3
+            })
+        );
+        assert_eq!(series("/blog/a.html"), None);
+    }
+
+    #[test]
+    fn index_and_query_taxonomy_terms() {
+        let page = |path: &str, data: Vec<(&str, Value)>| -> Arc<dyn Page> {
+            Arc::new(TestPage {
+                path: vec![path.to_string()],
+                metadata: Some(Metadata {
+                    data: data.into_iter().map(|(k, v)| (k.to_string(), v)).collect(),
+                    ..Default::default()
+                }),
+                content: String::new(),
+            })
+        };
+        let vec_bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                page(
+                    "f1",
+                    vec![
+                        ("categories", Value::Vec(vec![Value::String("rust".to_string()), Value::String("web".to_string())])),
+                        ("difficulty", Value::I32(2)),
+                    ],
+                ),
+                page("f2", vec![("categories", Value::String("rust".to_string())), ("products", Value::String("p1".to_string()))]),
+            ],
+        });
+
+        let bundle_index = BundleIndex::new(&vec_bundle, &["categories".to_string(), "difficulty".to_string(), "topics".to_string()]);
+        assert_eq!(
+            bundle_index.all_terms.get("categories"),
+            Some(&BTreeSet::from_iter(IntoIter::new(["rust".to_string(), "web".to_string()])))
+        );
+        assert_eq!(bundle_index.all_terms.get("difficulty"), Some(&BTreeSet::from_iter(IntoIter::new(["2".to_string()]))));
+        assert_eq!(bundle_index.all_terms.get("topics"), Some(&BTreeSet::new()));
+        // undeclared taxonomies are not indexed
+        assert_eq!(bundle_index.all_terms.get("products"), None);
+        assert_eq!(
+            bundle_index.pages_by_term.get("categories").and_then(|pages| pages.get("rust")),
+            Some(&vec![PageRef { path: vec!["f1".to_string()] }, PageRef { path: vec!["f2".to_string()] }])
+        );
+
+        let query: BundleQuery = serde_yaml::from_str("{taxonomy: categories, term: web}").unwrap();
+        let result = bundle_index.query(
+            &query,
+            &BundlePagination {
+                skip: None,
+                limit: None,
+                sort_by: None,
+            },
+        );
+        assert_eq!(result.iter().map(|p| p.page_uri.to_string()).collect::<Vec<String>>(), vec!["/f1"]);
+    }
+
+    #[test]
+    fn query_pages_by_ext_dates_text_data_and_path_regex() {
+        let page = |path: &str, title: &str, summary: Option<&str>, publishing_date: i64, last_edit_date: Option<i64>, weight: Option<i32>| -> Arc<dyn Page> {
+            Arc::new(TestPage {
+                path: path.split('/').map(|s| s.to_string()).collect(),
+                metadata: Some(Metadata {
+                    title: Some(Arc::new(title.to_string())),
+                    summary: summary.map(|s| Arc::new(s.to_string())),
+                    publishing_date: Some(publishing_date),
+                    last_edit_date,
+                    data: weight.map(|w| ("weight".to_string(), Value::I32(w))).into_iter().collect(),
+                    ..Default::default()
+                }),
+                content: String::new(),
+            })
+        };
+        // 2021-10-01T12:00:00Z, 2021-11-01T12:00:00Z, 2021-12-01T12:00:00Z
+        let vec_bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                page("docs/intro.md", "Getting Started", Some("First steps"), 1633089600, None, Some(1)),
+                page("docs/guide/setup.html", "Setup guide", None, 1635768000, Some(1638360000), Some(3)),
+                page("blog/release.html", "Release notes", Some("What is new in this release"), 1638360000, None, None),
+            ],
+        });
+        let bundle_index = BundleIndex::from(&vec_bundle);
+        let select = |query: &str| {
+            let query: BundleQuery = serde_yaml::from_str(query).unwrap();
+            let mut paths = bundle_index
+                .query(
+                    &query,
+                    &BundlePagination {
+                        skip: None,
+                        limit: None,
+                        sort_by: None,
+                    },
+                )
+                .iter()
+                .map(|p| p.page_ref.path.join("/"))
+                .collect::<Vec<String>>();
+            paths.sort();
+            paths
+        };
+
+        assert_eq!(select("{ext: .html}"), vec!["blog/release.html", "docs/guide/setup.html"]);
+        assert_eq!(select("{publishing: {betweenDates: ['2021-10-01', '2021-11-01']}}"), vec!["docs/guide/setup.html", "docs/intro.md"]);
+        assert_eq!(select("{publishing: {afterDate: '2021-11-01'}}"), vec!["blog/release.html"]);
+        assert_eq!(select("{lastEdit: {afterTime: '2021-11-15T00:00:00'}}"), vec!["docs/guide/setup.html"]);
+        assert_eq!(select("{titleContains: GUIDE}"), vec!["docs/guide/setup.html"]);
+        assert_eq!(select("{summaryContains: release}"), vec!["blog/release.html"]);
+        assert_eq!(select("{data: {key: weight}}"), vec!["docs/guide/setup.html", "docs/intro.md"]);
+        assert_eq!(select("{data: {key: weight, eq: 1}}"), vec!["docs/intro.md"]);
+        assert_eq!(select("{data: {key: weight, gt: 1, lte: 3}}"), vec!["docs/guide/setup.html"]);
+        assert_eq!(select("{pathRegex: '^docs/.*\\.(md|html)$'}"), vec!["docs/guide/setup.html", "docs/intro.md"]);
+        assert_eq!(select("{and: [{pathRegex: '^docs/'}, {not: {ext: .md}}]}"), vec!["docs/guide/setup.html"]);
+
+        assert!(serde_yaml::from_str::<BundleQuery>("{publishing: {afterDate: 'not a date'}}").is_err());
+    }
+
+    #[test]
+    fn map_page_uris_back_to_page_paths() {
+        let to_path = |p: &[&str]| p.iter().map(|s| s.to_string()).collect::<Vec<String>>();
+
+        assert_eq!(PageIndex::uri_from_path(&to_path(&["index.html"])), "/");
+        assert_eq!(PageIndex::uri_from_path(&to_path(&["a", "b", "index.htm"])), "/a/b/");
+        assert_eq!(PageIndex::uri_from_path(&to_path(&["a", "f.html"])), "/a/f.html");
+
+        assert_eq!(PageIndex::paths_from_uri("/"), vec![to_path(&["index.html"]), to_path(&["index.htm"])]);
+        assert_eq!(PageIndex::paths_from_uri("/a/b/"), vec![to_path(&["a", "b", "index.html"]), to_path(&["a", "b", "index.htm"])]);
+        assert_eq!(PageIndex::paths_from_uri("/a/f.html"), vec![to_path(&["a", "f.html"])]);
+        for path in PageIndex::paths_from_uri("/a/b/") {
+            assert_eq!(PageIndex::uri_from_path(&path), "/a/b/");
+        }
+    }
 }
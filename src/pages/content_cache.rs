@@ -0,0 +1,140 @@
+use crate::pages::{page_content_key, BundleIndex, Env, Fingerprint, Metadata, Page, PageIndex};
+use crate::pages_error::PagesError;
+use once_cell::sync::OnceCell;
+use std::collections::{HashMap, VecDeque};
+use std::io::{Cursor, Read};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+struct CacheEntries {
+    contents: HashMap<String, Arc<[u8]>>,
+    order: VecDeque<String>,
+    size: usize,
+}
+
+// in memory cache of opened page contents, oldest entries are evicted once memory limit is reached
+pub struct ContentCache {
+    memory_limit: usize,
+    entries: Mutex<CacheEntries>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ContentCache {
+    pub fn new(memory_limit: usize) -> Self {
+        ContentCache {
+            memory_limit,
+            entries: Default::default(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn wrap(self: &Arc<Self>, page: Arc<dyn Page>) -> Arc<dyn Page> {
+        Arc::new(CachedPage {
+            cache: Arc::clone(self),
+            inner: page,
+            fingerprint: OnceCell::new(),
+            key: OnceCell::new(),
+        })
+    }
+
+    pub fn size(&self) -> anyhow::Result<usize> {
+        Ok(self.entries.lock().map_err(|e| PagesError::Exec(e.to_string()))?.size)
+    }
+
+    // returns hits and misses since last reset
+    pub fn stats(&self) -> (u64, u64) {
+        (self.hits.load(Ordering::Relaxed), self.misses.load(Ordering::Relaxed))
+    }
+
+    pub fn print_stats(&self, env: &Env) -> anyhow::Result<()> {
+        let hits = self.hits.swap(0, Ordering::Relaxed);
+        let misses = self.misses.swap(0, Ordering::Relaxed);
+        let rate = if hits + misses == 0 { 0.0 } else { hits as f64 * 100.0 / (hits + misses) as f64 };
+        env.print_vvv("Content Cache", &format!("{} hits, {} misses ({:.1}% hit rate), {} bytes cached", hits, misses, rate, self.size()?));
+        Ok(())
+    }
+
+    fn get(&self, key: &str) -> anyhow::Result<Option<Arc<[u8]>>> {
+        let content = self.entries.lock().map_err(|e| PagesError::Exec(e.to_string()))?.contents.get(key).cloned();
+        match &content {
+            None => self.misses.fetch_add(1, Ordering::Relaxed),
+            Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+        };
+        Ok(content)
+    }
+
+    fn insert(&self, key: String, content: Arc<[u8]>) -> anyhow::Result<()> {
+        if content.len() > self.memory_limit {
+            return Ok(());
+        }
+        let mut entries = self.entries.lock().map_err(|e| PagesError::Exec(e.to_string()))?;
+        if entries.contents.contains_key(&key) {
+            return Ok(());
+        }
+        while entries.size + content.len() > self.memory_limit {
+            let oldest = match entries.order.pop_front() {
+                None => break,
+                Some(k) => k,
+            };
+            if let Some(evicted) = entries.contents.remove(&oldest) {
+                entries.size -= evicted.len();
+            }
+        }
+        entries.size += content.len();
+        entries.order.push_back(key.clone());
+        entries.contents.insert(key, content);
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for ContentCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContentCache").field("memory_limit", &self.memory_limit).finish()
+    }
+}
+
+// page whose opened content is memoized by its fingerprint, pages without fingerprint are never cached
+// wrappers are made by stages on each build so that fingerprint and content key are computed once per build
+#[derive(Debug)]
+pub(crate) struct CachedPage {
+    cache: Arc<ContentCache>,
+    inner: Arc<dyn Page>,
+    fingerprint: OnceCell<Option<Fingerprint>>,
+    key: OnceCell<Option<String>>,
+}
+
+impl Page for CachedPage {
+    fn path(&self) -> &[String] {
+        self.inner.path()
+    }
+
+    fn metadata(&self) -> Option<&Metadata> {
+        self.inner.metadata()
+    }
+
+    fn open(&self, output_page: &PageIndex, output_index: &BundleIndex, env: &Env) -> anyhow::Result<Box<dyn Read>> {
+        let key = self.key.get_or_try_init(|| match self.fingerprint() {
+            None => Ok(None),
+            Some(fingerprint) => page_content_key(&fingerprint, output_page, self.inner.metadata(), output_index, &env.values_digest()?).map(Some),
+        })?;
+        let key = match key {
+            None => return self.inner.open(output_page, output_index, env),
+            Some(k) => k.clone(),
+        };
+        if let Some(content) = self.cache.get(&key)? {
+            return Ok(Box::new(Cursor::new(content)));
+        }
+        let mut content = vec![];
+        self.inner.open(output_page, output_index, env)?.read_to_end(&mut content)?;
+        let content: Arc<[u8]> = Arc::from(content);
+        self.cache.insert(key, Arc::clone(&content))?;
+        Ok(Box::new(Cursor::new(content)))
+    }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        self.fingerprint.get_or_init(|| self.inner.fingerprint()).clone()
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use crate::pages::test_page::TestPage;
+    use crate::pages::{digest_serializable, BundleIndex, ContentCache, Env, Fingerprint, Metadata, Page, PageIndex};
+    use std::io::Read;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    // counts source fingerprint computations
+    #[derive(Debug)]
+    struct CountingPage {
+        inner: TestPage,
+        fingerprints: Arc<AtomicUsize>,
+    }
+
+    impl Page for CountingPage {
+        fn path(&self) -> &[String] {
+            self.inner.path()
+        }
+
+        fn metadata(&self) -> Option<&Metadata> {
+            self.inner.metadata()
+        }
+
+        fn open(&self, output_page: &PageIndex, output_index: &BundleIndex, env: &Env) -> anyhow::Result<Box<dyn Read>> {
+            self.inner.open(output_page, output_index, env)
+        }
+
+        fn fingerprint(&self) -> Option<Fingerprint> {
+            self.fingerprints.fetch_add(1, Ordering::Relaxed);
+            self.inner.fingerprint()
+        }
+    }
+
+    fn test_page(name: &str, content: &str) -> Arc<dyn Page> {
+        Arc::new(TestPage {
+            path: vec![name.to_string()],
+            metadata: None,
+            content: content.to_string(),
+        })
+    }
+
+    #[test]
+    fn reuse_content_of_already_opened_pages() {
+        let cache = Arc::new(ContentCache::new(1024));
+        let env = Env::test().with_content_cache(Arc::clone(&cache));
+        let p1 = env.cache_content(test_page("f1.md", "f1 content"));
+        let p1_bis = env.cache_content(test_page("f1.md", "f1 content"));
+        let p2 = env.cache_content(test_page("f2.md", "f2 content"));
+
+        assert_eq!(TestPage::from((&env, &p1)).content, "f1 content");
+        assert_eq!(TestPage::from((&env, &p1)).content, "f1 content");
+        assert_eq!(TestPage::from((&env, &p1_bis)).content, "f1 content");
+        assert_eq!(TestPage::from((&env, &p2)).content, "f2 content");
+        assert_eq!(cache.stats(), (2, 2));
+        assert_eq!(cache.size().unwrap(), 20);
+    }
+
+    #[test]
+    fn evict_oldest_contents_when_memory_limit_is_reached() {
+        let cache = Arc::new(ContentCache::new(15));
+        let env = Env::test().with_content_cache(Arc::clone(&cache));
+        let p1 = env.cache_content(test_page("f1.md", "f1 content"));
+        let p2 = env.cache_content(test_page("f2.md", "f2 content"));
+        let p3 = env.cache_content(test_page("f3.md", "f3 content is too large"));
+
+        assert!(!TestPage::from((&env, &p1)).content.is_empty());
+        assert!(!TestPage::from((&env, &p2)).content.is_empty());
+        assert!(!TestPage::from((&env, &p3)).content.is_empty());
+        assert_eq!(cache.size().unwrap(), 10);
+        assert!(!TestPage::from((&env, &p2)).content.is_empty());
+        assert!(!TestPage::from((&env, &p1)).content.is_empty());
+        assert_eq!(cache.stats(), (1, 4));
+        assert_eq!(cache.size().unwrap(), 10);
+    }
+
+    #[test]
+    fn compute_page_fingerprint_once() {
+        let cache = Arc::new(ContentCache::new(1024));
+        let env = Env::test().with_content_cache(Arc::clone(&cache));
+        let fingerprints = Arc::new(AtomicUsize::new(0));
+        let page = env.cache_content(Arc::new(CountingPage {
+            inner: TestPage {
+                path: vec!["f1.md".to_string()],
+                metadata: None,
+                content: "f1 content".to_string(),
+            },
+            fingerprints: Arc::clone(&fingerprints),
+        }));
+
+        for _ in 0..3 {
+            assert_eq!(TestPage::from((&env, &page)).content, "f1 content");
+        }
+        assert!(page.fingerprint().is_some());
+        assert_eq!(fingerprints.load(Ordering::Relaxed), 1);
+        assert_eq!(cache.stats(), (2, 1));
+    }
+    #[test]
+    fn serialize_metadata_sets_in_order() {
+        let tags = (0..20).map(|i| format!("t{:02}", i)).collect::<Vec<String>>();
+        let metadata = |tags: &mut dyn Iterator<Item = &String>| Metadata {
+            tags: tags.map(|t| Arc::new(t.to_string())).collect(),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(metadata(&mut tags.iter())).unwrap();
+        assert_eq!(json["tags"], serde_json::to_value(&tags).unwrap());
+        assert_eq!(digest_serializable(&metadata(&mut tags.iter().rev())).unwrap(), digest_serializable(&json).unwrap());
+    }
+}
@@ -1,6 +1,9 @@
 use crate::config::Value;
+use crate::pages::{digest_serializable, epoch_timestamp, ContentCache, Page};
+use crate::pages_error::PagesError;
 use chrono::Utc;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
 pub trait Printer {
@@ -37,8 +40,11 @@ impl PrintLevel {
 }
 pub struct Env {
     values: Arc<Mutex<HashMap<String, Value>>>,
+    // digest of values, reset when they change
+    values_digest: Mutex<Option<String>>,
     printer: Box<dyn Printer + Send + Sync>,
     print_level: Option<PrintLevel>,
+    content_cache: Option<Arc<ContentCache>>,
 }
 
 impl Env {
@@ -67,11 +73,35 @@ impl Env {
         self.print(&PRINT_LEVEL_VVV, caller, message)
     }
 
+    // errors are printed whatever the print level is
+    pub fn print_error(&self, caller: &str, message: &str) {
+        self.printer.print(caller, message)
+    }
+
     pub fn new(printer: Box<dyn Printer + Send + Sync>, print_level: Option<PrintLevel>) -> Self {
         Self {
             values: Default::default(),
+            values_digest: Default::default(),
             printer,
             print_level,
+            content_cache: None,
+        }
+    }
+
+    pub fn with_content_cache(mut self, content_cache: Arc<ContentCache>) -> Self {
+        self.content_cache = Some(content_cache);
+        self
+    }
+
+    pub fn content_cache(&self) -> Option<&Arc<ContentCache>> {
+        self.content_cache.as_ref()
+    }
+
+    // wraps page in the content cache when enabled
+    pub fn cache_content(&self, page: Arc<dyn Page>) -> Arc<dyn Page> {
+        match &self.content_cache {
+            None => page,
+            Some(cache) => cache.wrap(page),
         }
     }
 
@@ -81,6 +111,88 @@ impl Env {
 
     pub fn insert(&self, key: String, value: Value) {
         self.values.lock().unwrap().insert(key, value);
+        *self.values_digest.lock().unwrap() = None;
+    }
+
+    pub fn use_gitignore(&self) -> bool {
+        matches!(self.get(USE_GITIGNORE_KEY), Some(Value::Bool(true)))
+    }
+
+    pub fn build_drafts(&self) -> bool {
+        matches!(self.get(BUILD_DRAFTS_KEY), Some(Value::Bool(true)))
+    }
+
+    pub fn build_future(&self) -> bool {
+        matches!(self.get(BUILD_FUTURE_KEY), Some(Value::Bool(true)))
+    }
+
+    // reference time deciding which pages are published, current time unless set
+    pub fn build_time(&self) -> i64 {
+        match self.get(BUILD_TIME_KEY) {
+            Some(Value::I64(build_time)) => build_time,
+            _ => Utc::now().timestamp(),
+        }
+    }
+
+    // build time given as a date or rfc3339 date time
+    pub fn set_build_time(&self, build_time: &str) -> anyhow::Result<()> {
+        let timestamp = epoch_timestamp::parse(build_time).map_err(|e| PagesError::ValueParsing(format!("invalid build time {} : {}", build_time, e)))?;
+        self.insert(BUILD_TIME_KEY.to_string(), Value::I64(timestamp));
+        Ok(())
+    }
+
+    // locale whose pages are kept at the output root
+    pub fn default_lang(&self) -> Option<String> {
+        match self.get(DEFAULT_LANG_KEY) {
+            Some(Value::String(lang)) => Some(lang),
+            _ => None,
+        }
+    }
+
+    // mounted source directories with their path prefix items, empty when sources are not mounted
+    pub fn mounts(&self) -> Vec<(Vec<String>, PathBuf)> {
+        match self.get(MOUNTS_KEY) {
+            Some(Value::Vec(mounts)) => mounts
+                .into_iter()
+                .filter_map(|v| match v {
+                    Value::Map(mut m) => match (m.remove("prefix"), m.remove("source")) {
+                        (Some(Value::String(prefix)), Some(Value::String(source))) => Some((prefix.split('/').filter(|s| !s.is_empty()).map(|s| s.to_string()).collect(), PathBuf::from(source))),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    // data keys indexed as taxonomies, besides tags and authors
+    pub fn taxonomies(&self) -> Vec<String> {
+        match self.get(TAXONOMIES_KEY) {
+            Some(Value::Vec(taxonomies)) => taxonomies
+                .into_iter()
+                .filter_map(|v| match v {
+                    Value::String(taxonomy) => Some(taxonomy),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    pub fn values(&self) -> HashMap<String, Value> {
+        self.values.lock().unwrap().clone()
+    }
+
+    // values digest, computed once as long as values are unchanged
+    pub fn values_digest(&self) -> anyhow::Result<String> {
+        let mut values_digest = self.values_digest.lock().unwrap();
+        if let Some(digest) = values_digest.as_ref() {
+            return Ok(digest.clone());
+        }
+        let digest = digest_serializable(&self.values())?;
+        *values_digest = Some(digest.clone());
+        Ok(digest)
     }
 }
 
@@ -114,3 +226,10 @@ impl Printer for NoopPrinter {
 }
 
 pub const ROOT_PATH_KEY: &str = "_root_path";
+pub const USE_GITIGNORE_KEY: &str = "_use_gitignore";
+pub const BUILD_DRAFTS_KEY: &str = "_build_drafts";
+pub const BUILD_FUTURE_KEY: &str = "_build_future";
+pub const BUILD_TIME_KEY: &str = "_build_time";
+pub const DEFAULT_LANG_KEY: &str = "_default_lang";
+pub const TAXONOMIES_KEY: &str = "_taxonomies";
+pub const MOUNTS_KEY: &str = "_mounts";
@@ -0,0 +1,74 @@
+use crate::pages::{BundleIndex, Metadata, PageIndex};
+use once_cell::sync::OnceCell;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fingerprint {
+    pub digest: String,
+    pub uses_index: bool,
+}
+
+impl Fingerprint {
+    pub fn new(content: &[u8]) -> Self {
+        Fingerprint {
+            digest: digest_bytes(&[content]),
+            uses_index: false,
+        }
+    }
+
+    pub fn with(&self, content: &[u8]) -> Self {
+        Fingerprint {
+            digest: digest_bytes(&[self.digest.as_bytes(), content]),
+            uses_index: self.uses_index,
+        }
+    }
+
+    pub fn using_index(&self) -> Self {
+        Fingerprint {
+            digest: self.digest.clone(),
+            uses_index: true,
+        }
+    }
+}
+
+// lazily computed digest of an immutable value
+#[derive(Debug, Clone, Default)]
+pub struct LazyDigest(OnceCell<String>);
+
+impl LazyDigest {
+    pub fn get_or_compute<T: Serialize + ?Sized>(&self, value: &T) -> anyhow::Result<&str> {
+        Ok(self.0.get_or_try_init(|| digest_serializable(value))?)
+    }
+}
+
+// key identifying page content : source fingerprint, page index, metadata, env and output index when used by the page
+pub fn page_content_key(fingerprint: &Fingerprint, page_index: &PageIndex, metadata: Option<&Metadata>, output_index: &BundleIndex, env_digest: &str) -> anyhow::Result<String> {
+    let page_index_digest = digest_serializable(page_index)?;
+    let metadata_digest = digest_serializable(&metadata)?;
+    let mut parts = vec![fingerprint.digest.as_bytes(), page_index_digest.as_bytes(), metadata_digest.as_bytes(), env_digest.as_bytes()];
+    if fingerprint.uses_index {
+        parts.push(output_index.digest()?.as_bytes());
+    }
+    Ok(digest_bytes(&parts))
+}
+
+pub fn digest_bytes(contents: &[&[u8]]) -> String {
+    let mut hasher = Sha256::new();
+    for content in contents {
+        hasher.update((content.len() as u64).to_le_bytes());
+        hasher.update(content);
+    }
+    let mut result = String::with_capacity(64);
+    for b in hasher.finalize() {
+        write!(result, "{:02x}", b).unwrap();
+    }
+    result
+}
+
+// hashed values are canonical : json objects sort map keys and sets are serialized in order (BTreeSet or sorted HashSet)
+pub fn digest_serializable<T: Serialize + ?Sized>(value: &T) -> anyhow::Result<String> {
+    let json = serde_json::to_value(value)?;
+    Ok(digest_bytes(&[json.to_string().as_bytes()]))
+}
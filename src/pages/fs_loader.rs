@@ -26,11 +26,15 @@ impl Loader for FsLoader {
             }));
         }
         let mut pages: Vec<Arc<dyn Page>> = Vec::new();
-        visit_dirs(&self.dir_or_file, &mut |entry| {
-            pages.push(Arc::new(FsPage::new(&self.dir_or_file, entry.path())?));
+        visit_dirs(&self.dir_or_file, env.use_gitignore(), &mut |entry_path| {
+            pages.push(Arc::new(FsPage::new(&self.dir_or_file, entry_path)?));
             Ok(())
         })?;
         env.print_v("FsLoader", &format!("{} loaded", &self.dir_or_file.to_string_lossy()));
         Ok(Arc::new(VecBundle { p: pages }))
     }
+
+    fn watched_paths(&self) -> Vec<PathBuf> {
+        vec![self.dir_or_file.clone()]
+    }
 }
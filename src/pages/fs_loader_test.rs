@@ -1,8 +1,9 @@
 #[cfg(test)]
 mod tests {
+    use crate::config::Value;
     use crate::pages::fs_loader::FsLoader;
     use crate::pages::test_page::TestPage;
-    use crate::pages::{Env, Loader};
+    use crate::pages::{Env, Loader, USE_GITIGNORE_KEY};
     use rustassert::fs::{FileNode, TmpTestFolder};
 
     #[test]
@@ -292,4 +293,46 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn should_ignore_entries_matched_by_ignore_files() {
+        let test_folder = TmpTestFolder::new().unwrap();
+        test_folder
+            .write(&FileNode::Dir {
+                name: "pages".to_string(),
+                sub: vec![
+                    FileNode::new_file(".elepagesignore", "*.swp\nnode_modules/\n".as_bytes().to_vec()),
+                    FileNode::new_file(".gitignore", "*.tmp\n".as_bytes().to_vec()),
+                    FileNode::new_file("f1", "file content 1".as_bytes().to_vec()),
+                    FileNode::new_file("f1.swp", "swap".as_bytes().to_vec()),
+                    FileNode::new_file("f2.tmp", "file content 2".as_bytes().to_vec()),
+                    FileNode::Dir {
+                        name: "node_modules".to_string(),
+                        sub: vec![FileNode::new_file("m1", "module".as_bytes().to_vec())],
+                    },
+                    FileNode::Dir {
+                        name: "d1".to_string(),
+                        sub: vec![
+                            FileNode::new_file(".elepagesignore", "draft_*\n".as_bytes().to_vec()),
+                            FileNode::new_file("draft_f3", "draft".as_bytes().to_vec()),
+                            FileNode::new_file("f3", "file content 3".as_bytes().to_vec()),
+                        ],
+                    },
+                ],
+            })
+            .unwrap();
+
+        let loader = FsLoader::new(test_folder.get_path().join("pages"));
+        let load = |env: &Env| {
+            let mut actual = loader.load(env).unwrap().pages().iter().map(|p| p.path().join("/")).collect::<Vec<_>>();
+            actual.sort();
+            actual
+        };
+
+        assert_eq!(load(&Env::test()), vec!["d1/f3", "f1", "f2.tmp"]);
+
+        let env = Env::test();
+        env.insert(USE_GITIGNORE_KEY.to_string(), Value::Bool(true));
+        assert_eq!(load(&env), vec!["d1/f3", "f1"]);
+    }
 }
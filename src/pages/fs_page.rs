@@ -1,4 +1,5 @@
-use crate::pages::{BundleIndex, Env, Metadata, Page, PageIndex};
+use crate::pages::{BundleIndex, Env, Fingerprint, Metadata, Page, PageIndex};
+use std::fs;
 use std::fs::File;
 use std::io::Read;
 use std::path::{Path, PathBuf};
@@ -46,4 +47,8 @@ impl Page for FsPage {
     fn open(&self, _: &PageIndex, _: &BundleIndex, _: &Env) -> anyhow::Result<Box<dyn Read>> {
         Ok(Box::new(File::open(self.file_path.as_path())?))
     }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        fs::read(self.file_path.as_path()).ok().map(|content| Fingerprint::new(&content))
+    }
 }
@@ -0,0 +1,103 @@
+use crate::pages::loader::Loader;
+use crate::pages::page::{Page, PageBundle};
+use crate::pages::{BundleIndex, Env, Fingerprint, Metadata, PageIndex, VecBundle};
+use crate::pages_error::PagesError;
+use crate::remote::GitReference;
+use git2::{ObjectType, Oid, Repository, TreeWalkMode, TreeWalkResult};
+use std::io::{Cursor, Read};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+const GIT_FILEMODE_LINK: i32 = 0o120000;
+
+// loads pages from a repository revision without checking it out
+pub struct GitLoader {
+    repo_path: PathBuf,
+    rel_path: Option<PathBuf>,
+    reference: GitReference,
+}
+
+impl GitLoader {
+    pub fn new(repo_path: PathBuf, rel_path: Option<PathBuf>, reference: GitReference) -> Self {
+        GitLoader { repo_path, rel_path, reference }
+    }
+
+    // discovers the repository containing dir, pages are loaded from dir relative path
+    pub fn discover(dir: PathBuf, reference: GitReference) -> anyhow::Result<Self> {
+        let repo = Repository::discover(&dir)?;
+        let work_dir = repo
+            .workdir()
+            .ok_or_else(|| PagesError::ElementNotFound(format!("no work dir found for repository containing {}", dir.to_string_lossy())))?
+            .canonicalize()?;
+        let rel_path = dir.canonicalize()?.strip_prefix(&work_dir)?.to_path_buf();
+        Ok(GitLoader {
+            repo_path: work_dir,
+            rel_path: if rel_path.as_os_str().is_empty() { None } else { Some(rel_path) },
+            reference,
+        })
+    }
+}
+
+impl Loader for GitLoader {
+    fn load(&self, env: &Env) -> anyhow::Result<Arc<dyn PageBundle>> {
+        env.print_v("GitLoader", &format!("loading {} at {}", &self.repo_path.to_string_lossy(), &self.reference));
+        let repo = Repository::open(&self.repo_path)?;
+        let commit = repo.find_commit(self.reference.resolve(&repo)?)?;
+        env.print_vv("GitLoader", &format!("resolved commit : {}", commit.id()));
+        let mut tree = commit.tree()?;
+        if let Some(rel_path) = &self.rel_path {
+            tree = tree.get_path(rel_path)?.to_object(&repo)?.peel_to_tree()?;
+        }
+
+        let mut pages: Vec<Arc<dyn Page>> = Vec::new();
+        tree.walk(TreeWalkMode::PreOrder, |root, entry| {
+            let name = match entry.name() {
+                None => return TreeWalkResult::Skip,
+                Some(n) => n,
+            };
+            if name.starts_with('.') {
+                return TreeWalkResult::Skip;
+            }
+            if entry.kind() == Some(ObjectType::Blob) && entry.filemode() != GIT_FILEMODE_LINK {
+                let mut path: Vec<String> = root.split('/').filter(|s| !s.is_empty()).map(|s| s.to_string()).collect();
+                path.push(name.to_string());
+                pages.push(Arc::new(GitPage {
+                    repo_path: self.repo_path.clone(),
+                    blob_id: entry.id(),
+                    rel_path: path,
+                }));
+            }
+            TreeWalkResult::Ok
+        })?;
+        env.print_v("GitLoader", &format!("{} pages loaded", pages.len()));
+        Ok(Arc::new(VecBundle { p: pages }))
+    }
+}
+
+#[derive(Debug)]
+pub struct GitPage {
+    repo_path: PathBuf,
+    blob_id: Oid,
+    rel_path: Vec<String>,
+}
+
+impl Page for GitPage {
+    fn path(&self) -> &[String] {
+        &self.rel_path
+    }
+
+    fn metadata(&self) -> Option<&Metadata> {
+        None
+    }
+
+    fn open(&self, _: &PageIndex, _: &BundleIndex, _: &Env) -> anyhow::Result<Box<dyn Read>> {
+        let repo = Repository::open(&self.repo_path)?;
+        let blob = repo.find_blob(self.blob_id)?;
+        Ok(Box::new(Cursor::new(blob.content().to_vec())))
+    }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        // blob id is already a content hash
+        Some(Fingerprint::new(self.blob_id.as_bytes()))
+    }
+}
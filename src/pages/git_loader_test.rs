@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use crate::pages::git_loader::GitLoader;
+    use crate::pages::test_page::TestPage;
+    use crate::pages::{Env, Loader};
+    use crate::remote::GitReference;
+    use git2::{IndexAddOption, Oid, Repository};
+    use rustassert::fs::{FileNode, TmpTestFolder};
+    use std::fs;
+
+    #[test]
+    fn load_pages_from_commit_branch_and_tag_ignoring_uncommitted_files() {
+        let test_folder = TmpTestFolder::new().unwrap();
+        let repo = Repository::init(test_folder.get_path()).unwrap();
+        repo.config().unwrap().set_str("user.name", "user_1").unwrap();
+        repo.config().unwrap().set_str("user.email", "user_1@pages.io").unwrap();
+
+        test_folder
+            .write(&FileNode::Dir {
+                name: "pages".to_string(),
+                sub: vec![
+                    FileNode::new_file("f1.md", "f1 v1".as_bytes().to_vec()),
+                    FileNode::Dir {
+                        name: "d1".to_string(),
+                        sub: vec![FileNode::new_file("f2.md", "f2 v1".as_bytes().to_vec())],
+                    },
+                    FileNode::new_file(".hidden", "hidden".as_bytes().to_vec()),
+                ],
+            })
+            .unwrap();
+        let first_commit = commit(&repo, "first commit");
+        repo.tag_lightweight("v1", &repo.find_object(first_commit, None).unwrap(), false).unwrap();
+
+        fs::write(test_folder.get_path().join("pages").join("f1.md"), "f1 v2").unwrap();
+        commit(&repo, "second commit");
+        let branch = repo.head().unwrap().shorthand().unwrap().to_string();
+
+        fs::write(test_folder.get_path().join("pages").join("f3.md"), "uncommitted").unwrap();
+
+        let load = |reference: GitReference| {
+            let loader = GitLoader::discover(test_folder.get_path().join("pages"), reference).unwrap();
+            let mut pages = loader.load(&Env::test()).unwrap().pages().iter().map(|p| TestPage::from(p)).collect::<Vec<_>>();
+            pages.sort_by_key(|f| f.path.join("/"));
+            pages
+        };
+        let expected = |f1_content: &str| {
+            vec![
+                TestPage {
+                    path: vec!["d1".to_string(), "f2.md".to_string()],
+                    metadata: None,
+                    content: "f2 v1".to_string(),
+                },
+                TestPage {
+                    path: vec!["f1.md".to_string()],
+                    metadata: None,
+                    content: f1_content.to_string(),
+                },
+            ]
+        };
+
+        assert_eq!(load(GitReference::Tag("v1".to_string())), expected("f1 v1"));
+        assert_eq!(load(GitReference::Commit(first_commit.to_string())), expected("f1 v1"));
+        assert_eq!(load(GitReference::Branch(branch)), expected("f1 v2"));
+    }
+
+    #[test]
+    fn return_err_when_reference_not_found() {
+        let test_folder = TmpTestFolder::new_from_node(&FileNode::new_file("file", "this is a test file".as_bytes().to_vec())).unwrap();
+        let repo = Repository::init(test_folder.get_path()).unwrap();
+        repo.config().unwrap().set_str("user.name", "user_1").unwrap();
+        repo.config().unwrap().set_str("user.email", "user_1@pages.io").unwrap();
+        commit(&repo, "first commit");
+
+        let loader = GitLoader::discover(test_folder.get_path().to_path_buf(), GitReference::Tag("unknown".to_string())).unwrap();
+        assert!(loader.load(&Env::test()).is_err());
+    }
+
+    fn commit(repo: &Repository, message: &str) -> Oid {
+        let sig = repo.signature().unwrap();
+        let tree_id = {
+            let mut index = repo.index().unwrap();
+            index.add_all(["*"].iter(), IndexAddOption::DEFAULT, None).unwrap();
+            index.write_tree().unwrap()
+        };
+        let tree = repo.find_tree(tree_id).unwrap();
+
+        if let Ok(h) = repo.head() {
+            let parent = repo.find_commit(h.target().unwrap()).unwrap();
+            return repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &[&parent]).unwrap();
+        }
+        repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &[]).unwrap()
+    }
+}
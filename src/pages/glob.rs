@@ -0,0 +1,185 @@
+// glob matching of page names and paths :
+// `*` any characters, `?` one character, `[a-z]` / `[!a-z]` character classes,
+// `{md,markdown}` alternatives and `\` escaping ; `**` path items match any number of directories
+
+#[derive(Debug, PartialEq)]
+enum GlobToken {
+    Literal(char),
+    AnyChar,
+    AnyChars,
+    Class { negated: bool, ranges: Vec<(char, char)> },
+}
+
+// brace alternatives expanded to plain patterns, escapes being kept for tokenization
+fn expand_braces(pattern: &[char]) -> Vec<Vec<char>> {
+    let mut i = 0;
+    while i < pattern.len() {
+        match pattern[i] {
+            '\\' => i += 2,
+            '{' => {
+                if let Some((close, alternatives)) = brace_alternatives(pattern, i) {
+                    let prefix = &pattern[..i];
+                    let suffixes = expand_braces(&pattern[close + 1..]);
+                    return alternatives
+                        .iter()
+                        .flat_map(|alternative| expand_braces(alternative))
+                        .flat_map(|alternative| suffixes.iter().map(move |suffix| [prefix, &alternative, suffix].concat()))
+                        .collect();
+                }
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+    vec![pattern.to_vec()]
+}
+
+// closing brace position and top level alternatives, None when the brace is never closed
+fn brace_alternatives(pattern: &[char], open: usize) -> Option<(usize, Vec<Vec<char>>)> {
+    let mut depth = 0;
+    let mut alternatives = vec![];
+    let mut start = open + 1;
+    let mut i = open + 1;
+    while i < pattern.len() {
+        match pattern[i] {
+            '\\' => i += 1,
+            '{' => depth += 1,
+            '}' if depth > 0 => depth -= 1,
+            '}' => {
+                alternatives.push(pattern[start..i].to_vec());
+                return Some((i, alternatives));
+            }
+            ',' if depth == 0 => {
+                alternatives.push(pattern[start..i].to_vec());
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+fn tokenize(pattern: &[char]) -> Vec<GlobToken> {
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < pattern.len() {
+        let token = match pattern[i] {
+            '\\' if i + 1 < pattern.len() => {
+                i += 1;
+                GlobToken::Literal(pattern[i])
+            }
+            '?' => GlobToken::AnyChar,
+            '*' => {
+                while pattern.get(i + 1) == Some(&'*') {
+                    i += 1;
+                }
+                GlobToken::AnyChars
+            }
+            '[' => match class(pattern, i) {
+                Some((close, token)) => {
+                    i = close;
+                    token
+                }
+                None => GlobToken::Literal('['),
+            },
+            c => GlobToken::Literal(c),
+        };
+        tokens.push(token);
+        i += 1;
+    }
+    tokens
+}
+
+// character class and its closing bracket position, a leading `]` being part of the class
+fn class(pattern: &[char], open: usize) -> Option<(usize, GlobToken)> {
+    let mut i = open + 1;
+    let negated = matches!(pattern.get(i), Some('!') | Some('^'));
+    if negated {
+        i += 1;
+    }
+    let mut ranges = vec![];
+    let first = i;
+    while i < pattern.len() {
+        let mut c = pattern[i];
+        if c == ']' && i > first {
+            return Some((i, GlobToken::Class { negated, ranges }));
+        }
+        if c == '\\' && i + 1 < pattern.len() {
+            i += 1;
+            c = pattern[i];
+        }
+        match (pattern.get(i + 1), pattern.get(i + 2)) {
+            (Some('-'), Some(end)) if *end != ']' => {
+                ranges.push((c, *end));
+                i += 3;
+            }
+            _ => {
+                ranges.push((c, c));
+                i += 1;
+            }
+        }
+    }
+    None
+}
+
+fn token_match(token: &GlobToken, c: char) -> bool {
+    match token {
+        GlobToken::Literal(l) => *l == c,
+        GlobToken::AnyChar => true,
+        GlobToken::AnyChars => true,
+        GlobToken::Class { negated, ranges } => ranges.iter().any(|(start, end)| *start <= c && c <= *end) != *negated,
+    }
+}
+
+// `*` backtracks to its last position when the rest of the pattern fails
+fn tokens_match(tokens: &[GlobToken], name: &[char]) -> bool {
+    let (mut t, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match tokens.get(t) {
+            Some(GlobToken::AnyChars) => {
+                backtrack = Some((t, n));
+                t += 1;
+            }
+            Some(token) if token_match(token, name[n]) => {
+                t += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star_t, star_n)) => {
+                    backtrack = Some((star_t, star_n + 1));
+                    t = star_t + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    tokens[t..].iter().all(|token| *token == GlobToken::AnyChars)
+}
+
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+    if pattern == "*" || pattern == name {
+        return true;
+    }
+    if !pattern.contains(['*', '?', '[', '{', '\\']) {
+        return false;
+    }
+    let name = name.chars().collect::<Vec<char>>();
+    expand_braces(&pattern.chars().collect::<Vec<char>>()).iter().any(|p| tokens_match(&tokenize(p), &name))
+}
+
+// path items matched one by one, `**` matching zero or more directories (at least one item when trailing)
+pub fn glob_match_path(path: &[String], query: &[String]) -> bool {
+    if query.is_empty() {
+        return path.is_empty();
+    }
+    if path.is_empty() {
+        return false;
+    }
+    if query[0] == "**" {
+        return (0..=path.len()).any(|skip| glob_match_path(&path[skip..], &query[1..]));
+    }
+    glob_match(&query[0], &path[0]) && glob_match_path(&path[1..], &query[1..])
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use crate::pages::test_page::TestPage;
+    use crate::pages::{glob_match, glob_match_path, BundleIndex, BundlePagination, BundleQuery, PageBundle, PathSelector, Selector, VecBundle};
+    use std::sync::Arc;
+
+    fn path(p: &str) -> Vec<String> {
+        p.split('/').map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn match_names_with_backtracking_stars() {
+        assert!(glob_match("a*b*c", "axbxbxc"));
+        assert!(glob_match("a*b*c", "abbc"));
+        assert!(!glob_match("a*b*c", "axbxbx"));
+        assert!(glob_match("*.tar.gz", "site.v1.tar.gz"));
+        assert!(glob_match("*guide", "Getting started guide"));
+        assert!(glob_match("f***.t**t", "f1.txt"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("a*", "b"));
+    }
+
+    #[test]
+    fn match_names_with_classes_alternatives_and_escapes() {
+        assert!(glob_match("f?.md", "f1.md"));
+        assert!(!glob_match("f?.md", "f10.md"));
+        assert!(glob_match("[a-c]*.md", "b.md"));
+        assert!(!glob_match("[a-c]*.md", "d.md"));
+        assert!(glob_match("[!a-c]*.md", "d.md"));
+        assert!(glob_match("[]x].md", "].md"));
+        assert!(glob_match("*.{md,markdown}", "readme.markdown"));
+        assert!(!glob_match("*.{md,markdown}", "readme.txt"));
+        assert!(glob_match("{docs,blog{,-old}}", "blog-old"));
+        assert!(glob_match("{a,b}-{1,2}", "b-2"));
+        assert!(glob_match("\\*.md", "*.md"));
+        assert!(!glob_match("\\*.md", "a.md"));
+        assert!(glob_match("file\\[1\\].md", "file[1].md"));
+        assert!(glob_match("{a,b", "{a,b"));
+        assert!(glob_match("[a", "[a"));
+    }
+
+    #[test]
+    fn match_paths_with_backtracking_double_stars() {
+        assert!(glob_match_path(&path("d1/x/d1/f2"), &path("**/d1/f2")));
+        assert!(glob_match_path(&path("d1/f2"), &path("**/d1/f2")));
+        assert!(glob_match_path(&path("a/b/c/d.md"), &path("a/**/c/*.{md,html}")));
+        assert!(!glob_match_path(&path("a"), &path("a/**")));
+        assert!(glob_match_path(&path("a/b"), &path("a/**")));
+
+        // selectors and queries share the engine
+        let bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec!["docs/v1/docs/intro.md", "docs/intro.markdown", "blog/intro.md"]
+                .into_iter()
+                .map(|p| {
+                    Arc::new(TestPage {
+                        path: path(p),
+                        metadata: None,
+                        content: "".to_string(),
+                    }) as _
+                })
+                .collect(),
+        });
+        let query = vec!["**".to_string(), "docs".to_string(), "intro.{md,markdown}".to_string()];
+        let selector = PathSelector { query: query.clone() };
+        let selected = bundle.pages().iter().filter(|p| selector.select(p)).map(|p| p.path().join("/")).collect::<Vec<String>>();
+        assert_eq!(selected, vec!["docs/v1/docs/intro.md", "docs/intro.markdown"]);
+
+        let bundle_index = BundleIndex::from(&bundle);
+        let no_paginate = BundlePagination {
+            skip: None,
+            limit: None,
+            sort_by: None,
+        };
+        let queried = bundle_index.query(&BundleQuery::Path { path: query.join("/") }, &no_paginate);
+        let mut queried = queried.iter().map(|p| p.page_ref.path.join("/")).collect::<Vec<String>>();
+        queried.sort();
+        assert_eq!(queried, vec!["docs/intro.markdown", "docs/v1/docs/intro.md"]);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::pages::{ArcPage, Env, Page, PageBundle, VecBundle};
+use std::sync::Arc;
+
+// page path without its locale : a leading locale folder (fr/a.html) and a locale file name suffix (a.fr.html) are removed
+pub fn unlocalized_path(path: &[String], lang: &str) -> Vec<String> {
+    let mut result = match path.first() {
+        Some(first) if first == lang && path.len() > 1 => path[1..].to_vec(),
+        _ => path.to_vec(),
+    };
+    if let Some(file_name) = result.last_mut() {
+        let mut parts: Vec<&str> = file_name.split('.').collect();
+        if parts.len() >= 3 && parts[parts.len() - 2] == lang {
+            parts.remove(parts.len() - 2);
+            *file_name = parts.join(".");
+        }
+    }
+    result
+}
+
+// output path of a page in its locale : default locale pages are kept at the root, others are placed under their locale folder
+pub fn localized_path(path: &[String], lang: &str, default_lang: Option<&str>) -> Vec<String> {
+    let unlocalized = unlocalized_path(path, lang);
+    if default_lang == Some(lang) {
+        return unlocalized;
+    }
+    let mut result = Vec::with_capacity(unlocalized.len() + 1);
+    result.push(lang.to_string());
+    result.extend(unlocalized);
+    result
+}
+
+// moves pages declaring a lang to their localized path
+pub fn localize(bundle: &Arc<dyn PageBundle>, env: &Env) -> Arc<dyn PageBundle> {
+    let default_lang = env.default_lang();
+    let mut moved = 0;
+    let pages = bundle
+        .pages()
+        .iter()
+        .map(|page| {
+            if let Some(lang) = page.metadata().and_then(|m| m.lang.as_ref()) {
+                let path = localized_path(page.path(), lang, default_lang.as_deref());
+                if path != page.path() {
+                    moved += 1;
+                    return page.change_path(path);
+                }
+            }
+            Arc::clone(page)
+        })
+        .collect::<Vec<Arc<dyn Page>>>();
+    if moved == 0 {
+        return Arc::clone(bundle);
+    }
+    env.print_vv("Localization", &format!("{} pages moved to their locale path", moved));
+    Arc::new(VecBundle { p: pages })
+}
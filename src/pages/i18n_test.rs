@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::Value;
+    use crate::pages::test_page::TestPage;
+    use crate::pages::{localize, localized_path, unlocalized_path, Env, Metadata, Page, PageBundle, VecBundle, DEFAULT_LANG_KEY};
+    use std::sync::Arc;
+
+    fn path(p: &str) -> Vec<String> {
+        p.split('/').map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn remove_locale_folder_and_file_name_suffix() {
+        assert_eq!(unlocalized_path(&path("fr/docs/intro.html"), "fr"), path("docs/intro.html"));
+        assert_eq!(unlocalized_path(&path("docs/intro.fr.html"), "fr"), path("docs/intro.html"));
+        assert_eq!(unlocalized_path(&path("fr/docs/intro.fr.html"), "fr"), path("docs/intro.html"));
+        assert_eq!(unlocalized_path(&path("docs/intro.de.html"), "fr"), path("docs/intro.de.html"));
+        assert_eq!(unlocalized_path(&path("fr.html"), "fr"), path("fr.html"));
+        assert_eq!(unlocalized_path(&path("fr"), "fr"), path("fr"));
+    }
+
+    #[test]
+    fn place_non_default_locales_under_their_folder() {
+        assert_eq!(localized_path(&path("docs/intro.fr.html"), "fr", Some("en")), path("fr/docs/intro.html"));
+        assert_eq!(localized_path(&path("fr/docs/intro.html"), "fr", Some("en")), path("fr/docs/intro.html"));
+        assert_eq!(localized_path(&path("docs/intro.en.html"), "en", Some("en")), path("docs/intro.html"));
+        assert_eq!(localized_path(&path("en/docs/intro.html"), "en", Some("en")), path("docs/intro.html"));
+        assert_eq!(localized_path(&path("docs/intro.html"), "en", None), path("en/docs/intro.html"));
+    }
+
+    #[test]
+    fn move_localized_pages_of_bundle() {
+        let page = |p: &str, lang: Option<&str>| -> Arc<dyn Page> {
+            Arc::new(TestPage {
+                path: path(p),
+                metadata: lang.map(|l| Metadata {
+                    lang: Some(Arc::new(l.to_string())),
+                    ..Default::default()
+                }),
+                content: p.to_string(),
+            })
+        };
+        let bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![page("index.en.html", Some("en")), page("index.fr.html", Some("fr")), page("style.css", None)],
+        });
+        let env = Env::test();
+        env.insert(DEFAULT_LANG_KEY.to_string(), Value::String("en".to_string()));
+
+        let mut actual = localize(&bundle, &env).pages().iter().map(|p| (p.path().join("/"), TestPage::from(p).content)).collect::<Vec<_>>();
+        actual.sort();
+        assert_eq!(
+            actual,
+            vec![
+                ("fr/index.html".to_string(), "index.fr.html".to_string()),
+                ("index.html".to_string(), "index.en.html".to_string()),
+                ("style.css".to_string(), "style.css".to_string()),
+            ]
+        );
+    }
+}
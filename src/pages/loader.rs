@@ -1,7 +1,11 @@
 use crate::pages::page::PageBundle;
 use crate::pages::Env;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 pub trait Loader {
     fn load(&self, env: &Env) -> anyhow::Result<Arc<dyn PageBundle>>;
+    fn watched_paths(&self) -> Vec<PathBuf> {
+        vec![]
+    }
 }
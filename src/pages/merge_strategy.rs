@@ -0,0 +1,138 @@
+use crate::config::Value;
+use crate::pages_error::PagesError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+// how a metadata field combines with the parent one
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MergeStrategy {
+    // child value is used when set, parent value otherwise
+    Replace,
+    // child and parent values are combined, missing data keys are taken from parent
+    Append,
+    // like append, nested data maps are merged key by key
+    DeepMerge,
+    // parent value is never used
+    IgnoreParent,
+}
+
+impl Display for MergeStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            MergeStrategy::Replace => "replace",
+            MergeStrategy::Append => "append",
+            MergeStrategy::DeepMerge => "deep-merge",
+            MergeStrategy::IgnoreParent => "ignore-parent",
+        })
+    }
+}
+
+// per field merge strategies, unset fields keep the default merge behavior
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MergeStrategies {
+    pub title: Option<MergeStrategy>,
+    pub summary: Option<MergeStrategy>,
+    pub authors: Option<MergeStrategy>,
+    pub tags: Option<MergeStrategy>,
+    #[serde(alias = "publishingDate")]
+    pub publishing_date: Option<MergeStrategy>,
+    #[serde(alias = "lastEditDate")]
+    pub last_edit_date: Option<MergeStrategy>,
+    #[serde(alias = "expiryDate")]
+    pub expiry_date: Option<MergeStrategy>,
+    pub draft: Option<MergeStrategy>,
+    pub lang: Option<MergeStrategy>,
+    #[serde(alias = "translationKey")]
+    pub translation_key: Option<MergeStrategy>,
+    pub series: Option<MergeStrategy>,
+    #[serde(alias = "seriesPart")]
+    pub series_part: Option<MergeStrategy>,
+    pub navigation: Option<MergeStrategy>,
+    pub data: Option<MergeStrategy>,
+}
+
+impl MergeStrategies {
+    // strategies of self, completed by fallback ones
+    pub fn or(&self, fallback: &MergeStrategies) -> MergeStrategies {
+        MergeStrategies {
+            title: self.title.or(fallback.title),
+            summary: self.summary.or(fallback.summary),
+            authors: self.authors.or(fallback.authors),
+            tags: self.tags.or(fallback.tags),
+            publishing_date: self.publishing_date.or(fallback.publishing_date),
+            last_edit_date: self.last_edit_date.or(fallback.last_edit_date),
+            expiry_date: self.expiry_date.or(fallback.expiry_date),
+            draft: self.draft.or(fallback.draft),
+            lang: self.lang.or(fallback.lang),
+            translation_key: self.translation_key.or(fallback.translation_key),
+            series: self.series.or(fallback.series),
+            series_part: self.series_part.or(fallback.series_part),
+            navigation: self.navigation.or(fallback.navigation),
+            data: self.data.or(fallback.data),
+        }
+    }
+
+    // single valued fields can only be replaced or ignore their parent
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let single_valued = [
+            ("title", self.title),
+            ("summary", self.summary),
+            ("publishing_date", self.publishing_date),
+            ("last_edit_date", self.last_edit_date),
+            ("expiry_date", self.expiry_date),
+            ("draft", self.draft),
+            ("lang", self.lang),
+            ("translation_key", self.translation_key),
+            ("series", self.series),
+            ("series_part", self.series_part),
+            ("navigation", self.navigation),
+        ];
+        for (field, strategy) in &single_valued {
+            if let Some(s @ (MergeStrategy::Append | MergeStrategy::DeepMerge)) = strategy {
+                return Err(PagesError::ValueParsing(format!("merge strategy {} is not supported for field {}", s, field)).into());
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn merge_single<T: Clone>(child: &Option<T>, parent: &Option<T>, strategy: Option<MergeStrategy>) -> Option<T> {
+    match strategy {
+        Some(MergeStrategy::IgnoreParent) => child.clone(),
+        _ => child.clone().or_else(|| parent.clone()),
+    }
+}
+
+pub(crate) fn merge_data(child: &HashMap<String, Value>, parent: &HashMap<String, Value>, strategy: Option<MergeStrategy>) -> HashMap<String, Value> {
+    match strategy {
+        Some(MergeStrategy::IgnoreParent) => child.clone(),
+        Some(MergeStrategy::Replace) if !child.is_empty() => child.clone(),
+        Some(MergeStrategy::Replace) => parent.clone(),
+        Some(MergeStrategy::DeepMerge) => deep_merge_maps(child, parent),
+        None | Some(MergeStrategy::Append) => {
+            let mut result = child.clone();
+            for (key, value) in parent {
+                if !child.contains_key(key) {
+                    result.insert(key.to_string(), value.clone());
+                }
+            }
+            result
+        }
+    }
+}
+
+fn deep_merge_maps(child: &HashMap<String, Value>, parent: &HashMap<String, Value>) -> HashMap<String, Value> {
+    let mut result = child.clone();
+    for (key, parent_value) in parent {
+        let merged = match (child.get(key), parent_value) {
+            (None, _) => parent_value.clone(),
+            (Some(Value::Map(c)), Value::Map(p)) => Value::Map(deep_merge_maps(c, p)),
+            (Some(child_value), _) => child_value.clone(),
+        };
+        result.insert(key.to_string(), merged);
+    }
+    result
+}
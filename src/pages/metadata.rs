@@ -1,15 +1,23 @@
 use crate::config::Value;
+use crate::pages::{merge_data, merge_single, AuthorProfile, MergeStrategies, MergeStrategy, NavigationScope};
 use crate::pages_error::PagesError;
-use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use serde::de::{IgnoredAny, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 #[derive(Clone, Deserialize, Serialize, Debug)]
 pub struct Author {
     pub name: String,
-    #[serde(default = "HashSet::default")]
+    #[serde(default = "HashSet::default", serialize_with = "serialize_sorted")]
     pub contacts: HashSet<String>,
+    // set when the author is resolved from the authors registry
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<Arc<AuthorProfile>>,
 }
 
 impl Eq for Author {}
@@ -26,6 +34,27 @@ impl Hash for Author {
     }
 }
 
+impl PartialOrd for Author {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Author {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.name.cmp(&other.name)
+    }
+}
+
+// sets are serialized sorted so that equal metadata always give the same output and digest
+fn serialize_sorted<S, T>(set: &HashSet<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize + Ord,
+{
+    serializer.collect_seq(set.iter().collect::<BTreeSet<&T>>())
+}
+
 impl Author {
     pub fn merge(&self, parent: &Arc<Self>) -> anyhow::Result<Self> {
         if self.name != parent.name {
@@ -34,6 +63,7 @@ impl Author {
         let mut result = Author {
             name: self.name.clone(),
             contacts: self.contacts.clone(),
+            profile: self.profile.clone().or_else(|| parent.profile.clone()),
         };
         for c in &parent.contacts {
             result.contacts.insert(c.clone());
@@ -43,48 +73,187 @@ impl Author {
     }
 }
 
-#[derive(Clone, PartialEq, Deserialize, Serialize, Debug)]
+#[derive(Clone, PartialEq, Deserialize, Serialize, Debug, Default)]
 pub struct Metadata {
     pub title: Option<Arc<String>>,
     pub summary: Option<Arc<String>>,
-    #[serde(default = "HashSet::default")]
+    #[serde(default = "HashSet::default", serialize_with = "serialize_sorted")]
     pub authors: HashSet<Arc<Author>>,
-    #[serde(default = "HashSet::default")]
+    #[serde(default = "HashSet::default", serialize_with = "serialize_sorted")]
     pub tags: HashSet<Arc<String>>,
     #[serde(with = "epoch_timestamp", default, alias = "publishingDate")]
     pub publishing_date: Option<i64>,
     #[serde(with = "epoch_timestamp", default, alias = "lastEditDate")]
     pub last_edit_date: Option<i64>,
+    #[serde(with = "epoch_timestamp", default, alias = "expiryDate")]
+    pub expiry_date: Option<i64>,
+    #[serde(default)]
+    pub draft: Option<bool>,
+    #[serde(default)]
+    pub lang: Option<Arc<String>>,
+    #[serde(default, alias = "translationKey")]
+    pub translation_key: Option<Arc<String>>,
+    // multi-part posts sharing a series name, ordered by part number then publishing date
+    #[serde(default)]
+    pub series: Option<Arc<String>>,
+    #[serde(default, alias = "seriesPart")]
+    pub series_part: Option<u32>,
+    #[serde(default)]
+    pub navigation: Option<NavigationScope>,
+    // strategies used when children metadata is merged into the declaring shadow file ; declared by a page, they only apply to its merge into its nearest parent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge: Option<MergeStrategies>,
+    // flattened so that it collects the keys left by other fields, as deny_unknown_fields cannot name them ;
+    // flatten makes serde buffer the whole map, fields are then read from the buffered content
+    #[serde(flatten)]
+    pub unknown_fields: UnknownFields,
     #[serde(default = "HashMap::default")]
     pub data: HashMap<String, Value>,
 }
 
+// top level keys matching no metadata field, kept so that typos can be reported
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct UnknownFields(pub BTreeSet<UnknownField>);
+
+// keys read from a shadow file keep its path, as they reach the pages it applies to through merges
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct UnknownField {
+    pub name: String,
+    pub shadow_path: Option<Vec<String>>,
+}
+
+impl UnknownFields {
+    pub fn from_shadow(&self, shadow_path: &[String]) -> Self {
+        UnknownFields(
+            self.0
+                .iter()
+                .map(|field| UnknownField {
+                    name: field.name.clone(),
+                    shadow_path: Some(shadow_path.to_vec()),
+                })
+                .collect(),
+        )
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        UnknownFields(self.0.union(&other.0).cloned().collect())
+    }
+}
+
+impl Display for UnknownField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.shadow_path {
+            None => write!(f, "unknown metadata field {}", self.name),
+            Some(path) => write!(f, "unknown metadata field {} in {}", self.name, path.join("/")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for UnknownFields {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct UnknownFieldsVisitor;
+
+        impl<'de> Visitor<'de> for UnknownFieldsVisitor {
+            type Value = UnknownFields;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("a map")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut result = BTreeSet::new();
+                while let Some(key) = map.next_key::<String>()? {
+                    map.next_value::<IgnoredAny>()?;
+                    result.insert(UnknownField { name: key, shadow_path: None });
+                }
+                Ok(UnknownFields(result))
+            }
+        }
+
+        deserializer.deserialize_map(UnknownFieldsVisitor)
+    }
+}
+
+// unknown fields are never written back
+impl Serialize for UnknownFields {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_map(Some(0))?.end()
+    }
+}
+
 impl Metadata {
+    pub fn is_draft(&self) -> bool {
+        self.draft.unwrap_or(false)
+    }
+
+    pub fn is_future(&self, now: i64) -> bool {
+        matches!(self.publishing_date, Some(publishing_date) if publishing_date > now)
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.expiry_date, Some(expiry_date) if expiry_date <= now)
+    }
+
     pub fn merge(&self, parent: &Self) -> anyhow::Result<Self> {
+        self.merge_with(parent, &MergeStrategies::default())
+    }
+
+    // by default single valued fields are taken from parent when missing, tags are appended,
+    // authors are kept and enriched with matching parent authors, missing data keys are taken from parent
+    pub fn merge_with(&self, parent: &Self, strategies: &MergeStrategies) -> anyhow::Result<Self> {
         let mut result = Metadata {
-            title: self.title.clone().or_else(|| parent.title.clone()),
-            summary: self.summary.clone().or_else(|| parent.summary.clone()),
+            title: merge_single(&self.title, &parent.title, strategies.title),
+            summary: merge_single(&self.summary, &parent.summary, strategies.summary),
             authors: self.authors.clone(),
             tags: self.tags.clone(),
-            publishing_date: self.publishing_date.or(parent.publishing_date),
-            last_edit_date: self.last_edit_date.or(parent.last_edit_date),
-            data: self.data.clone(),
+            publishing_date: merge_single(&self.publishing_date, &parent.publishing_date, strategies.publishing_date),
+            last_edit_date: merge_single(&self.last_edit_date, &parent.last_edit_date, strategies.last_edit_date),
+            expiry_date: merge_single(&self.expiry_date, &parent.expiry_date, strategies.expiry_date),
+            draft: merge_single(&self.draft, &parent.draft, strategies.draft),
+            lang: merge_single(&self.lang, &parent.lang, strategies.lang),
+            translation_key: merge_single(&self.translation_key, &parent.translation_key, strategies.translation_key),
+            series: merge_single(&self.series, &parent.series, strategies.series),
+            series_part: merge_single(&self.series_part, &parent.series_part, strategies.series_part),
+            navigation: merge_single(&self.navigation, &parent.navigation, strategies.navigation),
+            merge: self.merge.clone(),
+            unknown_fields: self.unknown_fields.union(&parent.unknown_fields),
+            data: merge_data(&self.data, &parent.data, strategies.data),
         };
 
-        for p_author in &parent.authors {
-            if let Some(c) = self.authors.get(p_author) {
-                result.authors.replace(Arc::new(c.merge(p_author)?));
+        match strategies.authors {
+            None => {
+                for p_author in &parent.authors {
+                    if let Some(c) = self.authors.get(p_author) {
+                        result.authors.replace(Arc::new(c.merge(p_author)?));
+                    }
+                }
             }
+            Some(MergeStrategy::Replace) if self.authors.is_empty() => result.authors = parent.authors.clone(),
+            Some(MergeStrategy::Append) | Some(MergeStrategy::DeepMerge) => {
+                for p_author in &parent.authors {
+                    match self.authors.get(p_author) {
+                        None => result.authors.insert(Arc::clone(p_author)),
+                        Some(c) => result.authors.replace(Arc::new(c.merge(p_author)?)).is_some(),
+                    };
+                }
+            }
+            Some(MergeStrategy::Replace) | Some(MergeStrategy::IgnoreParent) => {}
         }
 
-        for tag in &parent.tags {
-            result.tags.insert(tag.clone());
-        }
-
-        for (key, value) in &parent.data {
-            if !self.data.contains_key(key) {
-                result.data.insert(key.to_string(), value.clone());
+        match strategies.tags {
+            Some(MergeStrategy::Replace) if self.tags.is_empty() => result.tags = parent.tags.clone(),
+            None | Some(MergeStrategy::Append) | Some(MergeStrategy::DeepMerge) => {
+                for tag in &parent.tags {
+                    result.tags.insert(tag.clone());
+                }
             }
+            Some(MergeStrategy::Replace) | Some(MergeStrategy::IgnoreParent) => {}
         }
 
         Ok(result)
@@ -103,22 +272,37 @@ pub mod epoch_timestamp {
         ts.map(|i| Utc.timestamp(i, 0).to_rfc3339()).serialize(serializer)
     }
 
+    // toml native datetime values are accepted along with strings
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum DateValue {
+        String(String),
+        TomlDatetime(toml::value::Datetime),
+    }
+
     pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let result = match Option::<String>::deserialize(deserializer)? {
+        let date_value = Option::<DateValue>::deserialize(deserializer)?.map(|v| match v {
+            DateValue::String(s) => s,
+            DateValue::TomlDatetime(d) => d.to_string(),
+        });
+        let result = match date_value {
             None => None,
-            Some(dt) => {
-                if let Ok(ts) = NaiveDate::from_str(&dt).map(|e| e.and_hms(0, 0, 0).timestamp()) {
-                    Some(ts)
-                } else if let Ok(ts) = NaiveDateTime::from_str(&dt).map(|e| e.timestamp()) {
-                    Some(ts)
-                } else {
-                    Some(DateTime::parse_from_rfc3339(&dt).map_err(D::Error::custom)?.timestamp())
-                }
-            }
+            Some(dt) => Some(parse(&dt).map_err(D::Error::custom)?),
         };
         Ok(result)
     }
+
+    // accepts dates, naive date times and rfc3339 date times
+    pub fn parse(dt: &str) -> Result<i64, chrono::ParseError> {
+        if let Ok(ts) = NaiveDate::from_str(dt).map(|e| e.and_hms(0, 0, 0).timestamp()) {
+            Ok(ts)
+        } else if let Ok(ts) = NaiveDateTime::from_str(dt).map(|e| e.timestamp()) {
+            Ok(ts)
+        } else {
+            Ok(DateTime::parse_from_rfc3339(dt)?.timestamp())
+        }
+    }
 }
@@ -1,8 +1,9 @@
 #[cfg(test)]
 mod tests {
     use crate::config::Value;
-    use crate::pages::{Author, Metadata};
+    use crate::pages::{Author, MergeStrategies, Metadata};
     use chrono::DateTime;
+    use indoc::indoc;
     use std::array::IntoIter;
     use std::collections::{HashMap, HashSet};
     use std::iter::FromIterator;
@@ -10,24 +11,15 @@ mod tests {
 
     #[test]
     fn metadata_merge_base_attributes() {
-        let m1 = Metadata {
-            title: None,
-            summary: None,
-            authors: HashSet::new(),
-            tags: HashSet::new(),
-            publishing_date: None,
-            last_edit_date: None,
-            data: HashMap::default(),
-        };
+        let m1 = Metadata::default();
 
         let m2 = Metadata {
             title: Some(Arc::new("title".to_string())),
             summary: Some(Arc::new("summary".to_string())),
-            authors: HashSet::new(),
-            tags: HashSet::new(),
             publishing_date: Some(DateTime::parse_from_rfc3339("2021-10-20T16:00:00-08:00").unwrap().timestamp()),
             last_edit_date: Some(DateTime::parse_from_rfc3339("2021-10-20T17:00:00-08:00").unwrap().timestamp()),
-            data: HashMap::default(),
+            draft: Some(true),
+            ..Default::default()
         };
 
         let result = m1.merge(&m2).unwrap();
@@ -38,12 +30,6 @@ mod tests {
     #[test]
     fn metadata_merge_data_attributes() {
         let m1 = Metadata {
-            title: None,
-            summary: None,
-            authors: HashSet::new(),
-            tags: HashSet::new(),
-            publishing_date: None,
-            last_edit_date: None,
             data: HashMap::from_iter(IntoIter::new([
                 ("a".to_string(), Value::String("a".to_string())),
                 (
@@ -52,36 +38,19 @@ mod tests {
                 ),
                 ("c".to_string(), Value::I32(10)),
             ])),
+            ..Default::default()
         };
 
-        let m2 = Metadata {
-            title: None,
-            summary: None,
-            authors: HashSet::new(),
-            tags: HashSet::new(),
-            publishing_date: None,
-            last_edit_date: None,
-            data: HashMap::default(),
-        };
+        let m2 = Metadata::default();
 
         let m3 = Metadata {
-            title: None,
-            summary: None,
-            authors: HashSet::new(),
-            tags: HashSet::new(),
-            publishing_date: None,
-            last_edit_date: None,
             data: HashMap::from_iter(IntoIter::new([("d".to_string(), Value::I32(20)), ("e".to_string(), Value::I32(30))])),
+            ..Default::default()
         };
 
         let m4 = Metadata {
-            title: None,
-            summary: None,
-            authors: HashSet::new(),
-            tags: HashSet::new(),
-            publishing_date: None,
-            last_edit_date: None,
             data: HashMap::from_iter(IntoIter::new([("c".to_string(), Value::I32(20)), ("d".to_string(), Value::I32(30))])),
+            ..Default::default()
         };
 
         let result_1 = m1.merge(&m2).unwrap();
@@ -92,12 +61,6 @@ mod tests {
         assert_eq!(
             result_2,
             Metadata {
-                title: None,
-                summary: None,
-                authors: HashSet::new(),
-                tags: HashSet::new(),
-                publishing_date: None,
-                last_edit_date: None,
                 data: HashMap::from_iter(IntoIter::new([
                     ("a".to_string(), Value::String("a".to_string())),
                     (
@@ -108,17 +71,12 @@ mod tests {
                     ("d".to_string(), Value::I32(20)),
                     ("e".to_string(), Value::I32(30)),
                 ])),
+                ..Default::default()
             }
         );
         assert_eq!(
             result_3,
             Metadata {
-                title: None,
-                summary: None,
-                authors: HashSet::new(),
-                tags: HashSet::new(),
-                publishing_date: None,
-                last_edit_date: None,
                 data: HashMap::from_iter(IntoIter::new([
                     ("a".to_string(), Value::String("a".to_string())),
                     (
@@ -128,6 +86,7 @@ mod tests {
                     ("c".to_string(), Value::I32(10)),
                     ("d".to_string(), Value::I32(30)),
                 ])),
+                ..Default::default()
             }
         );
     }
@@ -140,21 +99,20 @@ mod tests {
             authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                 name: "a1".to_string(),
                 contacts: vec!["c1", "c2"].iter().map(|x| x.to_string()).collect(),
+                profile: None,
             })])),
             tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
             publishing_date: Some(DateTime::parse_from_rfc3339("2021-10-20T16:00:00-08:00").unwrap().timestamp()),
             last_edit_date: Some(DateTime::parse_from_rfc3339("2021-10-20T17:00:00-08:00").unwrap().timestamp()),
-            data: HashMap::default(),
+            ..Default::default()
         };
 
         let m2 = Metadata {
             title: Some(Arc::new("parent title".to_string())),
             summary: Some(Arc::new("parent summary".to_string())),
-            authors: HashSet::new(),
-            tags: HashSet::new(),
             publishing_date: Some(DateTime::parse_from_rfc3339("2021-10-20T18:00:00-08:00").unwrap().timestamp()),
             last_edit_date: Some(DateTime::parse_from_rfc3339("2021-10-20T19:00:00-08:00").unwrap().timestamp()),
-            data: HashMap::default(),
+            ..Default::default()
         };
 
         let result = m1.merge(&m2).unwrap();
@@ -165,57 +123,55 @@ mod tests {
     #[test]
     fn metadata_merge_author_contacts_when_common_authors() {
         let m1 = Metadata {
-            title: None,
-            summary: None,
             authors: HashSet::from_iter(IntoIter::new([
                 Arc::new(Author {
                     name: "a1".to_string(),
                     contacts: vec!["c1", "c2"].iter().map(|x| x.to_string()).collect(),
+                    profile: None,
                 }),
                 Arc::new(Author {
                     name: "a2".to_string(),
                     contacts: HashSet::new(),
+                    profile: None,
                 }),
                 Arc::new(Author {
                     name: "a3".to_string(),
                     contacts: vec!["c1"].iter().map(|x| x.to_string()).collect(),
+                    profile: None,
                 }),
                 Arc::new(Author {
                     name: "a4".to_string(),
                     contacts: vec!["c1"].iter().map(|x| x.to_string()).collect(),
+                    profile: None,
                 }),
             ])),
-            tags: HashSet::new(),
-            publishing_date: None,
-            last_edit_date: None,
-            data: HashMap::default(),
+            ..Default::default()
         };
 
         let m2 = Metadata {
-            title: None,
-            summary: None,
             authors: HashSet::from_iter(IntoIter::new([
                 Arc::new(Author {
                     name: "a1".to_string(),
                     contacts: vec!["c3"].iter().map(|x| x.to_string()).collect(),
+                    profile: None,
                 }),
                 Arc::new(Author {
                     name: "a2".to_string(),
                     contacts: vec!["c1", "c2"].iter().map(|x| x.to_string()).collect(),
+                    profile: None,
                 }),
                 Arc::new(Author {
                     name: "a3".to_string(),
                     contacts: HashSet::new(),
+                    profile: None,
                 }),
                 Arc::new(Author {
                     name: "b1".to_string(),
                     contacts: vec!["c1", "c2"].iter().map(|x| x.to_string()).collect(),
+                    profile: None,
                 }),
             ])),
-            tags: HashSet::new(),
-            publishing_date: None,
-            last_edit_date: None,
-            data: HashMap::default(),
+            ..Default::default()
         };
 
         let result = m1.merge(&m2).unwrap();
@@ -223,30 +179,29 @@ mod tests {
         assert_eq!(
             result,
             Metadata {
-                title: None,
-                summary: None,
                 authors: HashSet::from_iter(IntoIter::new([
                     Arc::new(Author {
                         name: "a1".to_string(),
-                        contacts: vec!["c1", "c2", "c3"].iter().map(|x| x.to_string()).collect()
+                        contacts: vec!["c1", "c2", "c3"].iter().map(|x| x.to_string()).collect(),
+                        profile: None,
                     }),
                     Arc::new(Author {
                         name: "a2".to_string(),
-                        contacts: vec!["c1", "c2"].iter().map(|x| x.to_string()).collect()
+                        contacts: vec!["c1", "c2"].iter().map(|x| x.to_string()).collect(),
+                        profile: None,
                     }),
                     Arc::new(Author {
                         name: "a3".to_string(),
-                        contacts: vec!["c1"].iter().map(|x| x.to_string()).collect()
+                        contacts: vec!["c1"].iter().map(|x| x.to_string()).collect(),
+                        profile: None,
                     }),
                     Arc::new(Author {
                         name: "a4".to_string(),
-                        contacts: vec!["c1"].iter().map(|x| x.to_string()).collect()
+                        contacts: vec!["c1"].iter().map(|x| x.to_string()).collect(),
+                        profile: None,
                     }),
                 ])),
-                tags: HashSet::new(),
-                publishing_date: None,
-                last_edit_date: None,
-                data: HashMap::default(),
+                ..Default::default()
             }
         );
     }
@@ -254,44 +209,21 @@ mod tests {
     #[test]
     fn metadata_merge_tags() {
         let m1 = Metadata {
-            title: None,
-            summary: None,
-            authors: HashSet::new(),
             tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
-            publishing_date: None,
-            last_edit_date: None,
-            data: HashMap::default(),
+            ..Default::default()
         };
 
         let m2 = Metadata {
-            title: None,
-            summary: None,
-            authors: HashSet::new(),
             tags: HashSet::from_iter(IntoIter::new([Arc::new("t3".to_string()), Arc::new("t4".to_string())])),
-            publishing_date: None,
-            last_edit_date: None,
-            data: HashMap::default(),
+            ..Default::default()
         };
 
         let m3 = Metadata {
-            title: None,
-            summary: None,
-            authors: HashSet::new(),
             tags: HashSet::from_iter(IntoIter::new([Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
-            publishing_date: None,
-            last_edit_date: None,
-            data: HashMap::default(),
+            ..Default::default()
         };
 
-        let m4 = Metadata {
-            title: None,
-            summary: None,
-            authors: HashSet::new(),
-            tags: HashSet::new(),
-            publishing_date: None,
-            last_edit_date: None,
-            data: HashMap::default(),
-        };
+        let m4 = Metadata::default();
 
         let result1 = m1.merge(&m2).unwrap();
         let result2 = m1.merge(&m3).unwrap();
@@ -301,57 +233,37 @@ mod tests {
         assert_eq!(
             result1,
             Metadata {
-                title: None,
-                summary: None,
-                authors: HashSet::new(),
                 tags: HashSet::from_iter(IntoIter::new([
                     Arc::new("t1".to_string()),
                     Arc::new("t2".to_string()),
                     Arc::new("t3".to_string()),
                     Arc::new("t4".to_string())
                 ])),
-                publishing_date: None,
-                last_edit_date: None,
-                data: HashMap::default(),
+                ..Default::default()
             }
         );
 
         assert_eq!(
             result2,
             Metadata {
-                title: None,
-                summary: None,
-                authors: HashSet::new(),
                 tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
-                publishing_date: None,
-                last_edit_date: None,
-                data: HashMap::default(),
+                ..Default::default()
             }
         );
 
         assert_eq!(
             result3,
             Metadata {
-                title: None,
-                summary: None,
-                authors: HashSet::new(),
                 tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
-                publishing_date: None,
-                last_edit_date: None,
-                data: HashMap::default(),
+                ..Default::default()
             }
         );
 
         assert_eq!(
             result4,
             Metadata {
-                title: None,
-                summary: None,
-                authors: HashSet::new(),
                 tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
-                publishing_date: None,
-                last_edit_date: None,
-                data: HashMap::default(),
+                ..Default::default()
             }
         );
     }
@@ -365,28 +277,110 @@ mod tests {
                 Arc::new(Author {
                     name: "a1".to_string(),
                     contacts: vec!["c1", "c2"].iter().map(|x| x.to_string()).collect(),
+                    profile: None,
                 }),
                 Arc::new(Author {
                     name: "a2".to_string(),
                     contacts: HashSet::new(),
+                    profile: None,
                 }),
                 Arc::new(Author {
                     name: "a3".to_string(),
                     contacts: vec!["c1"].iter().map(|x| x.to_string()).collect(),
+                    profile: None,
                 }),
                 Arc::new(Author {
                     name: "a4".to_string(),
                     contacts: vec!["c1"].iter().map(|x| x.to_string()).collect(),
+                    profile: None,
                 }),
             ])),
             tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
             publishing_date: Some(DateTime::parse_from_rfc3339("2021-10-20T16:00:00-08:00").unwrap().timestamp()),
             last_edit_date: Some(DateTime::parse_from_rfc3339("2021-10-20T17:00:00-08:00").unwrap().timestamp()),
-            data: HashMap::default(),
+            ..Default::default()
         };
 
         let result = m.merge(&m).unwrap();
 
         assert_eq!(result, m);
     }
+
+    fn yaml_metadata(content: &str) -> Metadata {
+        serde_yaml::from_str(content).unwrap()
+    }
+
+    #[test]
+    fn metadata_merge_with_field_strategies() {
+        let parent = yaml_metadata(indoc! {"
+            ---
+            title: parent title
+            authors: [{name: a1, contacts: [c1]}, {name: a2}]
+            tags: [t1, t2]
+            data:
+              menu: {weight: 1, icon: home}
+              color: red
+        "});
+        let child = yaml_metadata(indoc! {"
+            ---
+            authors: [{name: a1, contacts: [c2]}]
+            tags: [t3]
+            data:
+              menu: {weight: 2}
+        "});
+        let tags = |m: &Metadata| {
+            let mut tags = m.tags.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+            tags.sort();
+            tags
+        };
+        let authors = |m: &Metadata| {
+            let mut authors = m.authors.iter().map(|a| (a.name.to_string(), a.contacts.len())).collect::<Vec<_>>();
+            authors.sort();
+            authors
+        };
+        let strategies = |yaml: &str| -> MergeStrategies { serde_yaml::from_str(yaml).unwrap() };
+
+        let result = child.merge_with(&parent, &strategies("{tags: replace, authors: append, data: deep-merge}")).unwrap();
+        assert_eq!(tags(&result), vec!["t3"]);
+        assert_eq!(authors(&result), vec![("a1".to_string(), 2), ("a2".to_string(), 0)]);
+        assert_eq!(
+            result.data.get("menu"),
+            Some(&Value::Map(
+                IntoIter::new([("weight".to_string(), Value::I32(2)), ("icon".to_string(), Value::String("home".to_string()))]).collect()
+            ))
+        );
+        assert_eq!(result.data.get("color"), Some(&Value::String("red".to_string())));
+        assert_eq!(result.title, Some(Arc::new("parent title".to_string())));
+
+        let result = child.merge_with(&parent, &strategies("{title: ignore-parent, tags: ignore-parent, data: replace}")).unwrap();
+        assert_eq!(result.title, None);
+        assert_eq!(tags(&result), vec!["t3"]);
+        assert_eq!(authors(&result), vec![("a1".to_string(), 2)]);
+        assert_eq!(result.data, child.data);
+
+        let empty_child = yaml_metadata("{title: child title}");
+        let result = empty_child.merge_with(&parent, &strategies("{tags: replace, authors: replace, data: replace}")).unwrap();
+        assert_eq!(tags(&result), vec!["t1", "t2"]);
+        assert_eq!(authors(&result), vec![("a1".to_string(), 1), ("a2".to_string(), 0)]);
+        assert_eq!(result.data, parent.data);
+
+        let result = child.merge(&parent).unwrap();
+        assert_eq!(tags(&result), vec!["t1", "t2", "t3"]);
+        assert_eq!(authors(&result), vec![("a1".to_string(), 2)]);
+        assert_eq!(result.data.get("menu"), child.data.get("menu"));
+
+        // unknown fields are kept along merges so that they can be reported on merged pages
+        let unknown_parent = yaml_metadata("{titel: parent title}");
+        assert_eq!(
+            child.merge(&unknown_parent).unwrap().unknown_fields.0.iter().map(|f| f.name.as_str()).collect::<Vec<&str>>(),
+            vec!["titel"]
+        );
+    }
+
+    #[test]
+    fn reject_combining_strategies_on_single_valued_fields() {
+        let strategies: MergeStrategies = serde_yaml::from_str("{tags: append, title: deep-merge}").unwrap();
+        assert_eq!(strategies.validate().err().unwrap().to_string(), "merge strategy deep-merge is not supported for field title");
+        assert!(serde_yaml::from_str::<MergeStrategies>("{tag: append}").is_err());
+    }
 }
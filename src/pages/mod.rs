@@ -1,26 +1,59 @@
 mod fs_loader;
 mod fs_loader_test;
 mod fs_page;
+mod git_loader;
+mod git_loader_test;
+mod glob;
+mod glob_test;
+mod i18n;
+mod i18n_test;
 mod loader;
+mod merge_strategy;
 mod metadata;
 mod metadata_test;
+mod mount_loader;
+mod mount_loader_test;
 mod page;
+mod page_sort;
+mod page_sort_test;
 mod page_test;
+mod query_filter;
+mod section_tree;
+mod section_tree_test;
 
+mod author_registry;
+mod author_registry_test;
 mod bundle_index;
 mod bundle_index_test;
+mod content_cache;
+mod content_cache_test;
 pub mod env;
 mod env_test;
+mod fingerprint;
 mod selector;
+mod selector_expr;
+mod selector_expr_test;
 mod selector_test;
 #[cfg(test)]
 pub(crate) mod test_page;
 
+pub use self::author_registry::*;
 pub use self::bundle_index::*;
+pub use self::content_cache::*;
 pub use self::env::*;
+pub use self::fingerprint::*;
 pub use self::fs_loader::*;
 pub use self::fs_page::*;
+pub use self::git_loader::*;
+pub use self::glob::*;
+pub use self::i18n::*;
 pub use self::loader::*;
+pub use self::merge_strategy::*;
 pub use self::metadata::*;
+pub use self::mount_loader::*;
 pub use self::page::*;
+pub use self::page_sort::*;
+pub use self::query_filter::*;
+pub use self::section_tree::*;
 pub use self::selector::*;
+pub use self::selector_expr::*;
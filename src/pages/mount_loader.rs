@@ -0,0 +1,55 @@
+use crate::pages::loader::Loader;
+use crate::pages::page::{ArcPage, Page, PageBundle};
+use crate::pages::{Env, VecBundle};
+use crate::pages_error::PagesError;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub struct Mount {
+    pub origin: String,
+    pub prefix: Vec<String>,
+    pub loader: Box<dyn Loader>,
+}
+
+impl Mount {
+    pub fn new(origin: String, prefix: &str, loader: Box<dyn Loader>) -> Self {
+        Mount {
+            origin,
+            prefix: prefix.split('/').filter(|s| !s.is_empty()).map(|s| s.to_string()).collect(),
+            loader,
+        }
+    }
+}
+
+// loads several sources into one bundle, each one under its path prefix
+pub struct MountLoader {
+    pub mounts: Vec<Mount>,
+}
+
+impl Loader for MountLoader {
+    fn load(&self, env: &Env) -> anyhow::Result<Arc<dyn PageBundle>> {
+        let mut pages: Vec<Arc<dyn Page>> = vec![];
+        let mut origins: HashMap<Vec<String>, &str> = HashMap::new();
+        for mount in &self.mounts {
+            env.print_vv("MountLoader", &format!("mounting {} on /{}", mount.origin, mount.prefix.join("/")));
+            for page in mount.loader.load(env)?.pages() {
+                let mut path = mount.prefix.clone();
+                path.extend(page.path().iter().cloned());
+                if let Some(other_origin) = origins.insert(path.clone(), &mount.origin) {
+                    return Err(PagesError::Conflict(format!("conflicting path {} provided by both {} and {}", path.join("/"), other_origin, mount.origin)).into());
+                }
+                if mount.prefix.is_empty() {
+                    pages.push(Arc::clone(page));
+                } else {
+                    pages.push(page.change_path(path));
+                }
+            }
+        }
+        Ok(Arc::new(VecBundle { p: pages }))
+    }
+
+    fn watched_paths(&self) -> Vec<PathBuf> {
+        self.mounts.iter().flat_map(|m| m.loader.watched_paths()).collect()
+    }
+}
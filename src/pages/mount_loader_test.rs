@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use crate::pages::mount_loader::{Mount, MountLoader};
+    use crate::pages::test_page::TestPage;
+    use crate::pages::{Env, FsLoader, Loader};
+    use rustassert::fs::{FileNode, TmpTestFolder};
+
+    fn source_folders() -> TmpTestFolder {
+        let test_folder = TmpTestFolder::new().unwrap();
+        test_folder
+            .write(&FileNode::Dir {
+                name: "docs".to_string(),
+                sub: vec![
+                    FileNode::new_file("index.md", "docs index".as_bytes().to_vec()),
+                    FileNode::Dir {
+                        name: "assets".to_string(),
+                        sub: vec![FileNode::new_file("style.css", "docs style".as_bytes().to_vec())],
+                    },
+                ],
+            })
+            .unwrap();
+        test_folder
+            .write(&FileNode::Dir {
+                name: "shared".to_string(),
+                sub: vec![
+                    FileNode::new_file("style.css", "shared style".as_bytes().to_vec()),
+                    FileNode::new_file("logo.svg", "logo".as_bytes().to_vec()),
+                ],
+            })
+            .unwrap();
+        test_folder
+    }
+
+    #[test]
+    fn load_mounted_sources_under_their_prefixes() {
+        let test_folder = source_folders();
+        let loader = MountLoader {
+            mounts: vec![
+                Mount::new("docs".to_string(), "/", Box::new(FsLoader::new(test_folder.get_path().join("docs")))),
+                Mount::new("shared".to_string(), "/shared/assets/", Box::new(FsLoader::new(test_folder.get_path().join("shared")))),
+            ],
+        };
+
+        let mut actual = loader.load(&Env::test()).unwrap().pages().iter().map(|p| TestPage::from(p)).collect::<Vec<_>>();
+        actual.sort_by_key(|f| f.path.join("/"));
+        assert_eq!(
+            actual,
+            &[
+                TestPage {
+                    path: vec!["assets".to_string(), "style.css".to_string()],
+                    metadata: None,
+                    content: "docs style".to_string(),
+                },
+                TestPage {
+                    path: vec!["index.md".to_string()],
+                    metadata: None,
+                    content: "docs index".to_string(),
+                },
+                TestPage {
+                    path: vec!["shared".to_string(), "assets".to_string(), "logo.svg".to_string()],
+                    metadata: None,
+                    content: "logo".to_string(),
+                },
+                TestPage {
+                    path: vec!["shared".to_string(), "assets".to_string(), "style.css".to_string()],
+                    metadata: None,
+                    content: "shared style".to_string(),
+                },
+            ]
+        );
+        assert_eq!(loader.watched_paths(), vec![test_folder.get_path().join("docs"), test_folder.get_path().join("shared")]);
+    }
+
+    #[test]
+    fn return_err_naming_both_origins_when_paths_conflict() {
+        let test_folder = source_folders();
+        let loader = MountLoader {
+            mounts: vec![
+                Mount::new("docs".to_string(), "", Box::new(FsLoader::new(test_folder.get_path().join("docs")))),
+                Mount::new("shared".to_string(), "assets", Box::new(FsLoader::new(test_folder.get_path().join("shared")))),
+            ],
+        };
+
+        let err = loader.load(&Env::test()).err().unwrap();
+        assert_eq!(err.to_string(), "conflicting path assets/style.css provided by both docs and shared");
+    }
+}
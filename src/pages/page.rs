@@ -1,4 +1,4 @@
-use crate::pages::{BundleIndex, Env, Metadata, PageIndex};
+use crate::pages::{BundleIndex, Env, Fingerprint, Metadata, PageIndex};
 use std::fmt::Debug;
 use std::io::Read;
 use std::sync::Arc;
@@ -7,6 +7,9 @@ pub trait Page: Debug + Send + Sync {
     fn path(&self) -> &[String];
     fn metadata(&self) -> Option<&Metadata>;
     fn open(&self, output_page: &PageIndex, output_index: &BundleIndex, env: &Env) -> anyhow::Result<Box<dyn Read>>;
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        None
+    }
 }
 
 pub trait ArcPage {
@@ -57,6 +60,10 @@ impl Page for PageProxy {
     fn open(&self, output_page: &PageIndex, output_bundle: &BundleIndex, env: &Env) -> anyhow::Result<Box<dyn Read>> {
         self.inner.open(output_page, output_bundle, env)
     }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        self.inner.fingerprint()
+    }
 }
 
 pub trait PageBundle: Send + Sync {
@@ -0,0 +1,154 @@
+use crate::config::Value;
+use crate::pages::PageIndex;
+use crate::pages_error::PagesError;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SortField {
+    Title,
+    PublishingDate,
+    LastEditDate,
+    Path,
+    Data(String),
+}
+
+// sort field, descending when written with a leading '-' : `-publishingDate`, `data.weight`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortKey {
+    pub field: SortField,
+    pub descending: bool,
+}
+
+// sort keys applied in order, next keys break ties of previous ones
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortBy(pub Vec<SortKey>);
+
+impl FromStr for SortKey {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (descending, name) = match s.trim().strip_prefix('-') {
+            Some(name) => (true, name),
+            None => (false, s.trim()),
+        };
+        let field = match name {
+            "title" => SortField::Title,
+            "publishingDate" | "publishing_date" => SortField::PublishingDate,
+            "lastEditDate" | "last_edit_date" => SortField::LastEditDate,
+            "path" => SortField::Path,
+            _ => match name.strip_prefix("data.") {
+                Some(key) if !key.is_empty() => SortField::Data(key.to_string()),
+                _ => return Err(PagesError::ValueParsing(format!("unknown sort key {}", s)).into()),
+            },
+        };
+        Ok(SortKey { field, descending })
+    }
+}
+
+impl Display for SortKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.descending {
+            f.write_str("-")?;
+        }
+        match &self.field {
+            SortField::Title => f.write_str("title"),
+            SortField::PublishingDate => f.write_str("publishingDate"),
+            SortField::LastEditDate => f.write_str("lastEditDate"),
+            SortField::Path => f.write_str("path"),
+            SortField::Data(key) => write!(f, "data.{}", key),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SortBy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Keys {
+            One(String),
+            Many(Vec<String>),
+        }
+        let keys = match Keys::deserialize(deserializer)? {
+            Keys::One(key) => vec![key],
+            Keys::Many(keys) => keys,
+        };
+        Ok(SortBy(keys.iter().map(|k| SortKey::from_str(k)).collect::<anyhow::Result<Vec<SortKey>>>().map_err(D::Error::custom)?))
+    }
+}
+
+impl Serialize for SortBy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.iter().map(|k| k.to_string()).collect::<Vec<String>>().serialize(serializer)
+    }
+}
+
+impl SortBy {
+    // pages missing a sort value are placed last whatever the order
+    pub fn compare(&self, a: &PageIndex, b: &PageIndex) -> Ordering {
+        for key in &self.0 {
+            let ordering = match (key.value(a), key.value(b)) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(va), Some(vb)) if key.descending => compare_values(&vb, &va),
+                (Some(va), Some(vb)) => compare_values(&va, &vb),
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }
+
+    pub fn sort(&self, pages: &mut [&PageIndex]) {
+        pages.sort_by(|a, b| self.compare(a, b));
+    }
+}
+
+impl SortKey {
+    fn value(&self, page: &PageIndex) -> Option<Value> {
+        let metadata = page.metadata.as_ref();
+        match &self.field {
+            SortField::Title => metadata.and_then(|m| m.title.clone()).map(Value::String),
+            SortField::PublishingDate => metadata.and_then(|m| m.publishing_date.as_ref()).map(|d| Value::I64(d.timestamp)),
+            SortField::LastEditDate => metadata.and_then(|m| m.last_edit_date.as_ref()).map(|d| Value::I64(d.timestamp)),
+            SortField::Path => Some(Value::String(page.page_ref.path.join("/"))),
+            SortField::Data(key) => metadata.and_then(|m| m.data.get(key)).cloned(),
+        }
+    }
+}
+
+// numbers are compared by value, values of different kinds by kind
+pub(crate) fn compare_values(a: &Value, b: &Value) -> Ordering {
+    let number = |v: &Value| match v {
+        Value::I32(n) => Some(*n as f64),
+        Value::I64(n) => Some(*n as f64),
+        Value::U64(n) => Some(*n as f64),
+        Value::F64(n) => Some(*n),
+        _ => None,
+    };
+    let kind = |v: &Value| match v {
+        Value::I32(_) | Value::I64(_) | Value::U64(_) | Value::F64(_) => 0,
+        Value::String(_) => 1,
+        Value::Bool(_) => 2,
+        _ => 3,
+    };
+    match (a, b) {
+        (Value::String(sa), Value::String(sb)) => sa.cmp(sb),
+        (Value::Bool(ba), Value::Bool(bb)) => ba.cmp(bb),
+        _ => match (number(a), number(b)) {
+            (Some(na), Some(nb)) => na.partial_cmp(&nb).unwrap_or(Ordering::Equal),
+            _ => kind(a).cmp(&kind(b)),
+        },
+    }
+}
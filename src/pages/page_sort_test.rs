@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::Value;
+    use crate::pages::test_page::TestPage;
+    use crate::pages::{BundleIndex, BundlePagination, BundleQuery, Metadata, Page, PageBundle, SortBy, SortField, SortKey, VecBundle};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn page(path: &str, title: Option<&str>, publishing_date: Option<i64>, weight: Option<Value>) -> Arc<dyn Page> {
+        Arc::new(TestPage {
+            path: vec![path.to_string()],
+            metadata: Some(Metadata {
+                title: title.map(|t| Arc::new(t.to_string())),
+                publishing_date,
+                data: weight.map(|w| ("weight".to_string(), w)).into_iter().collect::<HashMap<String, Value>>(),
+                ..Default::default()
+            }),
+            content: String::new(),
+        })
+    }
+
+    fn sorted_paths(bundle_index: &BundleIndex, sort_by: &str, skip: Option<usize>, limit: Option<usize>) -> Vec<String> {
+        let pagination = BundlePagination {
+            skip,
+            limit,
+            sort_by: Some(serde_yaml::from_str(sort_by).unwrap()),
+        };
+        bundle_index.query(&BundleQuery::Always, &pagination).iter().map(|p| p.page_ref.path.join("/")).collect()
+    }
+
+    #[test]
+    fn parse_sort_keys() {
+        let sort_by: SortBy = serde_yaml::from_str("[data.weight, -lastEditDate, title]").unwrap();
+        assert_eq!(
+            sort_by,
+            SortBy(vec![
+                SortKey {
+                    field: SortField::Data("weight".to_string()),
+                    descending: false
+                },
+                SortKey {
+                    field: SortField::LastEditDate,
+                    descending: true
+                },
+                SortKey {
+                    field: SortField::Title,
+                    descending: false
+                },
+            ])
+        );
+        let single: SortBy = serde_yaml::from_str("-publishing_date").unwrap();
+        assert_eq!(
+            single,
+            SortBy(vec![SortKey {
+                field: SortField::PublishingDate,
+                descending: true
+            }])
+        );
+        assert_eq!(serde_yaml::from_str::<SortBy>("weight").unwrap_err().to_string(), "unknown sort key weight");
+    }
+
+    #[test]
+    fn sort_query_results() {
+        let bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                page("a", Some("Zeta"), Some(100), Some(Value::I32(2))),
+                page("b", Some("Alpha"), Some(300), Some(Value::I32(1))),
+                page("c", None, Some(200), Some(Value::I32(2))),
+                page("d", Some("Beta"), None, None),
+            ],
+        });
+        let bundle_index = BundleIndex::from(&bundle);
+
+        // default order is by descending publishing date
+        let default_order = bundle_index.query(
+            &BundleQuery::Always,
+            &BundlePagination {
+                skip: None,
+                limit: None,
+                sort_by: None,
+            },
+        );
+        assert_eq!(default_order.iter().map(|p| p.page_ref.path.join("/")).collect::<Vec<String>>(), vec!["b", "c", "a", "d"]);
+
+        assert_eq!(sorted_paths(&bundle_index, "publishingDate", None, None), vec!["a", "c", "b", "d"]);
+        assert_eq!(sorted_paths(&bundle_index, "title", None, None), vec!["b", "d", "a", "c"]);
+        assert_eq!(sorted_paths(&bundle_index, "-title", None, None), vec!["a", "d", "b", "c"]);
+        assert_eq!(sorted_paths(&bundle_index, "[data.weight, -path]", None, None), vec!["b", "c", "a", "d"]);
+        assert_eq!(sorted_paths(&bundle_index, "[data.weight, path]", Some(1), Some(2)), vec!["a", "c"]);
+    }
+}
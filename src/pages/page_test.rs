@@ -3,7 +3,6 @@ mod tests {
     use crate::pages::test_page::TestPage;
     use crate::pages::{Metadata, Page, PageProxy};
     use chrono::DateTime;
-    use std::collections::{HashMap, HashSet};
     use std::sync::Arc;
 
     #[test]
@@ -46,12 +45,9 @@ mod tests {
             path: vec![],
             metadata: Some(Metadata {
                 title: Some(Arc::new("test page".to_string())),
-                summary: None,
-                authors: HashSet::new(),
-                tags: HashSet::new(),
                 publishing_date: Some(DateTime::parse_from_rfc3339("2021-10-20T16:00:00-08:00").unwrap().timestamp()),
                 last_edit_date: Some(DateTime::parse_from_rfc3339("2021-10-20T17:00:00-08:00").unwrap().timestamp()),
-                data: HashMap::default(),
+                ..Default::default()
             }),
             content: "".to_string(),
         });
@@ -62,15 +58,9 @@ mod tests {
             inner: Arc::clone(&test_page),
         };
 
-        assert!(matches!(proxy.metadata(), Some(metadata) if metadata == &Metadata{
-            title: Some(Arc::new("test page".to_string())),
-            summary: None,
-            authors: HashSet::new(),
-            tags: HashSet::new(),
-            publishing_date: Some(DateTime::parse_from_rfc3339("2021-10-20T16:00:00-08:00").unwrap().timestamp()),
-            last_edit_date: Some(DateTime::parse_from_rfc3339("2021-10-20T17:00:00-08:00").unwrap().timestamp()),
-            data: HashMap::default(),
-        }))
+        assert!(
+            matches!(proxy.metadata(), Some(metadata) if metadata == &Metadata { title: Some(Arc::new("test page".to_string())), publishing_date: Some(DateTime::parse_from_rfc3339("2021-10-20T16:00:00-08:00").unwrap().timestamp()), last_edit_date: Some(DateTime::parse_from_rfc3339("2021-10-20T17:00:00-08:00").unwrap().timestamp()), ..Default::default() })
+        )
     }
 
     #[test]
@@ -79,12 +69,9 @@ mod tests {
             path: vec![],
             metadata: Some(Metadata {
                 title: Some(Arc::new("test page".to_string())),
-                summary: None,
-                authors: HashSet::new(),
-                tags: HashSet::new(),
                 publishing_date: Some(DateTime::parse_from_rfc3339("2021-10-20T16:00:00-08:00").unwrap().timestamp()),
                 last_edit_date: Some(DateTime::parse_from_rfc3339("2021-10-20T17:00:00-08:00").unwrap().timestamp()),
-                data: HashMap::default(),
+                ..Default::default()
             }),
             content: "".to_string(),
         });
@@ -93,24 +80,15 @@ mod tests {
             new_path: None,
             new_metadata: Some(Metadata {
                 title: Some(Arc::new("new test page".to_string())),
-                summary: None,
-                authors: HashSet::new(),
-                tags: HashSet::new(),
                 publishing_date: Some(DateTime::parse_from_rfc3339("2021-10-20T18:00:00-08:00").unwrap().timestamp()),
                 last_edit_date: Some(DateTime::parse_from_rfc3339("2021-10-20T19:00:00-08:00").unwrap().timestamp()),
-                data: HashMap::default(),
+                ..Default::default()
             }),
             inner: Arc::clone(&test_page),
         };
 
-        assert!(matches!(proxy.metadata(), Some(metadata) if metadata == &Metadata{
-            title: Some(Arc::new("new test page".to_string())),
-            summary: None,
-            authors: HashSet::new(),
-            tags: HashSet::new(),
-            publishing_date: Some(DateTime::parse_from_rfc3339("2021-10-20T18:00:00-08:00").unwrap().timestamp()),
-            last_edit_date: Some(DateTime::parse_from_rfc3339("2021-10-20T19:00:00-08:00").unwrap().timestamp()),
-            data: HashMap::default(),
-        }))
+        assert!(
+            matches!(proxy.metadata(), Some(metadata) if metadata == &Metadata { title: Some(Arc::new("new test page".to_string())), publishing_date: Some(DateTime::parse_from_rfc3339("2021-10-20T18:00:00-08:00").unwrap().timestamp()), last_edit_date: Some(DateTime::parse_from_rfc3339("2021-10-20T19:00:00-08:00").unwrap().timestamp()), ..Default::default() })
+        )
     }
 }
@@ -0,0 +1,108 @@
+use crate::config::Value;
+use crate::pages::{compare_values, DateQuery, DateQueryConfig};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+// date query resolved once parsed, dates being checked at parsing time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "DateQueryConfig", into = "DateQueryConfig")]
+pub struct DateFilter {
+    pub config: DateQueryConfig,
+    pub query: DateQuery,
+}
+
+impl TryFrom<DateQueryConfig> for DateFilter {
+    type Error = anyhow::Error;
+
+    fn try_from(config: DateQueryConfig) -> Result<Self, Self::Error> {
+        let query = DateQuery::try_from(&config)?;
+        Ok(DateFilter { config, query })
+    }
+}
+
+impl From<DateFilter> for DateQueryConfig {
+    fn from(filter: DateFilter) -> Self {
+        filter.config
+    }
+}
+
+// regex matched against the joined page path (`docs/guide/intro.md`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PathRegex(pub Regex);
+
+impl TryFrom<String> for PathRegex {
+    type Error = regex::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Ok(PathRegex(Regex::new(&value)?))
+    }
+}
+
+impl From<PathRegex> for String {
+    fn from(path_regex: PathRegex) -> Self {
+        path_regex.0.as_str().to_string()
+    }
+}
+
+impl PartialEq for PathRegex {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl PathRegex {
+    pub fn match_path(&self, path: &[String]) -> bool {
+        self.0.is_match(&path.join("/"))
+    }
+}
+
+// data key existence, compared to the given values when set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataQuery {
+    pub key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eq: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ne: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lt: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lte: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gt: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gte: Option<Value>,
+}
+
+impl DataQuery {
+    pub fn match_data(&self, data: &HashMap<String, Value>) -> bool {
+        let value = match data.get(&self.key) {
+            None => return false,
+            Some(v) => v,
+        };
+        let checks: [(&Option<Value>, &[Ordering]); 6] = [
+            (&self.eq, &[Ordering::Equal]),
+            (&self.ne, &[Ordering::Less, Ordering::Greater]),
+            (&self.lt, &[Ordering::Less]),
+            (&self.lte, &[Ordering::Less, Ordering::Equal]),
+            (&self.gt, &[Ordering::Greater]),
+            (&self.gte, &[Ordering::Greater, Ordering::Equal]),
+        ];
+        checks.iter().all(|(expected, orderings)| match expected {
+            None => true,
+            Some(expected) => orderings.contains(&compare_values(value, expected)),
+        })
+    }
+}
+
+// case insensitive substring match
+pub(crate) fn contains_text(text: Option<&String>, fragment: &str) -> bool {
+    match text {
+        None => false,
+        Some(text) => text.to_lowercase().contains(&fragment.to_lowercase()),
+    }
+}
@@ -0,0 +1,133 @@
+use crate::pages::{PageIndex, PageRef};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+// section or page listed in a section
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SectionEntry {
+    pub path: Vec<String>,
+    pub name: String,
+    pub title: Option<String>,
+    pub uri: String,
+    // page itself, or landing page of a section
+    pub page_ref: Option<PageRef>,
+    pub is_section: bool,
+}
+
+// directory of indexed pages, sub sections are listed before pages
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Section {
+    pub entry: SectionEntry,
+    pub children: Vec<SectionEntry>,
+}
+
+// position of a page in the section tree
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct PageSections<'a> {
+    pub parent: Option<&'a SectionEntry>,
+    pub children: Vec<&'a SectionEntry>,
+    pub siblings: Vec<&'a SectionEntry>,
+    pub breadcrumbs: Vec<&'a SectionEntry>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SectionTree {
+    sections: BTreeMap<Vec<String>, Section>,
+}
+
+impl SectionTree {
+    pub fn get(&self, path: &[String]) -> Option<&Section> {
+        self.sections.get(path)
+    }
+
+    // index pages land their directory section : their parent is the enclosing section and their children are the section ones
+    pub fn page_sections(&self, page: &PageIndex) -> PageSections<'_> {
+        let path = &page.page_ref.path;
+        if path.is_empty() {
+            return PageSections::default();
+        }
+        let dir = &path[..path.len() - 1];
+        let (own_path, parent_path) = match is_index_page(path) {
+            true if dir.is_empty() => (dir, None),
+            true => (dir, Some(&dir[..dir.len() - 1])),
+            false => (path.as_slice(), Some(dir)),
+        };
+        let parent = parent_path.and_then(|p| self.get(p));
+        PageSections {
+            parent: parent.map(|s| &s.entry),
+            children: match is_index_page(path) {
+                true => self.get(dir).map(|s| s.children.iter().collect()).unwrap_or_default(),
+                false => vec![],
+            },
+            siblings: parent.map(|s| s.children.iter().filter(|e| e.path != own_path).collect()).unwrap_or_default(),
+            breadcrumbs: match parent_path {
+                None => vec![],
+                Some(p) => (0..=p.len()).filter_map(|i| self.get(&p[..i])).map(|s| &s.entry).collect(),
+            },
+        }
+    }
+
+    fn section_mut(&mut self, path: &[String]) -> &mut Section {
+        self.sections.entry(path.to_vec()).or_insert_with(|| Section {
+            entry: SectionEntry {
+                path: path.to_vec(),
+                name: path.last().cloned().unwrap_or_default(),
+                title: None,
+                uri: PageIndex::uri_from_path(&[path, &["index.html".to_string()]].concat()),
+                page_ref: None,
+                is_section: true,
+            },
+            children: vec![],
+        })
+    }
+}
+
+impl From<&[PageIndex]> for SectionTree {
+    fn from(pages: &[PageIndex]) -> Self {
+        let mut result = SectionTree::default();
+        for page in pages.iter().filter(|p| is_content_page(p)) {
+            let path = &page.page_ref.path;
+            let dir = match path.len() {
+                0 => continue,
+                len => &path[..len - 1],
+            };
+            for i in 0..dir.len() {
+                result.section_mut(&dir[..i]);
+            }
+            let title = page.metadata.as_ref().and_then(|m| m.title.clone());
+            let section = result.section_mut(dir);
+            if is_index_page(path) && section.entry.page_ref.is_none() {
+                section.entry.title = title;
+                section.entry.uri = page.page_uri.clone();
+                section.entry.page_ref = Some(page.page_ref.clone());
+                continue;
+            }
+            section.children.push(SectionEntry {
+                path: path.clone(),
+                name: path[path.len() - 1].clone(),
+                title,
+                uri: page.page_uri.clone(),
+                page_ref: Some(page.page_ref.clone()),
+                is_section: false,
+            });
+        }
+
+        let sub_sections = result.sections.values().filter(|s| !s.entry.path.is_empty()).map(|s| s.entry.clone()).collect::<Vec<SectionEntry>>();
+        for entry in sub_sections {
+            result.section_mut(&entry.path[..entry.path.len() - 1]).children.push(entry);
+        }
+        for section in result.sections.values_mut() {
+            section.children.sort_by(|a, b| b.is_section.cmp(&a.is_section).then_with(|| a.name.cmp(&b.name)));
+        }
+        result
+    }
+}
+
+// assets and index files are left out of sections : only pages with metadata and html pages are listed
+fn is_content_page(page: &PageIndex) -> bool {
+    page.metadata.is_some() || matches!(page.page_ref.path.last(), Some(name) if name.ends_with(".html") || name.ends_with(".htm"))
+}
+
+fn is_index_page(path: &[String]) -> bool {
+    matches!(path.last().map(|s| s.as_str()), Some("index.html") | Some("index.htm"))
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use crate::pages::test_page::TestPage;
+    use crate::pages::{BundleIndex, Metadata, Page, PageBundle, PageIndex, SectionEntry, VecBundle};
+    use std::sync::Arc;
+
+    fn titled_page(path: &str, title: &str) -> Arc<dyn Page> {
+        Arc::new(TestPage {
+            path: path.split('/').map(|s| s.to_string()).collect(),
+            metadata: Some(Metadata {
+                title: Some(Arc::new(title.to_string())),
+                ..Default::default()
+            }),
+            content: String::new(),
+        })
+    }
+
+    fn asset(path: &str) -> Arc<dyn Page> {
+        Arc::new(TestPage {
+            path: path.split('/').map(|s| s.to_string()).collect(),
+            metadata: None,
+            content: String::new(),
+        })
+    }
+
+    fn docs_bundle() -> Arc<dyn PageBundle> {
+        Arc::new(VecBundle {
+            p: vec![
+                titled_page("index.html", "Home"),
+                titled_page("docs/index.html", "Docs"),
+                titled_page("docs/setup.html", "Setup"),
+                titled_page("docs/faq.html", "FAQ"),
+                titled_page("docs/guide/index.html", "Guide"),
+                titled_page("docs/guide/templates.html", "Templates"),
+                titled_page("docs/api/stages.html", "Stages"),
+                asset("docs/guide/diagram.png"),
+                asset("assets/style.css"),
+                asset("all_pages.json"),
+            ],
+        })
+    }
+
+    fn names(entries: &[&SectionEntry]) -> Vec<String> {
+        entries.iter().map(|e| e.name.to_string()).collect()
+    }
+
+    #[test]
+    fn build_sections_from_page_directories() {
+        let bundle_index = BundleIndex::from(&docs_bundle());
+        let tree = bundle_index.section_tree();
+
+        let docs = tree.get(&["docs".to_string()]).unwrap();
+        assert_eq!(docs.entry.title, Some("Docs".to_string()));
+        assert_eq!(docs.entry.uri, "/docs/");
+        assert_eq!(
+            docs.children.iter().map(|e| (e.name.as_str(), e.is_section)).collect::<Vec<_>>(),
+            vec![("api", true), ("guide", true), ("faq.html", false), ("setup.html", false)]
+        );
+
+        // sections without landing page keep their directory uri
+        let api = tree.get(&["docs".to_string(), "api".to_string()]).unwrap();
+        assert_eq!(
+            api.entry,
+            SectionEntry {
+                path: vec!["docs".to_string(), "api".to_string()],
+                name: "api".to_string(),
+                title: None,
+                uri: "/docs/api/".to_string(),
+                page_ref: None,
+                is_section: true,
+            }
+        );
+        assert_eq!(tree.get(&[]).unwrap().children.iter().map(|e| e.name.as_str()).collect::<Vec<_>>(), vec!["docs"]);
+        assert!(tree.get(&["blog".to_string()]).is_none());
+
+        // assets and index files are not listed
+        assert!(tree.get(&["assets".to_string()]).is_none());
+        assert_eq!(
+            names(&tree.get(&["docs".to_string(), "guide".to_string()]).unwrap().children.iter().collect::<Vec<_>>()),
+            vec!["templates.html"]
+        );
+    }
+
+    #[test]
+    fn locate_pages_in_section_tree() {
+        let bundle = docs_bundle();
+        let bundle_index = BundleIndex::from(&bundle);
+        let tree = bundle_index.section_tree();
+        let page = |path: &str| bundle_index.page_index(bundle.pages().iter().find(|p| p.path().join("/") == path).unwrap());
+
+        let setup: PageIndex = page("docs/setup.html");
+        let sections = tree.page_sections(&setup);
+        assert_eq!(sections.parent.map(|e| e.name.as_str()), Some("docs"));
+        assert!(sections.children.is_empty());
+        assert_eq!(names(&sections.siblings), vec!["api", "guide", "faq.html"]);
+        assert_eq!(names(&sections.breadcrumbs), vec!["", "docs"]);
+
+        let guide = page("docs/guide/index.html");
+        let sections = tree.page_sections(&guide);
+        assert_eq!(sections.parent.map(|e| e.name.as_str()), Some("docs"));
+        assert_eq!(names(&sections.children), vec!["templates.html"]);
+        assert_eq!(names(&sections.siblings), vec!["api", "faq.html", "setup.html"]);
+        assert_eq!(sections.breadcrumbs.iter().map(|e| e.title.as_deref()).collect::<Vec<_>>(), vec![Some("Home"), Some("Docs")]);
+
+        let home = page("index.html");
+        let sections = tree.page_sections(&home);
+        assert_eq!(sections.parent, None);
+        assert_eq!(names(&sections.children), vec!["docs"]);
+        assert!(sections.siblings.is_empty());
+        assert!(sections.breadcrumbs.is_empty());
+    }
+}
@@ -1,7 +1,12 @@
-use crate::pages::Page;
+use crate::pages::{glob_match_path, Page};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
 use std::any::Any;
+use std::convert::TryFrom;
 use std::fmt::Debug;
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 pub trait Selector: Send + Sync + Debug {
     fn select(&self, page: &Arc<dyn Page>) -> bool;
@@ -17,80 +22,7 @@ pub struct PathSelector {
 
 impl PathSelector {
     pub fn select_page(path: &[String], query: &[String]) -> bool {
-        if query.is_empty() {
-            return path.is_empty();
-        }
-        if path.is_empty() {
-            return false;
-        }
-        let item_query = query.get(0).unwrap();
-        if item_query == "**" {
-            let mut i = 1;
-            while let Some(next_item_query) = query.get(i) {
-                if next_item_query == "**" {
-                    i += 1;
-                    continue;
-                }
-                for (pos, v) in path.iter().enumerate() {
-                    if PathSelector::entry_match(next_item_query, v) {
-                        return PathSelector::select_page(&path[pos + 1..], &query[i + 1..]);
-                    }
-                }
-                return false;
-            }
-            return true;
-        }
-
-        if PathSelector::entry_match(item_query, &path[0]) {
-            return PathSelector::select_page(&path[1..], &query[1..]);
-        }
-
-        false
-    }
-
-    fn entry_match_chars(ic: &[char], qc: &[char]) -> bool {
-        if qc.is_empty() {
-            return ic.is_empty();
-        }
-        if ic.is_empty() {
-            return false;
-        }
-        let q = qc[0];
-        if q == '*' {
-            let mut i = 1;
-            while let Some(next_q) = qc.get(i) {
-                if *next_q == '*' {
-                    i += 1;
-                    continue;
-                }
-                for (pos, v) in ic.iter().enumerate() {
-                    if next_q == v {
-                        return PathSelector::entry_match_chars(&ic[pos + 1..], &qc[i + 1..]);
-                    }
-                }
-                return false;
-            }
-            return true;
-        }
-
-        if ic[0] == qc[0] {
-            return PathSelector::entry_match_chars(&ic[1..], &qc[1..]);
-        }
-
-        false
-    }
-
-    fn entry_match(query: &str, path_item: &str) -> bool {
-        if query == "*" || query == path_item {
-            return true;
-        }
-        if query.contains('*') {
-            let pic = path_item.chars().collect::<Vec<char>>();
-            let qc = query.chars().collect::<Vec<char>>();
-            return PathSelector::entry_match_chars(&pic, &qc);
-        }
-
-        false
+        glob_match_path(path, query)
     }
 }
 
@@ -160,7 +92,76 @@ impl Selector for AuthorSelector {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(untagged)]
+pub enum DateQueryConfig {
+    BeforeDate {
+        #[serde(alias = "beforeDate")]
+        before_date: String,
+    },
+    AfterDate {
+        #[serde(alias = "afterDate")]
+        after_date: String,
+    },
+    BeforeTime {
+        #[serde(alias = "beforeTime")]
+        before_time: String,
+    },
+    AfterTime {
+        #[serde(alias = "afterTime")]
+        after_time: String,
+    },
+    // both days included
+    BetweenDates {
+        #[serde(alias = "betweenDates")]
+        between_dates: (String, String),
+    },
+    // both times excluded
+    BetweenTimes {
+        #[serde(alias = "betweenTimes")]
+        between_times: (String, String),
+    },
+}
+
+impl DateQueryConfig {
+    fn day_start(date: &str) -> anyhow::Result<i64> {
+        if date == "now" {
+            return Ok(DateTime::<Utc>::from(SystemTime::now()).date().and_hms(0, 0, 0).timestamp());
+        }
+        Ok(NaiveDate::from_str(date)?.and_hms(0, 0, 0).timestamp())
+    }
+
+    fn day_end(date: &str) -> anyhow::Result<i64> {
+        if date == "now" {
+            return Ok(DateTime::<Utc>::from(SystemTime::now()).date().and_hms(23, 59, 59).timestamp());
+        }
+        Ok(NaiveDate::from_str(date)?.and_hms(23, 59, 59).timestamp())
+    }
+
+    fn time(time: &str) -> anyhow::Result<i64> {
+        if time == "now" {
+            return Ok(DateTime::<Utc>::from(SystemTime::now()).timestamp());
+        }
+        Ok(NaiveDateTime::from_str(time)?.timestamp())
+    }
+}
+
+impl TryFrom<&DateQueryConfig> for DateQuery {
+    type Error = anyhow::Error;
+
+    fn try_from(config: &DateQueryConfig) -> Result<Self, Self::Error> {
+        Ok(match config {
+            DateQueryConfig::BeforeDate { before_date } => DateQuery::Before(DateQueryConfig::day_start(before_date)?),
+            DateQueryConfig::AfterDate { after_date } => DateQuery::After(DateQueryConfig::day_end(after_date)?),
+            DateQueryConfig::BeforeTime { before_time } => DateQuery::Before(DateQueryConfig::time(before_time)?),
+            DateQueryConfig::AfterTime { after_time } => DateQuery::After(DateQueryConfig::time(after_time)?),
+            DateQueryConfig::BetweenDates { between_dates: (from, to) } => DateQuery::Between(DateQueryConfig::day_start(from)? - 1, DateQueryConfig::day_end(to)? + 1),
+            DateQueryConfig::BetweenTimes { between_times: (from, to) } => DateQuery::Between(DateQueryConfig::time(from)?, DateQueryConfig::time(to)?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub enum DateQuery {
     Before(i64),
     After(i64),
@@ -195,6 +196,21 @@ impl Selector for PublishingDateSelector {
     }
 }
 
+#[derive(Debug)]
+pub struct MetadataSelector {
+    pub has_metadata: bool,
+}
+
+impl Selector for MetadataSelector {
+    fn select(&self, page: &Arc<dyn Page>) -> bool {
+        page.metadata().is_some() == self.has_metadata
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+}
+
 #[derive(Debug)]
 pub enum Logical {
     And(Vec<Arc<dyn Selector>>),
@@ -0,0 +1,343 @@
+use crate::config::Value;
+use crate::pages::{BundleQuery, DataQuery, DateFilter, DateQueryConfig, PathRegex};
+use crate::pages_error::PagesError;
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// compact selector expression : `ext:.md and (tag:rust or author:"Jane") and not path:drafts/**`
+// `not` binds tighter than `and`, itself binding tighter than `or`
+// dates are ranges : `publishing:>2021-01-01`, `lastEdit:<2021-12-31T12:00:00`, `publishing:2021-01-01..2021-06-30`
+const EXPR_KEYS: [&str; 14] = [
+    "path",
+    "tag",
+    "tags",
+    "author",
+    "lang",
+    "ext",
+    "title",
+    "summary",
+    "publishing",
+    "lastEdit",
+    "last_edit",
+    "pathRegex",
+    "path_regex",
+    "taxonomy",
+];
+// keys naming their field after a dot : `data.weight:2`, `taxonomy.series:rust`
+const EXPR_PREFIXES: [&str; 2] = ["data.", "taxonomy."];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Filter { key: String, value: String, quoted: bool },
+    End,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Open => f.write_str("`(`"),
+            Token::Close => f.write_str("`)`"),
+            Token::And => f.write_str("`and`"),
+            Token::Or => f.write_str("`or`"),
+            Token::Not => f.write_str("`not`"),
+            Token::Filter { key, value, .. } => write!(f, "`{}:{}`", key, value),
+            Token::End => f.write_str("end of expression"),
+        }
+    }
+}
+
+fn expr_error<T>(message: String, column: usize) -> anyhow::Result<T> {
+    Err(PagesError::ValueParsing(format!("{} at column {}", message, column)).into())
+}
+
+fn is_expr_key(key: &str) -> bool {
+    EXPR_KEYS.contains(&key) || EXPR_PREFIXES.iter().any(|prefix| matches!(key.strip_prefix(prefix), Some(k) if !k.is_empty()))
+}
+
+// tokens with their 1-based column, ending with Token::End
+fn tokenize(expr: &str) -> anyhow::Result<Vec<(Token, usize)>> {
+    let chars = expr.chars().collect::<Vec<char>>();
+    let mut tokens = vec![];
+    let mut pos = 0;
+    while pos < chars.len() {
+        let column = pos + 1;
+        match chars[pos] {
+            c if c.is_whitespace() => pos += 1,
+            '(' => {
+                tokens.push((Token::Open, column));
+                pos += 1;
+            }
+            ')' => {
+                tokens.push((Token::Close, column));
+                pos += 1;
+            }
+            '"' => return expr_error("unexpected quoted value, expected key:value".to_string(), column),
+            _ => {
+                let start = pos;
+                while pos < chars.len() && !chars[pos].is_whitespace() && !matches!(chars[pos], '(' | ')' | ':' | '"') {
+                    pos += 1;
+                }
+                let word = chars[start..pos].iter().collect::<String>();
+                if pos < chars.len() && chars[pos] == ':' {
+                    pos += 1;
+                    let (value, quoted) = match chars.get(pos) {
+                        Some('"') => {
+                            let mut value = String::new();
+                            pos += 1;
+                            loop {
+                                match chars.get(pos) {
+                                    None => return expr_error("unterminated quoted value".to_string(), column),
+                                    Some('"') => break,
+                                    Some('\\') if pos + 1 < chars.len() => {
+                                        value.push(chars[pos + 1]);
+                                        pos += 2;
+                                    }
+                                    Some(c) => {
+                                        value.push(*c);
+                                        pos += 1;
+                                    }
+                                }
+                            }
+                            pos += 1;
+                            (value, true)
+                        }
+                        _ => {
+                            let value_start = pos;
+                            while pos < chars.len() && !chars[pos].is_whitespace() && chars[pos] != ')' {
+                                pos += 1;
+                            }
+                            (chars[value_start..pos].iter().collect::<String>(), false)
+                        }
+                    };
+                    if value.is_empty() && !quoted {
+                        return expr_error(format!("missing value for key `{}`", word), column);
+                    }
+                    tokens.push((Token::Filter { key: word, value, quoted }, column));
+                    continue;
+                }
+                let token = match word.as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    _ => return expr_error(format!("unexpected `{}`, expected key:value", word), column),
+                };
+                tokens.push((token, column));
+            }
+        }
+    }
+    tokens.push((Token::End, chars.len() + 1));
+    Ok(tokens)
+}
+
+struct ExprParser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl ExprParser {
+    fn peek(&self) -> &(Token, usize) {
+        &self.tokens[self.pos]
+    }
+
+    fn next(&mut self) -> (Token, usize) {
+        let token = self.tokens[self.pos].clone();
+        if token.0 != Token::End {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn parse_or(&mut self) -> anyhow::Result<BundleQuery> {
+        let mut or = vec![self.parse_and()?];
+        while self.peek().0 == Token::Or {
+            self.next();
+            or.push(self.parse_and()?);
+        }
+        Ok(if or.len() == 1 { or.pop().unwrap() } else { BundleQuery::Or { or } })
+    }
+
+    fn parse_and(&mut self) -> anyhow::Result<BundleQuery> {
+        let mut and = vec![self.parse_not()?];
+        while self.peek().0 == Token::And {
+            self.next();
+            and.push(self.parse_not()?);
+        }
+        Ok(if and.len() == 1 { and.pop().unwrap() } else { BundleQuery::And { and } })
+    }
+
+    fn parse_not(&mut self) -> anyhow::Result<BundleQuery> {
+        if self.peek().0 == Token::Not {
+            self.next();
+            return Ok(BundleQuery::Not { not: Box::new(self.parse_not()?) });
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> anyhow::Result<BundleQuery> {
+        match self.next() {
+            (Token::Open, _) => {
+                let query = self.parse_or()?;
+                match self.next() {
+                    (Token::Close, _) => Ok(query),
+                    (token, column) => expr_error(format!("unexpected {}, expected `)`", token), column),
+                }
+            }
+            (Token::Filter { key, value, quoted }, column) => filter_query(&key, value, quoted, column),
+            (token, column) => expr_error(format!("unexpected {}, expected key:value", token), column),
+        }
+    }
+}
+
+fn filter_query(key: &str, value: String, quoted: bool, column: usize) -> anyhow::Result<BundleQuery> {
+    Ok(match key {
+        "path" => BundleQuery::Path { path: value },
+        "tag" => BundleQuery::Tag { tag: value },
+        "tags" => BundleQuery::Tags {
+            tags: value.split(',').map(|tag| tag.trim().to_string()).filter(|tag| !tag.is_empty()).collect(),
+        },
+        "author" => BundleQuery::Author { author: value },
+        "lang" => BundleQuery::Lang { lang: value },
+        "ext" => BundleQuery::Ext { ext: value },
+        "title" => BundleQuery::TitleContains { title_contains: value },
+        "summary" => BundleQuery::SummaryContains { summary_contains: value },
+        "publishing" => BundleQuery::Publishing {
+            publishing: Box::new(date_filter(&value, column)?),
+        },
+        "lastEdit" | "last_edit" => BundleQuery::LastEdit {
+            last_edit: Box::new(date_filter(&value, column)?),
+        },
+        "taxonomy" => return expr_error("missing taxonomy name, expected `taxonomy.<name>:<term>`".to_string(), column),
+        "pathRegex" | "path_regex" => match PathRegex::try_from(value) {
+            Ok(path_regex) => BundleQuery::PathRegex { path_regex: Box::new(path_regex) },
+            Err(e) => return expr_error(format!("invalid path regex ({})", e), column),
+        },
+        _ => match key.strip_prefix("data.") {
+            Some(data_key) if !data_key.is_empty() => BundleQuery::Data {
+                data: Box::new(DataQuery {
+                    key: data_key.to_string(),
+                    eq: Some(data_value(value, quoted)),
+                    ne: None,
+                    lt: None,
+                    lte: None,
+                    gt: None,
+                    gte: None,
+                }),
+            },
+            _ => match key.strip_prefix("taxonomy.") {
+                Some(taxonomy) if !taxonomy.is_empty() => BundleQuery::Taxonomy {
+                    taxonomy: taxonomy.to_string(),
+                    term: value,
+                },
+                _ => return expr_error(format!("unknown selector key `{}`", key), column),
+            },
+        },
+    })
+}
+
+// `>day` and `<day` exclude the day, `from..to` includes both days ; bounds with a `T` are times
+fn date_filter(value: &str, column: usize) -> anyhow::Result<DateFilter> {
+    let is_time = value.contains('T');
+    let config = if let Some(date) = value.strip_prefix('>') {
+        match is_time {
+            true => DateQueryConfig::AfterTime { after_time: date.to_string() },
+            false => DateQueryConfig::AfterDate { after_date: date.to_string() },
+        }
+    } else if let Some(date) = value.strip_prefix('<') {
+        match is_time {
+            true => DateQueryConfig::BeforeTime { before_time: date.to_string() },
+            false => DateQueryConfig::BeforeDate { before_date: date.to_string() },
+        }
+    } else if let Some((from, to)) = value.split_once("..") {
+        match (from.contains('T'), to.contains('T')) {
+            (true, true) => DateQueryConfig::BetweenTimes {
+                between_times: (from.to_string(), to.to_string()),
+            },
+            (false, false) => DateQueryConfig::BetweenDates {
+                between_dates: (from.to_string(), to.to_string()),
+            },
+            _ => return expr_error(format!("date range `{}` mixes a date and a time", value), column),
+        }
+    } else {
+        return expr_error(format!("invalid date range `{}`, expected `>date`, `<date` or `from..to`", value), column);
+    };
+    match DateFilter::try_from(config) {
+        Ok(filter) => Ok(filter),
+        Err(e) => expr_error(format!("invalid date range `{}` ({})", value, e), column),
+    }
+}
+
+// unquoted data values are typed : `data.weight:2`, `data.featured:true`
+fn data_value(value: String, quoted: bool) -> Value {
+    if quoted {
+        return Value::String(value);
+    }
+    if let Ok(n) = i64::from_str(&value) {
+        return Value::I64(n);
+    }
+    if let Ok(n) = f64::from_str(&value) {
+        return Value::F64(n);
+    }
+    match value.as_str() {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => Value::String(value),
+    }
+}
+
+pub fn parse_selector_expr(expr: &str) -> anyhow::Result<BundleQuery> {
+    let mut parser = ExprParser { tokens: tokenize(expr)?, pos: 0 };
+    if parser.peek().0 == Token::End {
+        return expr_error("empty selector expression".to_string(), 1);
+    }
+    let query = parser.parse_or()?;
+    match parser.next() {
+        (Token::End, _) => Ok(query),
+        (token, column) => expr_error(format!("unexpected {}", token), column),
+    }
+}
+
+// expressions start with a known `key:value`, possibly behind `(` or `not` ; path short cuts (`a/**`) never do
+fn starts_with_expr(value: &str) -> bool {
+    let value = value.trim_start();
+    if let Some(rest) = value.strip_prefix('(') {
+        return starts_with_expr(rest);
+    }
+    let word_end = value.find(|c: char| c.is_whitespace() || matches!(c, '(' | ')' | ':' | '"')).unwrap_or(value.len());
+    match (&value[..word_end], value[word_end..].chars().next()) {
+        ("not", Some(c)) if c.is_whitespace() || c == '(' => starts_with_expr(&value[word_end..]),
+        // `tag: t1` is a yaml query
+        (key, Some(':')) => is_expr_key(key) && matches!(value[word_end + 1..].chars().next(), Some(c) if !c.is_whitespace()),
+        _ => false,
+    }
+}
+
+// tells expressions apart from path short cuts, rejecting values that could be read both ways (`not drafts/**`)
+pub fn is_selector_expr(value: &str) -> anyhow::Result<bool> {
+    if starts_with_expr(value) {
+        return Ok(true);
+    }
+    let value = value.trim_start();
+    let word_end = value.find(|c: char| c.is_whitespace() || c == '(').unwrap_or(value.len());
+    if value.starts_with('(') || (&value[..word_end] == "not" && word_end < value.len()) {
+        return Err(PagesError::ValueParsing(format!(
+            "ambiguous selector `{}` : use key:value filters (`not path:drafts/**`) for an expression or `{{path: '...'}}` for a path",
+            value
+        ))
+        .into());
+    }
+    Ok(false)
+}
+
+impl FromStr for BundleQuery {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_selector_expr(s)
+    }
+}
@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::Value;
+    use crate::maker::SelectorConfig;
+    use crate::pages::{is_selector_expr, parse_selector_expr, BundleQuery, DataQuery, DateFilter};
+
+    fn tag(tag: &str) -> BundleQuery {
+        BundleQuery::Tag { tag: tag.to_string() }
+    }
+
+    #[test]
+    fn parse_selector_expressions() {
+        assert_eq!(
+            parse_selector_expr("ext:.md and (tag:rust or author:\"Jane Doe\") and not path:drafts/**").unwrap(),
+            BundleQuery::And {
+                and: vec![
+                    BundleQuery::Ext { ext: ".md".to_string() },
+                    BundleQuery::Or {
+                        or: vec![tag("rust"), BundleQuery::Author { author: "Jane Doe".to_string() }]
+                    },
+                    BundleQuery::Not {
+                        not: Box::new(BundleQuery::Path { path: "drafts/**".to_string() })
+                    },
+                ]
+            }
+        );
+
+        // `not` binds tighter than `and`, `and` tighter than `or`
+        assert_eq!(
+            parse_selector_expr("tag:a or not tag:b and tag:c").unwrap(),
+            BundleQuery::Or {
+                or: vec![
+                    tag("a"),
+                    BundleQuery::And {
+                        and: vec![BundleQuery::Not { not: Box::new(tag("b")) }, tag("c")]
+                    }
+                ]
+            }
+        );
+
+        assert_eq!(
+            parse_selector_expr("data.weight:2 or data.kind:\"2\"").unwrap(),
+            BundleQuery::Or {
+                or: vec![
+                    BundleQuery::Data {
+                        data: Box::new(DataQuery {
+                            key: "weight".to_string(),
+                            eq: Some(Value::I64(2)),
+                            ne: None,
+                            lt: None,
+                            lte: None,
+                            gt: None,
+                            gte: None,
+                        })
+                    },
+                    BundleQuery::Data {
+                        data: Box::new(DataQuery {
+                            key: "kind".to_string(),
+                            eq: Some(Value::String("2".to_string())),
+                            ne: None,
+                            lt: None,
+                            lte: None,
+                            gt: None,
+                            gte: None,
+                        })
+                    },
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn parse_tags_date_ranges_and_taxonomies() {
+        let date = |yaml: &str| Box::new(serde_yaml::from_str::<DateFilter>(yaml).unwrap());
+        assert_eq!(
+            parse_selector_expr("tags:rust,web and taxonomy.series:\"Getting started\"").unwrap(),
+            BundleQuery::And {
+                and: vec![
+                    BundleQuery::Tags {
+                        tags: vec!["rust".to_string(), "web".to_string()]
+                    },
+                    BundleQuery::Taxonomy {
+                        taxonomy: "series".to_string(),
+                        term: "Getting started".to_string()
+                    },
+                ]
+            }
+        );
+        assert_eq!(
+            parse_selector_expr("publishing:>2021-01-01").unwrap(),
+            BundleQuery::Publishing {
+                publishing: date("{afterDate: '2021-01-01'}")
+            }
+        );
+        assert_eq!(
+            parse_selector_expr("lastEdit:<2021-12-31T12:00:00").unwrap(),
+            BundleQuery::LastEdit {
+                last_edit: date("{beforeTime: '2021-12-31T12:00:00'}")
+            }
+        );
+        assert_eq!(
+            parse_selector_expr("publishing:2021-01-01..2021-06-30").unwrap(),
+            BundleQuery::Publishing {
+                publishing: date("{betweenDates: ['2021-01-01', '2021-06-30']}")
+            }
+        );
+    }
+
+    #[test]
+    fn report_expression_errors_with_column() {
+        let error = |expr: &str| parse_selector_expr(expr).unwrap_err().to_string();
+        assert_eq!(error("tag:a and (tag:b or tag:c"), "unexpected end of expression, expected `)` at column 26");
+        assert_eq!(error("tag:a and and tag:b"), "unexpected `and`, expected key:value at column 11");
+        assert_eq!(error("tag:a tag:b"), "unexpected `tag:b` at column 7");
+        assert_eq!(error("tag:a or color:red"), "unknown selector key `color` at column 10");
+        assert_eq!(error("author:\"Jane"), "unterminated quoted value at column 1");
+        assert_eq!(error("tag: or ext:.md"), "missing value for key `tag` at column 1");
+        assert_eq!(error("tag:a and rust"), "unexpected `rust`, expected key:value at column 11");
+        assert_eq!(error("  "), "empty selector expression at column 1");
+        assert_eq!(error("tag:a or publishing:2021"), "invalid date range `2021`, expected `>date`, `<date` or `from..to` at column 10");
+        assert_eq!(
+            error("publishing:2021-01-01..2021-01-02T10:00:00"),
+            "date range `2021-01-01..2021-01-02T10:00:00` mixes a date and a time at column 1"
+        );
+        assert!(error("lastEdit:>2021-13-01").starts_with("invalid date range `>2021-13-01` ("));
+        assert_eq!(error("taxonomy:rust"), "missing taxonomy name, expected `taxonomy.<name>:<term>` at column 1");
+    }
+
+    #[test]
+    fn parse_expressions_where_queries_are_accepted() {
+        assert!(is_selector_expr("tag:rust").unwrap());
+        assert!(is_selector_expr("not path:a/**").unwrap());
+        assert!(is_selector_expr("(tag:a or tag:b)").unwrap());
+        assert!(is_selector_expr("not (not taxonomy.series:rust)").unwrap());
+        assert!(!is_selector_expr("a/**").unwrap());
+        assert!(!is_selector_expr("notes/*.md").unwrap());
+        assert!(!is_selector_expr("not").unwrap());
+        assert!(!is_selector_expr("{tag: rust}").unwrap());
+        assert!(!is_selector_expr("tag: rust").unwrap());
+        assert!(!is_selector_expr("path: posts/**").unwrap());
+        assert_eq!(
+            is_selector_expr("not published/**").unwrap_err().to_string(),
+            "ambiguous selector `not published/**` : use key:value filters (`not path:drafts/**`) for an expression or `{path: '...'}` for a path"
+        );
+        assert!(is_selector_expr("(drafts)/**").is_err());
+
+        let query: BundleQuery = serde_yaml::from_str("and: ['tag:a or tag:b', {ext: .md}]").unwrap();
+        assert_eq!(
+            query,
+            BundleQuery::And {
+                and: vec![BundleQuery::Or { or: vec![tag("a"), tag("b")] }, BundleQuery::Ext { ext: ".md".to_string() }]
+            }
+        );
+        assert_eq!(serde_yaml::from_str::<SelectorConfig>("query: 'tag:a'").unwrap(), SelectorConfig::Query { query: tag("a") });
+        assert_eq!(
+            serde_yaml::from_str::<BundleQuery>("'tag:a and'").unwrap_err().to_string(),
+            "unexpected end of expression, expected key:value at column 10"
+        );
+    }
+}
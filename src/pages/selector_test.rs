@@ -1,10 +1,10 @@
 #[cfg(test)]
 mod tests {
+    use crate::config::Value;
     use crate::pages::selector::{AuthorSelector, PathSelector, Selector};
     use crate::pages::test_page::TestPage;
-    use crate::pages::{Author, DateQuery, ExtSelector, Logical, Metadata, PageBundle, PublishingDateSelector, TagSelector, VecBundle};
+    use crate::pages::{Author, BundleQuery, DateQuery, ExtSelector, Logical, Metadata, MetadataSelector, Page, PageBundle, PublishingDateSelector, TagSelector, VecBundle};
     use chrono::DateTime;
-    use std::collections::HashMap;
     use std::sync::Arc;
 
     #[macro_export]
@@ -29,15 +29,7 @@ mod tests {
                 p: vec![
                     $(Arc::new(TestPage {
                         path: vec![],
-                        metadata: Some(Metadata{
-                            title: None,
-                            summary: None,
-                            authors: Default::default(),
-                            tags: $result.iter().map(|s| Arc::new(s.to_string())).collect(),
-                            publishing_date: None,
-                            last_edit_date: None,
-                            data: HashMap::default(),
-                        }),
+                        metadata: Some(Metadata { tags: $result.iter().map(|s| Arc::new(s.to_string())).collect(), ..Default::default() }),
                         content: "".to_string()
                 })), +
                 ],
@@ -52,18 +44,11 @@ mod tests {
                 p: vec![
                     $(Arc::new(TestPage {
                         path: vec![],
-                        metadata: Some(Metadata{
-                            title: None,
-                            summary: None,
-                            authors: $result.iter().map(|s| Arc::new(Author {
+                        metadata: Some(Metadata { authors: $result.iter().map(|s| Arc::new(Author {
                                 name: s.to_string(),
                                 contacts: Default::default(),
-                            })).collect(),
-                            tags: Default::default(),
-                            publishing_date: None,
-                            last_edit_date: None,
-                            data: HashMap::default(),
-                        }),
+                                profile: None,
+                            })).collect(), ..Default::default() }),
                         content: "".to_string()
                 })), +
                 ],
@@ -78,15 +63,7 @@ mod tests {
                 p: vec![
                     $(Arc::new(TestPage {
                         path: vec![],
-                        metadata: Some(Metadata{
-                            title: None,
-                            summary: None,
-                            authors: Default::default(),
-                            tags: Default::default(),
-                            publishing_date: $result,
-                            last_edit_date: None,
-                            data: HashMap::default(),
-                        }),
+                        metadata: Some(Metadata { publishing_date: $result, ..Default::default() }),
                         content: "".to_string()
                 })), +
                 ],
@@ -340,6 +317,58 @@ mod tests {
         assert_eq_bundles!(result_bundle_3, tag_bundle!(vec!["e", "f"]));
     }
 
+    fn data_page(path: &str, title: Option<&str>, last_edit_date: Option<i64>, data: Vec<(&str, Value)>) -> Arc<dyn Page> {
+        Arc::new(TestPage {
+            path: vec![path.to_string()],
+            metadata: Some(Metadata {
+                title: title.map(|t| Arc::new(t.to_string())),
+                last_edit_date,
+                data: data.into_iter().map(|(k, v)| (k.to_string(), v)).collect(),
+                ..Default::default()
+            }),
+            content: "".to_string(),
+        })
+    }
+
+    fn selected_paths(selector: &dyn Selector, bundle: &Arc<dyn PageBundle>) -> Vec<String> {
+        select_to_bundle(selector, bundle).pages().iter().map(|p| p.path().join("/")).collect()
+    }
+
+    #[test]
+    fn select_pages_by_data_title_last_edit_date_and_metadata_queries() {
+        let bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                data_page(
+                    "a",
+                    Some("Getting started guide"),
+                    Some(100),
+                    vec![("kind", Value::String("tutorial".to_string())), ("level", Value::I32(1))],
+                ),
+                data_page(
+                    "b",
+                    Some("Reference"),
+                    Some(300),
+                    vec![("kind", Value::Vec(vec![Value::String("tutorial".to_string()), Value::String("api".to_string())]))],
+                ),
+                data_page("c", None, None, vec![("kind", Value::String("news".to_string())), ("level", Value::I64(1))]),
+                Arc::new(TestPage {
+                    path: vec!["d".to_string()],
+                    metadata: None,
+                    content: "".to_string(),
+                }),
+            ],
+        });
+
+        // base selector keys are query filters
+        let query = |yaml: &str| serde_yaml::from_str::<BundleQuery>(yaml).unwrap();
+        assert_eq!(selected_paths(&query("data: {key: kind, eq: tutorial}"), &bundle), vec!["a"]);
+        assert_eq!(selected_paths(&query("data: {key: level, gte: 1}"), &bundle), vec!["a", "c"]);
+        assert_eq!(selected_paths(&query("titleContains: GUIDE"), &bundle), vec!["a"]);
+        assert_eq!(selected_paths(&query("lastEdit: {afterTime: '1970-01-01T00:03:20'}"), &bundle), vec!["b"]);
+        assert_eq!(selected_paths(&MetadataSelector { has_metadata: true }, &bundle), vec!["a", "b", "c"]);
+        assert_eq!(selected_paths(&MetadataSelector { has_metadata: false }, &bundle), vec!["d"]);
+    }
+
     fn select_to_bundle(selector: &dyn Selector, bundle: &Arc<dyn PageBundle>) -> Arc<dyn PageBundle> {
         Arc::new(VecBundle {
             p: bundle.pages().iter().filter_map(|p| if selector.select(p) { Some(Arc::clone(p)) } else { None }).collect(),
@@ -1,4 +1,4 @@
-use crate::pages::{BundleIndex, Env, Metadata, Page, PageIndex};
+use crate::pages::{BundleIndex, Env, Fingerprint, Metadata, Page, PageIndex};
 use std::cmp::Ordering;
 use std::io::{Cursor, Read};
 use std::sync::Arc;
@@ -24,9 +24,17 @@ impl From<(&Env, &Arc<dyn Page>)> for TestPage {
             &BundleIndex {
                 all_authors: Default::default(),
                 all_tags: Default::default(),
+                all_langs: Default::default(),
                 all_pages: vec![],
                 pages_by_author: Default::default(),
                 pages_by_tag: Default::default(),
+                pages_by_lang: Default::default(),
+                all_terms: Default::default(),
+                pages_by_term: Default::default(),
+                digest: Default::default(),
+                page_positions: Default::default(),
+                translation_positions: Default::default(),
+                sections: Default::default(),
             },
             env,
         )
@@ -59,4 +67,8 @@ impl Page for TestPage {
     fn open(&self, _: &PageIndex, _: &BundleIndex, _: &Env) -> anyhow::Result<Box<dyn Read>> {
         Ok(Box::new(Cursor::new(self.content.clone())))
     }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        Some(Fingerprint::new(self.content.as_bytes()))
+    }
 }
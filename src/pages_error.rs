@@ -9,6 +9,7 @@ pub enum PagesError {
     ValueParsing(String),
     Conflict(String),
     Exec(String),
+    Validation(String),
 }
 
 impl Display for PagesError {
@@ -20,6 +21,7 @@ impl Display for PagesError {
             PagesError::ValueParsing(s) => f.write_fmt(format_args!("{}", s)),
             PagesError::Conflict(s) => f.write_fmt(format_args!("{}", s)),
             PagesError::Exec(s) => f.write_fmt(format_args!("{}", s)),
+            PagesError::Validation(s) => f.write_fmt(format_args!("{}", s)),
         }
     }
 }
@@ -14,6 +14,7 @@ pub struct GitRemote {
     pub local_dir: PathBuf,
 }
 
+#[derive(Debug, Clone)]
 pub enum GitReference {
     Commit(String),
     Branch(String),
@@ -30,6 +31,26 @@ impl Display for GitReference {
     }
 }
 
+impl GitReference {
+    // resolves the reference against local repository refs (local branches first then origin ones)
+    pub fn resolve(&self, repo: &Repository) -> anyhow::Result<Oid> {
+        match self {
+            GitReference::Commit(commit) => Ok(repo.revparse_single(commit)?.peel_to_commit()?.id()),
+            GitReference::Branch(branch) => {
+                let branch_ref = match repo.find_reference(&format!("refs/heads/{}", branch)) {
+                    Ok(r) => r,
+                    Err(_) => repo.find_reference(&format!("refs/remotes/origin/{}", branch))?,
+                };
+                Ok(branch_ref.peel_to_commit()?.id())
+            }
+            GitReference::Tag(tag) => {
+                let tag_ref = repo.find_reference(&format!("refs/tags/{}", tag))?;
+                Ok(tag_ref.peel_to_commit()?.id())
+            }
+        }
+    }
+}
+
 impl GitRemote {
     fn find_or_make_local_dir(home_dir: &Path, remote: &str) -> anyhow::Result<(PathBuf, bool)> {
         let mut result_dir = home_dir.join("elepages");
@@ -2,6 +2,7 @@ use crate::pages::{Env, PageBundle, VecBundle};
 use crate::stages::{PageGeneratorBag, ProcessingResult, Stage};
 use chrono::{DateTime, Utc};
 use std::any::Any;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::SystemTime;
 
@@ -36,6 +37,14 @@ impl Stage for AppendStage {
         ))
     }
 
+    fn watched_paths(&self) -> Vec<PathBuf> {
+        self.inner.watched_paths()
+    }
+
+    fn unwatched_paths(&self) -> Vec<PathBuf> {
+        self.inner.unwatched_paths()
+    }
+
     fn as_any(&self) -> Option<&dyn Any> {
         Some(self)
     }
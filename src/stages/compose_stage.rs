@@ -6,6 +6,7 @@ use rayon::prelude::*;
 use std::any::Any;
 use std::borrow::Borrow;
 use std::collections::HashSet;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::SystemTime;
 
@@ -150,6 +151,26 @@ impl Stage for ComposeStage {
         })
     }
 
+    fn watched_paths(&self) -> Vec<PathBuf> {
+        self.units
+            .iter()
+            .flat_map(|u| match u.as_ref() {
+                ComposeUnit::CreateNewSet(s) => s.watched_paths(),
+                ComposeUnit::ReplaceSubSet(_, s) => s.watched_paths(),
+            })
+            .collect()
+    }
+
+    fn unwatched_paths(&self) -> Vec<PathBuf> {
+        self.units
+            .iter()
+            .flat_map(|u| match u.as_ref() {
+                ComposeUnit::CreateNewSet(s) => s.unwatched_paths(),
+                ComposeUnit::ReplaceSubSet(_, s) => s.unwatched_paths(),
+            })
+            .collect()
+    }
+
     fn as_any(&self) -> Option<&dyn Any> {
         Some(self)
     }
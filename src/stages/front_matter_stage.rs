@@ -0,0 +1,134 @@
+use crate::pages::{BundleIndex, Env, Fingerprint, Metadata, Page, PageBundle, PageIndex, VecBundle};
+use crate::stages::stage::Stage;
+use crate::stages::{PageGeneratorBag, ProcessingResult};
+use chrono::{DateTime, Utc};
+use rayon::prelude::*;
+use std::any::Any;
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+pub struct FrontMatterStage {
+    pub name: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum FrontMatterFormat {
+    Yaml,
+    Toml,
+}
+
+impl Stage for FrontMatterStage {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn process(&self, bundle: &Arc<dyn PageBundle>, env: &Env, _: &Arc<dyn PageGeneratorBag>) -> anyhow::Result<(Arc<dyn PageBundle>, ProcessingResult)> {
+        let start = DateTime::<Utc>::from(SystemTime::now());
+        env.print_vv(&format!("stage {}", self.name()), "front matter processing started");
+        let output_index = BundleIndex::new(bundle, &env.taxonomies());
+        let vec_bundle = VecBundle {
+            p: bundle
+                .pages()
+                .par_iter()
+                .map(|p| self.process_page(p, &output_index, env))
+                .collect::<anyhow::Result<Vec<Arc<dyn Page>>>>()?,
+        };
+        env.print_vv(&format!("stage {}", self.name()), "front matter processing ended");
+        let end = DateTime::<Utc>::from(SystemTime::now());
+        Ok((
+            Arc::new(vec_bundle),
+            ProcessingResult {
+                stage_name: self.name.clone(),
+                start,
+                end,
+                sub_results: vec![],
+            },
+        ))
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+}
+
+impl FrontMatterStage {
+    fn process_page(&self, page: &Arc<dyn Page>, output_index: &BundleIndex, env: &Env) -> anyhow::Result<Arc<dyn Page>> {
+        let mut content = vec![];
+        page.open(&PageIndex::from(page), output_index, env)?.read_to_end(&mut content)?;
+        let (format, front_matter, body_offset) = match std::str::from_utf8(&content).ok().and_then(split_front_matter) {
+            None => return Ok(Arc::clone(page)),
+            Some(f) => f,
+        };
+        env.print_vvv(&format!("stage {}", self.name()), &format!("front matter found in page {}", page.path().join("/")));
+        let metadata = if front_matter.trim().is_empty() {
+            page.metadata().cloned()
+        } else {
+            let front_matter_metadata: Metadata = match format {
+                FrontMatterFormat::Yaml => serde_yaml::from_str(front_matter)?,
+                FrontMatterFormat::Toml => toml::from_str(front_matter)?,
+            };
+            if let Some(strategies) = &front_matter_metadata.merge {
+                strategies.validate()?;
+            }
+            // front matter takes precedence over metadata already set on the page
+            Some(match page.metadata() {
+                None => front_matter_metadata,
+                Some(page_metadata) => front_matter_metadata.merge(page_metadata)?,
+            })
+        };
+        Ok(Arc::new(FrontMatterPage {
+            source: Arc::clone(page),
+            metadata,
+            body_offset,
+        }))
+    }
+}
+
+// returns front matter format, front matter block and body start offset when content starts with a front matter block
+pub(crate) fn split_front_matter(content: &str) -> Option<(FrontMatterFormat, &str, usize)> {
+    let first_line_end = content.find('\n')?;
+    let (format, delimiter) = match content[..first_line_end].trim_end() {
+        "---" => (FrontMatterFormat::Yaml, "---"),
+        "+++" => (FrontMatterFormat::Toml, "+++"),
+        _ => return None,
+    };
+    let block_start = first_line_end + 1;
+    let mut line_start = block_start;
+    while line_start <= content.len() {
+        let line_end = content[line_start..].find('\n').map(|i| line_start + i).unwrap_or_else(|| content.len());
+        if content[line_start..line_end].trim_end() == delimiter {
+            let body_offset = if line_end < content.len() { line_end + 1 } else { line_end };
+            return Some((format, &content[block_start..line_start], body_offset));
+        }
+        line_start = line_end + 1;
+    }
+    None
+}
+
+#[derive(Debug)]
+struct FrontMatterPage {
+    source: Arc<dyn Page>,
+    metadata: Option<Metadata>,
+    body_offset: usize,
+}
+
+impl Page for FrontMatterPage {
+    fn path(&self) -> &[String] {
+        self.source.path()
+    }
+
+    fn metadata(&self) -> Option<&Metadata> {
+        self.metadata.as_ref()
+    }
+
+    fn open(&self, output_page: &PageIndex, output_index: &BundleIndex, env: &Env) -> anyhow::Result<Box<dyn Read>> {
+        let mut content = vec![];
+        self.source.open(output_page, output_index, env)?.read_to_end(&mut content)?;
+        Ok(Box::new(Cursor::new(content.split_off(self.body_offset.min(content.len())))))
+    }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        self.source.fingerprint()
+    }
+}
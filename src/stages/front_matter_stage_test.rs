@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::Value;
+    use crate::pages::test_page::TestPage;
+    use crate::pages::{Author, Env, Metadata, PageBundle, VecBundle};
+    use crate::stages::front_matter_stage::FrontMatterStage;
+    use crate::stages::stage::Stage;
+    use crate::stages::test_stage::TestProcessingResult;
+    use crate::stages::{PageGeneratorBagImpl, SequenceStage, ShadowPages};
+    use indoc::indoc;
+    use std::array::IntoIter;
+    use std::collections::{HashMap, HashSet};
+    use std::iter::FromIterator;
+    use std::sync::Arc;
+
+    #[test]
+    fn parse_yaml_and_toml_front_matter_and_strip_it_from_content() {
+        let bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                Arc::new(TestPage {
+                    path: vec!["f1.md".to_string()],
+                    metadata: None,
+                    content: indoc! {"
+                        ---
+                        title: f1 title
+                        tags: [t1, t2]
+                        ---
+                        # f1 content
+                    "}
+                    .to_string(),
+                }),
+                Arc::new(TestPage {
+                    path: vec!["f2.md".to_string()],
+                    metadata: None,
+                    content: indoc! {r#"
+                        +++
+                        title = "f2 title"
+                        publishing_date = "2021-10-20"
+                        [data]
+                        key = "value"
+                        +++
+                        # f2 content
+                    "#}
+                    .to_string(),
+                }),
+                Arc::new(TestPage {
+                    path: vec!["f3.md".to_string()],
+                    metadata: None,
+                    content: indoc! {"
+                        # f3 content
+                        ---
+                        not a front matter
+                        ---
+                    "}
+                    .to_string(),
+                }),
+            ],
+        });
+
+        let stage = FrontMatterStage {
+            name: "front matter stage".to_string(),
+        };
+        let result_bundle = stage.process(&bundle, &Env::test(), &PageGeneratorBagImpl::new()).unwrap();
+        assert_eq!(
+            TestProcessingResult::from(&result_bundle.1),
+            TestProcessingResult {
+                stage_name: "front matter stage".to_string(),
+                sub_results: vec![]
+            }
+        );
+        let mut actual = result_bundle.0.pages().iter().map(|p| TestPage::from(p)).collect::<Vec<_>>();
+        actual.sort_by_key(|f| f.path.join("/"));
+        assert_eq!(
+            actual,
+            &[
+                TestPage {
+                    path: vec!["f1.md".to_string()],
+                    metadata: Some(Metadata {
+                        title: Some(Arc::new("f1 title".to_string())),
+                        tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
+                        ..Default::default()
+                    }),
+                    content: "# f1 content\n".to_string()
+                },
+                TestPage {
+                    path: vec!["f2.md".to_string()],
+                    metadata: Some(Metadata {
+                        title: Some(Arc::new("f2 title".to_string())),
+                        publishing_date: Some(1634688000),
+                        data: HashMap::from_iter(IntoIter::new([("key".to_string(), Value::String("value".to_string()))])),
+                        ..Default::default()
+                    }),
+                    content: "# f2 content\n".to_string()
+                },
+                TestPage {
+                    path: vec!["f3.md".to_string()],
+                    metadata: None,
+                    content: "# f3 content\n---\nnot a front matter\n---\n".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_front_matter_with_shadow_and_directory_metadata() {
+        let bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                Arc::new(TestPage {
+                    path: vec!["dir".to_string(), "f1.md".to_string()],
+                    metadata: None,
+                    content: indoc! {"
+                        ---
+                        title: front matter title
+                        authors:
+                          - name: a1
+                        tags: [t1]
+                        ---
+                        f1 content
+                    "}
+                    .to_string(),
+                }),
+                Arc::new(TestPage {
+                    path: vec!["dir".to_string(), "f1.md.yaml".to_string()],
+                    metadata: None,
+                    content: indoc! {"
+                        title: shadow title
+                        summary: shadow summary
+                        authors:
+                          - name: a1
+                            contacts: [c1]
+                        tags: [t2]
+                    "}
+                    .to_string(),
+                }),
+                Arc::new(TestPage {
+                    path: vec!["dir.yaml".to_string()],
+                    metadata: None,
+                    content: indoc! {"
+                        tags: [t3]
+                    "}
+                    .to_string(),
+                }),
+            ],
+        });
+
+        let stage = SequenceStage {
+            name: "sequence".to_string(),
+            stages: vec![
+                Arc::new(FrontMatterStage {
+                    name: "front matter stage".to_string(),
+                }),
+                Arc::new(ShadowPages::default("shadow stage".to_string())),
+            ],
+        };
+        let result_bundle = stage.process(&bundle, &Env::test(), &PageGeneratorBagImpl::new()).unwrap();
+        let actual = result_bundle.0.pages().iter().map(|p| TestPage::from(p)).collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            &[TestPage {
+                path: vec!["dir".to_string(), "f1.md".to_string()],
+                metadata: Some(Metadata {
+                    title: Some(Arc::new("front matter title".to_string())),
+                    summary: Some(Arc::new("shadow summary".to_string())),
+                    authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
+                        name: "a1".to_string(),
+                        contacts: HashSet::from_iter(IntoIter::new(["c1".to_string()])),
+                        profile: None,
+                    })])),
+                    tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
+                    ..Default::default()
+                }),
+                content: "f1 content\n".to_string()
+            }]
+        );
+    }
+    #[test]
+    fn reject_unsupported_front_matter_merge_strategies() {
+        let bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![Arc::new(TestPage {
+                path: vec!["f1.md".to_string()],
+                metadata: None,
+                content: indoc! {"
+                    ---
+                    merge: {series: deep-merge}
+                    ---
+                    f1 content
+                "}
+                .to_string(),
+            })],
+        });
+
+        let stage = FrontMatterStage {
+            name: "front matter stage".to_string(),
+        };
+        let err = stage.process(&bundle, &Env::test(), &PageGeneratorBagImpl::new()).err().unwrap();
+        assert_eq!(err.to_string(), "merge strategy deep-merge is not supported for field series");
+    }
+}
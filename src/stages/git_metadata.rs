@@ -1,4 +1,4 @@
-use crate::pages::{ArcPage, Author, Env, Metadata, Page, PageBundle, VecBundle};
+use crate::pages::{ArcPage, Author, AuthorRegistry, Env, Metadata, Page, PageBundle, VecBundle};
 use crate::pages_error::PagesError;
 use crate::stages::stage::Stage;
 use crate::stages::{PageGeneratorBag, ProcessingResult};
@@ -15,10 +15,12 @@ pub struct GitMetadata {
     pub name: String,
     pub repo_path: PathBuf,
     pub pages_rel_path: Option<PathBuf>,
+    // path prefix of the pages loaded from the repository, other pages are left unchanged
+    pub page_prefix: Vec<String>,
 }
 
 impl GitMetadata {
-    fn process_repository(&self, repo: Repository, mut blame_pages: HashMap<String, &Arc<dyn Page>>) -> anyhow::Result<Vec<Arc<dyn Page>>> {
+    fn process_repository(&self, repo: Repository, mut blame_pages: HashMap<String, &Arc<dyn Page>>, author_registry: Option<&AuthorRegistry>) -> anyhow::Result<Vec<Arc<dyn Page>>> {
         let mut rev_walk = repo.revwalk()?;
         rev_walk.set_sorting(git2::Sort::TIME)?;
         rev_walk.push_head()?;
@@ -57,6 +59,7 @@ impl GitMetadata {
                         _ => IntoIter::new([Arc::new(Author {
                             name: commit.author().name().map(|n| n.to_string()).unwrap_or_else(|| "".to_string()),
                             contacts: commit.author().email().map(|e| IntoIter::new([e.to_string()]).collect()).unwrap_or_else(HashSet::default),
+                            profile: None,
                         })])
                         .collect(),
                     };
@@ -67,26 +70,15 @@ impl GitMetadata {
                         },
                         None => Some(commit.time().seconds()),
                     };
-                    result.push(origin_page.change_meta(if let Some(m) = origin_page.metadata() {
-                        Metadata {
-                            title: m.title.clone(),
-                            summary: m.summary.clone(),
-                            authors,
-                            tags: m.tags.clone(),
-                            publishing_date: m.publishing_date,
-                            last_edit_date,
-                            data: m.data.clone(),
-                        }
-                    } else {
-                        Metadata {
-                            title: None,
-                            summary: None,
-                            authors,
-                            tags: HashSet::default(),
-                            publishing_date: None,
-                            last_edit_date,
-                            data: HashMap::default(),
-                        }
+                    let metadata = Metadata {
+                        authors,
+                        last_edit_date,
+                        ..origin_page.metadata().cloned().unwrap_or_default()
+                    };
+                    // commit authors are resolved to registry entries, e.g. from their git name or email
+                    result.push(origin_page.change_meta(match author_registry {
+                        None => metadata,
+                        Some(registry) => registry.resolve_metadata(&metadata)?,
                     }))
                 }
             }
@@ -139,17 +131,25 @@ impl Stage for GitMetadata {
             if page.path().is_empty() {
                 continue;
             }
+            let repo_page_path = match page.path().strip_prefix(self.page_prefix.as_slice()) {
+                Some(p) if !p.is_empty() => p,
+                _ => {
+                    vec_bundle.p.push(Arc::clone(page));
+                    continue;
+                }
+            };
             if let Some(m) = page.metadata() {
                 if !m.authors.is_empty() && m.last_edit_date.is_some() {
                     vec_bundle.p.push(Arc::clone(page));
                     continue;
                 }
             }
-            blame_pages.insert(page.path().join("/"), page);
+            blame_pages.insert(repo_page_path.join("/"), page);
         }
 
         if !blame_pages.is_empty() {
-            let mut processed_pages = self.process_repository(repo, blame_pages)?;
+            let author_registry = AuthorRegistry::from_env(env)?;
+            let mut processed_pages = self.process_repository(repo, blame_pages, author_registry.as_ref())?;
             vec_bundle.p.append(&mut processed_pages);
         }
         env.print_vv(&format!("stage {}", self.name()), "git metadata extraction ended");
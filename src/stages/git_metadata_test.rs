@@ -2,7 +2,7 @@
 mod tests {
     use crate::config::Value;
     use crate::pages::test_page::TestPage;
-    use crate::pages::{Author, Env, FsLoader, Loader, Metadata};
+    use crate::pages::{Author, Env, FsLoader, Loader, Metadata, AUTHORS_KEY};
     use crate::stages::git_metadata::GitMetadata;
     use crate::stages::sequence_stage::SequenceStage;
     use crate::stages::shadow_pages::ShadowPages;
@@ -34,6 +34,7 @@ mod tests {
             name: "git meta stage".to_string(),
             repo_path: test_folder.get_path().to_path_buf(),
             pages_rel_path: None,
+            page_prefix: vec![],
         };
 
         let loader = FsLoader::new(test_folder.get_path().to_path_buf());
@@ -82,6 +83,7 @@ mod tests {
             name: "git meta stage".to_string(),
             repo_path: test_folder.get_path().to_path_buf(),
             pages_rel_path: None,
+            page_prefix: vec![],
         };
 
         let loader = FsLoader::new(test_folder.get_path().to_path_buf());
@@ -104,22 +106,54 @@ mod tests {
             &[TestPage {
                 path: vec!["file_1".to_string()],
                 metadata: Some(Metadata {
-                    title: None,
-                    summary: None,
                     authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                         name: "user_1".to_string(),
                         contacts: HashSet::from_iter(IntoIter::new(["user_1@pages.io".to_string()])),
+                        profile: None,
                     })])),
-                    tags: Default::default(),
-                    publishing_date: None,
                     last_edit_date: commit_time,
-                    data: HashMap::default(),
+                    ..Default::default()
                 }),
                 content: "file content 1".to_string()
             }]
         );
     }
 
+    #[test]
+    fn resolve_git_authors_from_registry() {
+        let test_folder = TmpTestFolder::new().unwrap();
+        let repo = Repository::init(test_folder.get_path()).unwrap();
+        repo.config().unwrap().set_str("user.name", "John D.").unwrap();
+        repo.config().unwrap().set_str("user.email", "jdoe@corp").unwrap();
+
+        test_folder
+            .write(&FileNode::File {
+                name: "file_1".to_string(),
+                content: "file content 1".as_bytes().to_vec(),
+                open_options: None,
+            })
+            .unwrap();
+        commit(&repo, "Initial commit");
+
+        let git_metadata_stage = GitMetadata {
+            name: "git meta stage".to_string(),
+            repo_path: test_folder.get_path().to_path_buf(),
+            pages_rel_path: None,
+            page_prefix: vec![],
+        };
+        let env = Env::test();
+        env.insert(AUTHORS_KEY.to_string(), serde_yaml::from_str("{jdoe: {display_name: John Doe, aliases: [jdoe@corp]}}").unwrap());
+
+        let bundle = FsLoader::new(test_folder.get_path().to_path_buf()).load(&env).unwrap();
+        let result_bundle = git_metadata_stage.process(&Arc::new(bundle), &env, &PageGeneratorBagImpl::new()).unwrap();
+
+        let authors = result_bundle.0.pages()[0].metadata().unwrap().authors.iter().cloned().collect::<Vec<_>>();
+        assert_eq!(authors.len(), 1);
+        assert_eq!(authors[0].name, "jdoe");
+        assert_eq!(authors[0].contacts, HashSet::from_iter(IntoIter::new(["jdoe@corp".to_string()])));
+        assert_eq!(authors[0].profile.as_ref().unwrap().display_name, Some("John Doe".to_string()));
+    }
+
     #[test]
     fn load_multiple_authors_from_git_metadata() {
         let mut test_folder = TmpTestFolder::new().unwrap();
@@ -195,6 +229,7 @@ mod tests {
             name: "git meta stage".to_string(),
             repo_path: test_folder.get_path().to_path_buf(),
             pages_rel_path: None,
+            page_prefix: vec![],
         };
 
         let loader = FsLoader::new(test_folder.get_path().to_path_buf());
@@ -218,80 +253,65 @@ mod tests {
                 TestPage {
                     path: vec!["d1".to_string(), "d11".to_string(), "f11".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_2".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_2@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_2,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 11".to_string(),
                 },
                 TestPage {
                     path: vec!["d1".to_string(), "f1".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_2".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_2@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_2,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 1".to_string(),
                 },
                 TestPage {
                     path: vec!["d1".to_string(), "f2".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_2".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_2@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_2,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 2".to_string(),
                 },
                 TestPage {
                     path: vec!["d1".to_string(), "f3".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_2".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_2@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_2,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 3".to_string(),
                 },
                 TestPage {
                     path: vec!["file_1".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_3".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_3@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_3,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                         file content 1
@@ -379,6 +399,7 @@ mod tests {
             name: "git meta stage".to_string(),
             repo_path: test_folder.get_path().to_path_buf(),
             pages_rel_path: Some(PathBuf::from_str("d1").unwrap()),
+            page_prefix: vec![],
         };
 
         let loader = FsLoader::new(test_folder.get_path().join(PathBuf::from_str("d1").unwrap()).to_path_buf());
@@ -402,64 +423,52 @@ mod tests {
                 TestPage {
                     path: vec!["d11".to_string(), "f11".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_2".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_2@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_2,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 11".to_string(),
                 },
                 TestPage {
                     path: vec!["f1".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_2".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_2@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_2,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 1".to_string(),
                 },
                 TestPage {
                     path: vec!["f2".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_2".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_2@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_2,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 2".to_string(),
                 },
                 TestPage {
                     path: vec!["f3".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_2".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_2@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_2,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 3".to_string(),
                 },
@@ -546,6 +555,7 @@ mod tests {
             name: "git meta stage".to_string(),
             repo_path: test_folder.get_path().to_path_buf(),
             pages_rel_path: None,
+            page_prefix: vec![],
         };
 
         let loader = FsLoader::new(test_folder.get_path().to_path_buf());
@@ -568,80 +578,65 @@ mod tests {
                 TestPage {
                     path: vec!["d1".to_string(), "d11".to_string(), "f11".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_2".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_2@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_2,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 11".to_string(),
                 },
                 TestPage {
                     path: vec!["d1".to_string(), "f1".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_2".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_2@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_2,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 1".to_string(),
                 },
                 TestPage {
                     path: vec!["d1".to_string(), "f2".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_2".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_2@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_2,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 2".to_string(),
                 },
                 TestPage {
                     path: vec!["d1".to_string(), "f3".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_2".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_2@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_2,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 3".to_string(),
                 },
                 TestPage {
                     path: vec!["file_1".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_1".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_1@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time_1,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                         file content 1
@@ -721,6 +716,7 @@ mod tests {
                     name: "git meta stage".to_string(),
                     repo_path: test_folder.get_path().to_path_buf(),
                     pages_rel_path: None,
+                    page_prefix: vec![],
                 }),
             ],
         };
@@ -755,16 +751,14 @@ mod tests {
                 TestPage {
                     path: vec!["d1".to_string(), "f1".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "a1".to_string(),
-                            contacts: HashSet::default()
+                            contacts: HashSet::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
-                        publishing_date: None,
                         last_edit_date: commit_time,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 1".to_string(),
                 },
@@ -776,27 +770,24 @@ mod tests {
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_1".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_1@pages.io".to_string()])),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
-                        publishing_date: None,
                         last_edit_date: commit_time,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 2".to_string(),
                 },
                 TestPage {
                     path: vec!["d1".to_string(), "f3".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_1".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_1@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 3".to_string(),
                 }
@@ -867,6 +858,7 @@ mod tests {
                     name: "git meta stage".to_string(),
                     repo_path: test_folder.get_path().to_path_buf(),
                     pages_rel_path: None,
+                    page_prefix: vec![],
                 }),
             ],
         };
@@ -899,16 +891,15 @@ mod tests {
                 TestPage {
                     path: vec!["d1".to_string(), "f1".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_1".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_1@pages.io".to_string()])),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
-                        publishing_date: None,
                         last_edit_date: Some(1634778000),
                         data: HashMap::from_iter(IntoIter::new([("some_key".to_string(), Value::String("some_value".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "file content 1".to_string(),
                 },
@@ -920,27 +911,24 @@ mod tests {
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_1".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_1@pages.io".to_string()])),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
-                        publishing_date: None,
                         last_edit_date: commit_time,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 2".to_string(),
                 },
                 TestPage {
                     path: vec!["d1".to_string(), "f3".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "user_1".to_string(),
                             contacts: HashSet::from_iter(IntoIter::new(["user_1@pages.io".to_string()])),
+                            profile: None,
                         })])),
-                        tags: Default::default(),
-                        publishing_date: None,
                         last_edit_date: commit_time,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "file content 3".to_string(),
                 }
@@ -1,5 +1,5 @@
-use crate::pages::{BundleIndex, Env, Metadata, Page, PageIndex};
-use crate::stages::{BundleArchiveHelper, BundleQueryHelper, DateFormatHelper, EnvHelper, ForUriHelper};
+use crate::pages::{digest_serializable, BundleIndex, Env, Fingerprint, Metadata, Page, PageIndex};
+use crate::stages::{BundleArchiveHelper, BundleQueryHelper, DateFormatHelper, EnvHelper, ForUriHelper, SectionQueryHelper};
 use serde::Serialize;
 use std::io::{Cursor, Read};
 
@@ -19,12 +19,17 @@ pub(crate) struct HbsAssetSelection {
     pub(crate) original_tag: Option<String>,
     #[serde(rename = "originalAuthor")]
     pub(crate) original_author: Option<String>,
+    pub(crate) taxonomy: Option<String>,
+    pub(crate) term: Option<String>,
+    #[serde(rename = "originalTerm")]
+    pub(crate) original_term: Option<String>,
 }
 
 #[derive(Debug)]
 pub(crate) struct HbsAsset {
     pub(crate) registry: handlebars::Handlebars<'static>,
     pub(crate) tpl_name: String,
+    pub(crate) tpl_digest: String,
     pub(crate) path: Vec<String>,
     pub(crate) metadata: Option<Metadata>,
     pub(crate) selection: Option<HbsAssetSelection>,
@@ -42,6 +47,7 @@ impl Page for HbsAsset {
     fn open(&self, output_page: &PageIndex, output_index: &BundleIndex, env: &Env) -> anyhow::Result<Box<dyn Read>> {
         let mut local_registry = self.registry.clone();
         local_registry.register_helper("bundle_query", Box::new(BundleQueryHelper { output_index }));
+        local_registry.register_helper("section_query", Box::new(SectionQueryHelper { output_index }));
         local_registry.register_helper("env", Box::new(EnvHelper { env }));
         local_registry.register_helper("bundle_archive_query", Box::new(BundleArchiveHelper { output_index }));
         local_registry.register_helper("date_format", Box::new(DateFormatHelper));
@@ -57,6 +63,16 @@ impl Page for HbsAsset {
         )?;
         Ok(Box::new(Cursor::new(result)))
     }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        let selection_digest = digest_serializable(&self.selection).ok()?;
+        Some(
+            Fingerprint::new(self.tpl_digest.as_bytes())
+                .with(self.tpl_name.as_bytes())
+                .with(selection_digest.as_bytes())
+                .using_index(),
+        )
+    }
 }
 
 #[derive(Serialize)]
@@ -1,5 +1,5 @@
-use crate::pages::{BundleIndex, Env, Metadata, Page, PageIndex};
-use crate::stages::{BundleArchiveHelper, BundleQueryHelper, DateFormatHelper, EnvHelper, ForUriHelper, PageContentHelper};
+use crate::pages::{BundleIndex, Env, Fingerprint, Metadata, Page, PageIndex, PageSections};
+use crate::stages::{BundleArchiveHelper, BundleQueryHelper, DateFormatHelper, EnvHelper, ForUriHelper, PageContentHelper, SectionQueryHelper};
 use serde::Serialize;
 use std::io::{Cursor, Read};
 use std::sync::Arc;
@@ -9,6 +9,7 @@ pub(crate) struct HbsPage {
     pub(crate) registry: handlebars::Handlebars<'static>,
     pub(crate) source: Arc<dyn Page>,
     pub(crate) tpl_name: String,
+    pub(crate) tpl_digest: String,
 }
 
 impl Page for HbsPage {
@@ -32,6 +33,7 @@ impl Page for HbsPage {
             }),
         );
         local_registry.register_helper("bundle_query", Box::new(BundleQueryHelper { output_index }));
+        local_registry.register_helper("section_query", Box::new(SectionQueryHelper { output_index }));
         local_registry.register_helper("env", Box::new(EnvHelper { env }));
         local_registry.register_helper("bundle_archive_query", Box::new(BundleArchiveHelper { output_index }));
         local_registry.register_helper("date_format", Box::new(DateFormatHelper));
@@ -42,10 +44,16 @@ impl Page for HbsPage {
                 current_metadata: self.metadata(),
                 page: output_page,
                 index: output_index,
+                translations: output_index.translations(output_page),
+                sections: output_index.section_tree().page_sections(output_page),
             },
         )?;
         Ok(Box::new(Cursor::new(result)))
     }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        self.source.fingerprint().map(|f| f.with(self.tpl_digest.as_bytes()).with(self.tpl_name.as_bytes()).using_index())
+    }
 }
 
 #[derive(Serialize)]
@@ -53,4 +61,8 @@ pub struct PageData<'a> {
     pub current_metadata: Option<&'a Metadata>,
     pub page: &'a PageIndex,
     pub index: &'a BundleIndex,
+    pub translations: Vec<&'a PageIndex>,
+    // parent, children, siblings and breadcrumbs of the page
+    #[serde(flatten)]
+    pub sections: PageSections<'a>,
 }
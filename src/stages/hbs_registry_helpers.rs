@@ -1,10 +1,11 @@
-use crate::pages::{BundleIndex, BundlePagination, BundleQuery, Env, Page, PageIndex};
+use crate::pages::{is_selector_expr, BundleIndex, BundlePagination, BundleQuery, Env, Page, PageIndex, SortBy, SortField, SortKey};
 use crate::utilities::uri_friendly_string;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use handlebars::{Context, Handlebars, Helper, HelperDef, HelperResult, Output, RenderContext, RenderError, ScopedJson};
 use serde::Serialize;
 use std::cmp::Reverse;
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 
 pub struct PageContentHelper<'a> {
@@ -26,6 +27,14 @@ impl HelperDef for PageContentHelper<'_> {
     }
 }
 
+// yaml query (`{tag: t1}`) or selector expression (`tag:t1 and not path:drafts/**`)
+fn parse_query_param(param: &str) -> Result<BundleQuery, RenderError> {
+    if is_selector_expr(param).map_err(|err| RenderError::new(err.to_string()))? {
+        return BundleQuery::from_str(param).map_err(|err| RenderError::new(err.to_string()));
+    }
+    serde_yaml::from_str(param).map_err(|err| RenderError::new(err.to_string()))
+}
+
 pub struct BundleQueryHelper<'a> {
     pub output_index: &'a BundleIndex,
 }
@@ -41,12 +50,16 @@ impl HelperDef for BundleQueryHelper<'_> {
                 if param.is_empty() {
                     None
                 } else {
-                    Some(serde_yaml::from_str(param).map_err(|err| RenderError::new(err.to_string()))?)
+                    Some(parse_query_param(param)?)
                 }
             }
         };
         let pagination: BundlePagination = match param2 {
-            None => BundlePagination { skip: None, limit: None },
+            None => BundlePagination {
+                skip: None,
+                limit: None,
+                sort_by: None,
+            },
             Some(param) => serde_yaml::from_str(param).map_err(|err| RenderError::new(err.to_string()))?,
         };
 
@@ -60,6 +73,19 @@ impl HelperDef for BundleQueryHelper<'_> {
     }
 }
 
+pub struct SectionQueryHelper<'a> {
+    pub output_index: &'a BundleIndex,
+}
+
+impl HelperDef for SectionQueryHelper<'_> {
+    fn call_inner<'reg: 'rc, 'rc>(&self, h: &Helper<'reg, 'rc>, _: &'reg Handlebars<'reg>, _: &'rc Context, _: &mut RenderContext<'reg, 'rc>) -> Result<ScopedJson<'reg, 'rc>, RenderError> {
+        let param1 = h.param(0).and_then(|v| v.value().as_str()).unwrap_or("");
+        let section_path = param1.split('/').filter(|s| !s.is_empty()).map(|s| s.to_string()).collect::<Vec<String>>();
+        let section = self.output_index.section_tree().get(&section_path);
+        Ok(ScopedJson::Derived(serde_json::to_value(section)?))
+    }
+}
+
 pub struct EnvHelper<'a> {
     pub env: &'a Env,
 }
@@ -117,7 +143,7 @@ impl HelperDef for BundleArchiveHelper<'_> {
                 if param.is_empty() {
                     None
                 } else {
-                    Some(serde_yaml::from_str(param).map_err(|err| RenderError::new(err.to_string()))?)
+                    Some(parse_query_param(param)?)
                 }
             }
         };
@@ -127,7 +153,15 @@ impl HelperDef for BundleArchiveHelper<'_> {
             Some(value) => value,
         };
 
-        let pages = self.output_index.query(&bundle_query, &BundlePagination { skip: None, limit: None });
+        // optional sort keys (`title`, `[-data.weight, title]`) order pages of each month,
+        // years and months are listed oldest first when the first key is an ascending publishing date
+        let sort_by: Option<SortBy> = match h.param(1).and_then(|v| v.value().as_str()) {
+            None | Some("") => None,
+            Some(param) => Some(serde_yaml::from_str(param).map_err(|err| RenderError::new(err.to_string()))?),
+        };
+        let oldest_first = matches!(&sort_by, Some(SortBy(keys)) if matches!(keys.first(), Some(SortKey { field: SortField::PublishingDate, descending: false })));
+
+        let pages = self.output_index.query(&bundle_query, &BundlePagination { skip: None, limit: None, sort_by });
 
         let mut archive_map: HashMap<String, HashMap<String, Vec<&PageIndex>>> = HashMap::default();
 
@@ -151,10 +185,18 @@ impl HelperDef for BundleArchiveHelper<'_> {
                     pages: pages.to_vec(),
                 })
             }
-            current.months.sort_by_key(|k| Reverse(k.month.clone()));
+            if oldest_first {
+                current.months.sort_by_key(|k| k.month.clone());
+            } else {
+                current.months.sort_by_key(|k| Reverse(k.month.clone()));
+            }
             archive_pages.push(current);
         }
-        archive_pages.sort_by_key(|k| Reverse(k.year.clone()));
+        if oldest_first {
+            archive_pages.sort_by_key(|k| k.year.clone());
+        } else {
+            archive_pages.sort_by_key(|k| Reverse(k.year.clone()));
+        }
 
         Ok(ScopedJson::Derived(serde_json::to_value(archive_pages)?))
     }
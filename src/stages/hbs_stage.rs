@@ -1,6 +1,6 @@
 use crate::commands::{DefaultNpmRunner, NpmRunner};
 use crate::config::Value;
-use crate::pages::{Env, Page, PageBundle, VecBundle};
+use crate::pages::{digest_bytes, Env, Page, PageBundle, VecBundle};
 use crate::pages_error::PagesError;
 use crate::stages::hbs_tpl_asset::{TplAsset, TplAssetMetadata};
 use crate::stages::hbs_tpl_model::TplModel;
@@ -48,7 +48,7 @@ impl Stage for HbsStage {
                         }
                     }
                 }
-                tpl_model.fetch(p)
+                tpl_model.fetch(p).map(|hbs_page| env.cache_content(hbs_page))
             })
             .collect::<Vec<Arc<dyn Page>>>();
 
@@ -65,6 +65,24 @@ impl Stage for HbsStage {
         ))
     }
 
+    fn watched_paths(&self) -> Vec<PathBuf> {
+        vec![self.tpl_path.clone()]
+    }
+
+    // npm install and build outputs would otherwise trigger endless rebuilds
+    fn unwatched_paths(&self) -> Vec<PathBuf> {
+        let node_js_path = self.tpl_path.join("package.json");
+        if !node_js_path.exists() {
+            return vec![];
+        }
+        let mut result = vec![self.tpl_path.join("node_modules"), self.tpl_path.join("package-lock.json")];
+        let package_json: Option<NodePackageJson> = fs::File::open(node_js_path).ok().and_then(|f| serde_json::from_reader(f).ok());
+        if let Some(build_output_dir) = package_json.and_then(|p| p.build_output_dir) {
+            result.push(self.tpl_path.join(build_output_dir));
+        }
+        result
+    }
+
     fn as_any(&self) -> Option<&dyn Any> {
         Some(self)
     }
@@ -114,7 +132,9 @@ impl HbsStage {
             registry: Handlebars::new(),
             pages_tpl_names: Default::default(),
             assets: Default::default(),
+            digest: Default::default(),
         };
+        let mut file_digests: Vec<(String, String)> = vec![];
         let mut assets_map: HashMap<String, TplAssetMetadata> = HashMap::new();
         let base_path = if let Some(npm_build_output) = self.try_npm_build(env)? {
             npm_build_output
@@ -123,11 +143,11 @@ impl HbsStage {
         };
 
         env.print_vv(&format!("stage {}", self.name()), &format!("handlebars lookup from dir {}", base_path.to_string_lossy()));
-        visit_dirs(&base_path, &mut |entry| {
-            let entry_path = entry.path();
+        visit_dirs(&base_path, env.use_gitignore(), &mut |entry_path| {
             let rel_path = entry_path.strip_prefix(&base_path)?;
             let name = entry_path.file_name().map(|e| e.to_string_lossy()).unwrap_or_else(|| "".into());
             let ext = entry_path.extension().map(|e| e.to_string_lossy()).unwrap_or_else(|| "".into());
+            file_digests.push((rel_path.to_string_lossy().to_string(), digest_bytes(&[&fs::read(&entry_path)?])));
 
             if name.starts_with("page.") && ext == "hbs" {
                 let template_name = rel_path.to_string_lossy().replace(MAIN_SEPARATOR, "/");
@@ -173,6 +193,10 @@ impl HbsStage {
             Ok(())
         })?;
 
+        // templates digest used to detect template changes between builds
+        file_digests.sort();
+        result.digest = digest_bytes(&file_digests.iter().flat_map(|(p, d)| [p.as_bytes(), d.as_bytes()]).collect::<Vec<&[u8]>>());
+
         for asset in &mut result.assets {
             if let TplAsset::Tpl { tpl_name, metadata, .. } = asset {
                 if let Some(m) = assets_map.remove(tpl_name) {
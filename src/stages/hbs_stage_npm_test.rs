@@ -284,13 +284,8 @@ mod tests {
 
     fn default_metadata() -> Option<Metadata> {
         Some(Metadata {
-            title: None,
-            summary: None,
-            authors: Default::default(),
-            tags: Default::default(),
-            publishing_date: None,
-            last_edit_date: None,
             data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+            ..Default::default()
         })
     }
 
@@ -21,12 +21,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "content 1".to_string(),
                 }),
@@ -78,12 +73,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "TPL 1 : f1 title \n content 1".to_string(),
                 },
@@ -104,12 +94,8 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: "content 1".to_string(),
                 }),
@@ -117,12 +103,8 @@ mod tests {
                     path: vec!["f2.htm".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f2 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(false))])),
+                        ..Default::default()
                     }),
                     content: "content 2".to_string(),
                 }),
@@ -169,12 +151,8 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true))]))
+                        data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: "content 1".to_string(),
                 },
@@ -182,12 +160,8 @@ mod tests {
                     path: vec!["f2.htm".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f2 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(false))]))
+                        data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(false))])),
+                        ..Default::default()
                     }),
                     content: "TPL 1 : f2 title \n content 2".to_string(),
                 },
@@ -203,12 +177,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "content 1".to_string(),
                 }),
@@ -272,26 +241,16 @@ mod tests {
                 TestPage {
                     path: vec!["a".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))]).collect(),
+                        ..Default::default()
                     }),
                     content: "a content".to_string()
                 },
                 TestPage {
                     path: vec!["b".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))]).collect(),
+                        ..Default::default()
                     }),
                     content: "b content".to_string()
                 },
@@ -312,12 +271,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "TPL 1 : f1 title \n content 1".to_string(),
                 },
@@ -338,12 +292,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "content 1".to_string(),
                 }),
@@ -399,26 +348,16 @@ mod tests {
                 TestPage {
                     path: vec!["tpl_2".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))]).collect(),
+                        ..Default::default()
                     }),
                     content: "TPL 2 : TPL 2 Content".to_string()
                 },
                 TestPage {
                     path: vec!["tpl_3".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))]).collect(),
+                        ..Default::default()
                     }),
                     content: "TPL 3 : TPL 3 Content".to_string()
                 },
@@ -439,12 +378,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "TPL 1 : f1 title \n content 1".to_string(),
                 },
@@ -465,12 +399,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "content 1".to_string(),
                 }),
@@ -531,12 +460,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "TPL root : f1 title \n content 1".to_string(),
                 },
@@ -557,12 +481,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "content 1".to_string(),
                 }),
@@ -641,12 +560,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "TPL root : f1 title \n content 1".to_string(),
                 },
@@ -667,12 +581,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "content 1".to_string(),
                 }),
@@ -690,12 +599,7 @@ mod tests {
                     path: vec!["dir".to_string(), "f4.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "content 4".to_string(),
                 }),
@@ -773,12 +677,7 @@ mod tests {
                     path: vec!["dir".to_string(), "f4.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "TPL base : f4 title \n inner: content 4".to_string(),
                 },
@@ -791,12 +690,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "TPL root : f1 title \n content 1".to_string(),
                 },
@@ -817,12 +711,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "content 1".to_string(),
                 }),
@@ -840,12 +729,7 @@ mod tests {
                     path: vec!["dir".to_string(), "f4.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "content 4".to_string(),
                 }),
@@ -938,39 +822,24 @@ mod tests {
                 TestPage {
                     path: vec!["dir".to_string(), "dir2".to_string(), "main.js".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: "test JS".to_string(),
                 },
                 TestPage {
                     path: vec!["dir".to_string(), "style.css".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: "test css".to_string(),
                 },
                 TestPage {
                     path: vec!["index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: "test index".to_string(),
                 },
@@ -991,12 +860,7 @@ mod tests {
                     path: vec!["dir".to_string(), "f4.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "TPL base : f4 title \n inner: content 4".to_string(),
                 },
@@ -1009,12 +873,7 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "TPL root : f1 title \n content 1".to_string(),
                 },
@@ -1161,13 +1020,8 @@ mod tests {
             &[TestPage {
                 path: vec!["index.html".to_string()],
                 metadata: Some(Metadata {
-                    title: None,
-                    summary: None,
-                    authors: Default::default(),
-                    tags: Default::default(),
-                    publishing_date: None,
-                    last_edit_date: None,
                     data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                    ..Default::default()
                 }),
                 content: indoc! {"10 [20, thirty, ]"
                 }
@@ -1176,6 +1030,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn apply_section_helpers() {
+        let page = |path: &str, title: &str| -> Arc<dyn Page> {
+            Arc::new(TestPage {
+                path: path.split('/').map(|s| s.to_string()).collect(),
+                metadata: Some(Metadata {
+                    title: Some(Arc::new(title.to_string())),
+                    ..Default::default()
+                }),
+                content: String::new(),
+            })
+        };
+        let bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                page("index.html", "Home"),
+                page("docs/index.html", "Docs"),
+                page("docs/setup.html", "Setup"),
+                page("docs/faq.html", "FAQ"),
+            ],
+        });
+        let test_folder = TmpTestFolder::new().unwrap();
+        test_folder
+            .write(&FileNode::Dir {
+                name: "templates".to_string(),
+                sub: vec![FileNode::File {
+                    name: "page.hbs".to_string(),
+                    content: indoc! {"
+                        {{#each breadcrumbs}}{{title}} > {{/each}}{{page.metadata.title}}
+                        siblings: {{#each siblings}}{{title}} {{/each}}
+                        sidebar: {{#with (section_query \"docs\")}}{{#each children}}{{uri}} {{/each}}{{/with}}"
+                    }
+                    .as_bytes()
+                    .to_vec(),
+                    open_options: None,
+                }],
+            })
+            .unwrap();
+        let hb_stage = HbsStage::new("hb stage".to_string(), test_folder.get_path().join("templates")).unwrap();
+        let env = Env::test();
+        let result_bundle = hb_stage.process(&bundle, &env, &PageGeneratorBagImpl::new()).unwrap();
+
+        let bundle_index = BundleIndex::from(&result_bundle.0);
+        let setup = result_bundle.0.pages().iter().find(|p| p.path().join("/") == "docs/setup.html").unwrap();
+        let mut content = String::new();
+        setup.open(&bundle_index.page_index(setup), &bundle_index, &env).unwrap().read_to_string(&mut content).unwrap();
+        assert_eq!(
+            content,
+            indoc! {"
+                Home > Docs > Setup
+                siblings: FAQ 
+                sidebar: /docs/faq.html /docs/setup.html "
+            }
+        );
+    }
+
     #[test]
     fn apply_bundle_query_helper() {
         let bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
@@ -1184,12 +1093,8 @@ mod tests {
                     path: vec!["f1".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(100),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1202,15 +1107,14 @@ mod tests {
                     path: vec!["f3".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f3 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "a1".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
                         publishing_date: Some(200),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1218,21 +1122,21 @@ mod tests {
                     path: vec!["f4".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([
                             Arc::new(Author {
                                 name: "a1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "a2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(300),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1240,12 +1144,9 @@ mod tests {
                     path: vec!["f5".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f5 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t4".to_string())])),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1253,12 +1154,8 @@ mod tests {
                     path: vec!["dir1".to_string(), "dir2".to_string(), "f6".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f6 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1294,6 +1191,18 @@ mod tests {
                                 {{/each}}
                                 {{#each (bundle_query \"{path: dir1/**}\") }}
                                 <h5>{{this.metadata.title}}</h5>
+                                {{/each}}
+                                {{#each (bundle_query \"author:a1 and (tag:t3 or tag:t4)\") }}
+                                <h6>{{this.metadata.title}}</h6>
+                                {{/each}}
+                                {{#each (bundle_query \"tag:t1 and not tag:t2\") }}
+                                <h6>{{this.metadata.title}}</h6>
+                                {{/each}}
+                                {{#each (bundle_query \"tag: t2\") }}
+                                <p>{{this.metadata.title}}</p>
+                                {{/each}}
+                                {{#each (bundle_query \"path: dir1/**\") }}
+                                <p>{{this.metadata.title}}</p>
                                 {{/each}}"}
                         .as_bytes()
                         .to_vec(),
@@ -1334,13 +1243,8 @@ mod tests {
             &[TestPage {
                 path: vec!["index.html".to_string()],
                 metadata: Some(Metadata {
-                    title: None,
-                    summary: None,
-                    authors: Default::default(),
-                    tags: Default::default(),
-                    publishing_date: None,
-                    last_edit_date: None,
                     data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                    ..Default::default()
                 }),
                 content: indoc! {"
                         <h1>f5 title</h1>
@@ -1352,6 +1256,11 @@ mod tests {
                         <h3>f3 title</h3>
                         <h4>f3 title</h4>
                         <h5>f6 title</h5>
+                        <h6>f3 title</h6>
+                        <h6>f5 title</h6>
+                        <p>f4 title</p>
+                        <p>f3 title</p>
+                        <p>f6 title</p>
                         "
                 }
                 .to_string()
@@ -1367,12 +1276,8 @@ mod tests {
                     path: vec!["f1".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(100),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1385,12 +1290,9 @@ mod tests {
                     path: vec!["f3".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f3 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
                         publishing_date: Some(200),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1398,12 +1300,9 @@ mod tests {
                     path: vec!["f4".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(300),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1411,12 +1310,9 @@ mod tests {
                     path: vec!["f5".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f5 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t4".to_string())])),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1424,12 +1320,9 @@ mod tests {
                     path: vec!["f6".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f6 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t6".to_string())])),
                         publishing_date: Some(500),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1500,13 +1393,8 @@ mod tests {
             &[TestPage {
                 path: vec!["index.html".to_string()],
                 metadata: Some(Metadata {
-                    title: None,
-                    summary: None,
-                    authors: Default::default(),
-                    tags: Default::default(),
-                    publishing_date: None,
-                    last_edit_date: None,
                     data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                    ..Default::default()
                 }),
                 content: indoc! {"
                             <h1>f5 title</h1>
@@ -1532,12 +1420,8 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(100),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1550,15 +1434,14 @@ mod tests {
                     path: vec!["f3.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f3 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "a1".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
                         publishing_date: Some(200),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1566,21 +1449,21 @@ mod tests {
                     path: vec!["f4.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([
                             Arc::new(Author {
                                 name: "a1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "a2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(300),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1588,12 +1471,9 @@ mod tests {
                     path: vec!["f5.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f5 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t4".to_string())])),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1601,12 +1481,8 @@ mod tests {
                     path: vec!["dir1".to_string(), "dir2".to_string(), "f6".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f6 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1676,13 +1552,8 @@ mod tests {
             &[TestPage {
                 path: vec!["index.html".to_string()],
                 metadata: Some(Metadata {
-                    title: None,
-                    summary: None,
-                    authors: Default::default(),
-                    tags: Default::default(),
-                    publishing_date: None,
-                    last_edit_date: None,
                     data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                    ..Default::default()
                 }),
                 content: indoc! {"
                         <h1>f5 title</h1>
@@ -1705,12 +1576,8 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(100),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1723,15 +1590,14 @@ mod tests {
                     path: vec!["f3.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f3 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "a1".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("T 1".to_string()), Arc::new("t 2".to_string()), Arc::new("t 3".to_string())])),
                         publishing_date: Some(200),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1739,21 +1605,21 @@ mod tests {
                     path: vec!["f4.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([
                             Arc::new(Author {
                                 name: "a1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "a2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("T 1".to_string()), Arc::new("t 2".to_string())])),
                         publishing_date: Some(300),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1761,12 +1627,9 @@ mod tests {
                     path: vec!["f5.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f5 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("T 1".to_string()), Arc::new("t 4".to_string())])),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1774,12 +1637,8 @@ mod tests {
                     path: vec!["dir1".to_string(), "dir2".to_string(), "f6".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f6 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1853,13 +1712,8 @@ mod tests {
                 TestPage {
                     path: vec!["t_1".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>T 1</h4>
@@ -1873,13 +1727,8 @@ mod tests {
                 TestPage {
                     path: vec!["t_2".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>t 2</h4>
@@ -1891,13 +1740,8 @@ mod tests {
                 TestPage {
                     path: vec!["t_3".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>t 3</h4>
@@ -1908,13 +1752,8 @@ mod tests {
                 TestPage {
                     path: vec!["t_4".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>t 4</h4>
@@ -1934,12 +1773,8 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(100),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1952,15 +1787,14 @@ mod tests {
                     path: vec!["f3.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f3 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "A 1".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
                         publishing_date: Some(200),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1968,21 +1802,21 @@ mod tests {
                     path: vec!["f4.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([
                             Arc::new(Author {
                                 name: "A 1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "a 2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(300),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -1990,12 +1824,9 @@ mod tests {
                     path: vec!["f5.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f5 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t4".to_string())])),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2003,12 +1834,8 @@ mod tests {
                     path: vec!["dir1".to_string(), "dir2".to_string(), "f6".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f6 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2082,13 +1909,8 @@ mod tests {
                 TestPage {
                     path: vec!["a_1".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                                 <h4>A 1</h4>
@@ -2101,13 +1923,8 @@ mod tests {
                 TestPage {
                     path: vec!["a_2".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>a 2</h4>
@@ -2127,12 +1944,8 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(100),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2145,15 +1958,14 @@ mod tests {
                     path: vec!["f3.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f3 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "a1".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
                         publishing_date: Some(200),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2161,21 +1973,21 @@ mod tests {
                     path: vec!["f4.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([
                             Arc::new(Author {
                                 name: "a1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "a2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(300),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2183,12 +1995,9 @@ mod tests {
                     path: vec!["f5.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f5 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t4".to_string())])),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2196,12 +2005,8 @@ mod tests {
                     path: vec!["dir1".to_string(), "dir2".to_string(), "f6".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f6 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2275,13 +2080,8 @@ mod tests {
                 TestPage {
                     path: vec!["0".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>0 / 2</h4>
@@ -2294,13 +2094,8 @@ mod tests {
                 TestPage {
                     path: vec!["1".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>1 / 2</h4>
@@ -2313,13 +2108,8 @@ mod tests {
                 TestPage {
                     path: vec!["2".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>2 / 2</h4>
@@ -2340,12 +2130,8 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(100),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2358,15 +2144,14 @@ mod tests {
                     path: vec!["f3.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f3 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "a1".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
                         publishing_date: Some(200),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2374,21 +2159,21 @@ mod tests {
                     path: vec!["f4.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([
                             Arc::new(Author {
                                 name: "a1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "a2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(300),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2396,12 +2181,9 @@ mod tests {
                     path: vec!["f5.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f5 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t4".to_string())])),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2409,12 +2191,8 @@ mod tests {
                     path: vec!["dir1".to_string(), "dir2".to_string(), "f6".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f6 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2489,13 +2267,8 @@ mod tests {
                 TestPage {
                     path: vec!["1".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>1 / 2</h4>
@@ -2508,13 +2281,8 @@ mod tests {
                 TestPage {
                     path: vec!["2".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>2 / 2</h4>
@@ -2526,13 +2294,8 @@ mod tests {
                 TestPage {
                     path: vec!["index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>0 / 2</h4>
@@ -2554,12 +2317,8 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(100),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2572,15 +2331,14 @@ mod tests {
                     path: vec!["f3.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f3 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "a1".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
                         publishing_date: Some(200),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2588,21 +2346,21 @@ mod tests {
                     path: vec!["f4.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([
                             Arc::new(Author {
                                 name: "a1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "a2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(300),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2610,12 +2368,9 @@ mod tests {
                     path: vec!["f5.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f5 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t4".to_string())])),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2623,12 +2378,8 @@ mod tests {
                     path: vec!["dir1".to_string(), "dir2".to_string(), "f6".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f6 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2704,13 +2455,8 @@ mod tests {
                 TestPage {
                     path: vec!["t1".to_string(), "1".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                                 <h4>t1</h4>
@@ -2722,13 +2468,8 @@ mod tests {
                 TestPage {
                     path: vec!["t1".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                                 <h4>t1</h4>
@@ -2741,13 +2482,8 @@ mod tests {
                 TestPage {
                     path: vec!["t2".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>t2</h4>
@@ -2759,13 +2495,8 @@ mod tests {
                 TestPage {
                     path: vec!["t3".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>t3</h4>
@@ -2776,13 +2507,8 @@ mod tests {
                 TestPage {
                     path: vec!["t4".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>t4</h4>
@@ -2802,12 +2528,8 @@ mod tests {
                     path: vec!["f1.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(100),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2820,15 +2542,14 @@ mod tests {
                     path: vec!["f3.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f3 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "a1".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
                         publishing_date: Some(200),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2836,21 +2557,21 @@ mod tests {
                     path: vec!["f4.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([
                             Arc::new(Author {
                                 name: "a1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "a2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(300),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2858,12 +2579,9 @@ mod tests {
                     path: vec!["f5.html".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f5 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t4".to_string())])),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2871,12 +2589,8 @@ mod tests {
                     path: vec!["dir1".to_string(), "dir2".to_string(), "f6".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f6 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -2952,13 +2666,8 @@ mod tests {
                 TestPage {
                     path: vec!["a1".to_string(), "1".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                                 <h4>a1</h4>
@@ -2970,13 +2679,8 @@ mod tests {
                 TestPage {
                     path: vec!["a1".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                                 <h4>a1</h4>
@@ -2988,13 +2692,8 @@ mod tests {
                 TestPage {
                     path: vec!["a2".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
                     }),
                     content: indoc! {"
                             <h4>a2</h4>
@@ -3006,6 +2705,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn apply_template_asset_metadata_query_selection_with_taxonomy_grouping() {
+        let page = |path: &str, title: &str, categories: Vec<&str>| -> Arc<dyn Page> {
+            Arc::new(TestPage {
+                path: vec![path.to_string()],
+                metadata: Some(Metadata {
+                    title: Some(Arc::new(title.to_string())),
+                    data: HashMap::from_iter(IntoIter::new([(
+                        "categories".to_string(),
+                        Value::Vec(categories.iter().map(|c| Value::String(c.to_string())).collect()),
+                    )])),
+                    ..Default::default()
+                }),
+                content: "".to_string(),
+            })
+        };
+        let bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![page("f1.html", "f1 title", vec!["Web Dev", "rust"]), page("f2.html", "f2 title", vec!["rust"])],
+        });
+        let test_folder = TmpTestFolder::new().unwrap();
+        test_folder
+            .write(&FileNode::Dir {
+                name: "templates".to_string(),
+                sub: vec![
+                    FileNode::File {
+                        name: "page.hbs".to_string(),
+                        content: "{{page_content}}".as_bytes().to_vec(),
+                        open_options: None,
+                    },
+                    FileNode::File {
+                        name: "asset.index.html.hbs.yaml".to_string(),
+                        content: indoc! {"
+                            groupBy: {taxonomy: categories}
+                            path: '{{taxonomy}}/{{term}}/index.html'
+                        "}
+                        .as_bytes()
+                        .to_vec(),
+                        open_options: None,
+                    },
+                    FileNode::File {
+                        name: "asset.index.html.hbs".to_string(),
+                        content: "{{selection.originalTerm}} :{{#each selection.pages }} {{this.metadata.title}}{{/each}}".as_bytes().to_vec(),
+                        open_options: None,
+                    },
+                ],
+            })
+            .unwrap();
+        let hb_stage = HbsStage::new("hb stage".to_string(), test_folder.get_path().join("templates")).unwrap();
+        let page_generator_bag = PageGeneratorBagImpl::new();
+        let env = Env::test();
+        let result_bundle = hb_stage.process(&bundle, &env, &page_generator_bag).unwrap();
+
+        let bundle_index = BundleIndex::new(&result_bundle.0, &["categories".to_string()]);
+        let generated: Vec<Arc<dyn Page>> = page_generator_bag.all().unwrap().iter().flat_map(|g| g.yield_pages(&bundle_index, &env).unwrap()).collect();
+        let mut actual_generated = generated
+            .iter()
+            .map(|p| {
+                let mut content: String = "".to_string();
+                p.open(&PageIndex::from(p), &bundle_index, &env).unwrap().read_to_string(&mut content).unwrap();
+                (p.path().join("/"), content)
+            })
+            .collect::<Vec<_>>();
+        actual_generated.sort();
+        assert_eq!(
+            actual_generated,
+            vec![
+                ("categories/rust/index.html".to_string(), "rust : f1 title f2 title".to_string()),
+                ("categories/web_dev/index.html".to_string(), "Web Dev : f1 title".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn apply_bundle_archive_query_helper() {
         let bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
@@ -3014,12 +2785,8 @@ mod tests {
                     path: vec!["f1".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f1 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(100),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -3032,15 +2799,14 @@ mod tests {
                     path: vec!["f3".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f3 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "a1".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
                         publishing_date: Some(3888000),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -3048,21 +2814,21 @@ mod tests {
                     path: vec!["f4".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f4 title".to_string())),
-                        summary: None,
                         authors: HashSet::from_iter(IntoIter::new([
                             Arc::new(Author {
                                 name: "a1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "a2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(46656000),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -3070,12 +2836,9 @@ mod tests {
                     path: vec!["f5".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f5 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t4".to_string())])),
                         publishing_date: Some(50544000),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -3083,12 +2846,8 @@ mod tests {
                     path: vec!["dir1".to_string(), "dir2".to_string(), "f6".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("f6 title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(58320000),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "".to_string(),
                 }),
@@ -3104,6 +2863,20 @@ mod tests {
                         content: "TPL root : {{page.metadata.title}} \n {{page_content}}".as_bytes().to_vec(),
                         open_options: None,
                     },
+                    FileNode::File {
+                        name: "asset.oldest.html.hbs".to_string(),
+                        content: indoc! {"
+                                {{#each (bundle_archive_query \"\" \"publishingDate\") }}
+                                <h5>{{this.year}}</h5>
+                                    {{#each this.months }}
+                                    <h4>{{this.month}}</h4>
+                                    {{/each}}
+                                {{/each}}"
+                        }
+                        .as_bytes()
+                        .to_vec(),
+                        open_options: None,
+                    },
                     FileNode::File {
                         name: "asset.index.html.hbs".to_string(),
                         content: indoc! {"
@@ -3153,18 +2926,14 @@ mod tests {
         actual_generated.sort_by_key(|f| f.path.join("/"));
         assert_eq!(
             actual_generated,
-            &[TestPage {
-                path: vec!["index.html".to_string()],
-                metadata: Some(Metadata {
-                    title: None,
-                    summary: None,
-                    authors: Default::default(),
-                    tags: Default::default(),
-                    publishing_date: None,
-                    last_edit_date: None,
-                    data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
-                }),
-                content: indoc! {"
+            &[
+                TestPage {
+                    path: vec!["index.html".to_string()],
+                    metadata: Some(Metadata {
+                        data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
+                    }),
+                    content: indoc! {"
                     <h5>1971</h5>
                         <h4>11</h4>
                             <span>f6 title</span>
@@ -3178,8 +2947,26 @@ mod tests {
                         <h4>01</h4>
                             <span>f1 title</span>
                 "}
-                .to_string()
-            },]
+                    .to_string()
+                },
+                TestPage {
+                    path: vec!["oldest.html".to_string()],
+                    metadata: Some(Metadata {
+                        data: HashMap::from_iter(IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))])),
+                        ..Default::default()
+                    }),
+                    content: indoc! {"
+                        <h5>1970</h5>
+                            <h4>01</h4>
+                            <h4>02</h4>
+                        <h5>1971</h5>
+                            <h4>06</h4>
+                            <h4>08</h4>
+                            <h4>11</h4>
+                    "}
+                    .to_string()
+                },
+            ]
         );
     }
 }
@@ -1,5 +1,5 @@
 use crate::config::Value;
-use crate::pages::{BundleIndex, BundlePagination, BundleQuery, Metadata, Page, PageIndex};
+use crate::pages::{BundleIndex, BundlePagination, BundleQuery, Metadata, Page, PageIndex, SortBy};
 use crate::stages::hbs_asset::{HbsAsset, HbsAssetSelection};
 use crate::utilities::uri_friendly_string;
 use handlebars::Handlebars;
@@ -33,6 +33,8 @@ pub(crate) struct TplAssetMetadata {
     pub(crate) path_pattern: Option<String>,
     #[serde(alias = "firstPagePath")]
     pub(crate) first_page_path_pattern: Option<String>,
+    #[serde(default, alias = "sortBy")]
+    pub(crate) sort_by: Option<SortBy>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -41,6 +43,9 @@ pub(crate) enum TplAssetGroupBy {
     Tag,
     #[serde(alias = "author")]
     Author,
+    // terms of a taxonomy declared in site config
+    #[serde(alias = "taxonomy")]
+    Taxonomy(String),
 }
 
 impl TplAssetMetadata {
@@ -57,7 +62,14 @@ impl TplAssetMetadata {
         Ok(asset_path.to_vec())
     }
 
-    pub(crate) fn yield_pages(&self, registry: &handlebars::Handlebars<'static>, asset_path: &[String], tpl_name: &str, output_bundle: &BundleIndex) -> anyhow::Result<Vec<Arc<dyn Page>>> {
+    pub(crate) fn yield_pages(
+        &self,
+        registry: &handlebars::Handlebars<'static>,
+        asset_path: &[String],
+        tpl_name: &str,
+        tpl_digest: &str,
+        output_bundle: &BundleIndex,
+    ) -> anyhow::Result<Vec<Arc<dyn Page>>> {
         let base_query = self.base_query.clone().unwrap_or(BundleQuery::Always);
         let mut queries = vec![];
 
@@ -74,6 +86,7 @@ impl TplAssetMetadata {
                                 },
                                 Some(t.to_string()),
                                 None,
+                                None,
                             )
                         })
                         .collect(),
@@ -89,6 +102,31 @@ impl TplAssetMetadata {
                                 },
                                 None,
                                 Some(a.name.to_string()),
+                                None,
+                            )
+                        })
+                        .collect(),
+                ),
+                TplAssetGroupBy::Taxonomy(taxonomy) => queries.append(
+                    &mut output_bundle
+                        .all_terms
+                        .get(taxonomy)
+                        .into_iter()
+                        .flatten()
+                        .map(|t| {
+                            (
+                                BundleQuery::And {
+                                    and: vec![
+                                        base_query.clone(),
+                                        BundleQuery::Taxonomy {
+                                            taxonomy: taxonomy.to_string(),
+                                            term: t.to_string(),
+                                        },
+                                    ],
+                                },
+                                None,
+                                None,
+                                Some((taxonomy.to_string(), t.to_string())),
                             )
                         })
                         .collect(),
@@ -97,12 +135,16 @@ impl TplAssetMetadata {
         }
 
         if queries.is_empty() {
-            queries.push((base_query, None, None));
+            queries.push((base_query, None, None, None));
         }
-        let no_paginate = BundlePagination { skip: None, limit: None };
+        let no_paginate = BundlePagination {
+            skip: None,
+            limit: None,
+            sort_by: self.sort_by.clone(),
+        };
         if let Some(limit) = self.limit {
             let mut result = vec![];
-            for (q, selection_tag, selection_author) in &queries {
+            for (q, selection_tag, selection_author, selection_term) in &queries {
                 let pages_size = output_bundle.count(q, &no_paginate);
                 let nb_pages: usize = (pages_size as f32 / limit as f32).ceil() as usize;
                 for p in 0..nb_pages {
@@ -111,6 +153,7 @@ impl TplAssetMetadata {
                         &BundlePagination {
                             skip: Some(p * limit),
                             limit: Some(limit),
+                            sort_by: self.sort_by.clone(),
                         },
                     );
                     let selection = HbsAssetSelection {
@@ -120,6 +163,9 @@ impl TplAssetMetadata {
                                 page_ref: e.page_ref.clone(),
                                 page_uri: e.page_uri.clone(),
                                 metadata: e.metadata.clone(),
+                                prev: e.prev.clone(),
+                                next: e.next.clone(),
+                                series: e.series.clone(),
                             })
                             .collect::<Vec<PageIndex>>(),
                         index: p,
@@ -132,20 +178,19 @@ impl TplAssetMetadata {
                         author: selection_author.as_ref().map(|s| uri_friendly_string(s)),
                         original_tag: selection_tag.clone(),
                         original_author: selection_author.clone(),
+                        taxonomy: selection_term.as_ref().map(|(taxonomy, _)| taxonomy.to_string()),
+                        term: selection_term.as_ref().map(|(_, term)| uri_friendly_string(term)),
+                        original_term: selection_term.as_ref().map(|(_, term)| term.to_string()),
                     };
 
                     result.push(Arc::new(HbsAsset {
                         registry: registry.clone(),
                         tpl_name: tpl_name.to_string(),
+                        tpl_digest: tpl_digest.to_string(),
                         path: self.make_path(asset_path, &selection)?,
                         metadata: Some(Metadata {
-                            title: None,
-                            summary: None,
-                            authors: Default::default(),
-                            tags: Default::default(),
-                            publishing_date: None,
-                            last_edit_date: None,
                             data: IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))]).collect(),
+                            ..Default::default()
                         }),
                         selection: Some(selection),
                     }) as Arc<dyn Page>);
@@ -157,7 +202,7 @@ impl TplAssetMetadata {
 
         let result = queries
             .iter()
-            .map(|(q, selection_tag, selection_author)| {
+            .map(|(q, selection_tag, selection_author, selection_term)| {
                 let pages: Vec<PageIndex> = output_bundle
                     .query(q, &no_paginate)
                     .iter()
@@ -165,6 +210,9 @@ impl TplAssetMetadata {
                         page_ref: e.page_ref.clone(),
                         page_uri: e.page_uri.clone(),
                         metadata: e.metadata.clone(),
+                        prev: e.prev.clone(),
+                        next: e.next.clone(),
+                        series: e.series.clone(),
                     })
                     .collect();
                 let limit = pages.len();
@@ -180,20 +228,19 @@ impl TplAssetMetadata {
                     author: selection_author.as_ref().map(|s| uri_friendly_string(s)),
                     original_tag: selection_tag.clone(),
                     original_author: selection_author.clone(),
+                    taxonomy: selection_term.as_ref().map(|(taxonomy, _)| taxonomy.to_string()),
+                    term: selection_term.as_ref().map(|(_, term)| uri_friendly_string(term)),
+                    original_term: selection_term.as_ref().map(|(_, term)| term.to_string()),
                 };
 
                 Ok(Arc::new(HbsAsset {
                     registry: registry.clone(),
                     tpl_name: tpl_name.to_string(),
+                    tpl_digest: tpl_digest.to_string(),
                     path: self.make_path(asset_path, &selection)?,
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))]).collect(),
+                        ..Default::default()
                     }),
                     selection: Some(selection),
                 }) as Arc<dyn Page>)
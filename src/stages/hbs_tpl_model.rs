@@ -12,6 +12,7 @@ pub(crate) struct TplModel {
     pub(crate) registry: handlebars::Handlebars<'static>,
     pub(crate) pages_tpl_names: HashSet<String>,
     pub(crate) assets: Vec<TplAsset>,
+    pub(crate) digest: String,
 }
 
 impl PageGenerator for TplModel {
@@ -21,21 +22,17 @@ impl PageGenerator for TplModel {
             match asset {
                 TplAsset::Tpl { tpl_name, asset_path, metadata } => {
                     if let Some(tpl_meta) = metadata {
-                        let mut pages = tpl_meta.yield_pages(&self.registry, asset_path, tpl_name, output_bundle)?;
+                        let mut pages = tpl_meta.yield_pages(&self.registry, asset_path, tpl_name, &self.digest, output_bundle)?;
                         result.append(&mut pages);
                     } else {
                         result.push(Arc::new(HbsAsset {
                             registry: self.registry.clone(),
                             tpl_name: tpl_name.clone(),
+                            tpl_digest: self.digest.clone(),
                             path: asset_path.clone(),
                             metadata: Some(Metadata {
-                                title: None,
-                                summary: None,
-                                authors: Default::default(),
-                                tags: Default::default(),
-                                publishing_date: None,
-                                last_edit_date: None,
                                 data: IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))]).collect(),
+                                ..Default::default()
                             }),
                             selection: None,
                         }) as Arc<dyn Page>);
@@ -46,13 +43,8 @@ impl PageGenerator for TplModel {
                         base_path,
                         file_path.to_path_buf(),
                         Metadata {
-                            title: None,
-                            summary: None,
-                            authors: Default::default(),
-                            tags: Default::default(),
-                            publishing_date: None,
-                            last_edit_date: None,
                             data: IntoIter::new([("isRaw".to_string(), Value::Bool(true)), ("isHidden".to_string(), Value::Bool(true))]).collect(),
+                            ..Default::default()
                         },
                     )?) as Arc<dyn Page>);
                 }
@@ -69,6 +61,7 @@ impl TplModel {
                 registry: self.registry.clone(),
                 source: Arc::clone(page),
                 tpl_name,
+                tpl_digest: self.digest.clone(),
             }) as Arc<dyn Page>
         })
     }
@@ -1,4 +1,4 @@
-use crate::pages::{BundleIndex, Env, Metadata, Page, PageBundle, PageIndex, VecBundle};
+use crate::pages::{BundleIndex, Env, Fingerprint, Metadata, Page, PageBundle, PageIndex, VecBundle};
 use crate::stages::stage::Stage;
 use crate::stages::{PageGeneratorBag, ProcessingResult};
 use chrono::{DateTime, Utc};
@@ -19,7 +19,7 @@ impl Stage for IndexStage {
     fn process(&self, _: &Arc<dyn PageBundle>, env: &Env, _: &Arc<dyn PageGeneratorBag>) -> anyhow::Result<(Arc<dyn PageBundle>, ProcessingResult)> {
         let start = DateTime::<Utc>::from(SystemTime::now());
         env.print_vv(&format!("stage {}", self.name()), "generate index pages");
-        let result_bundle = VecBundle {
+        let mut result_bundle = VecBundle {
             p: vec![
                 Arc::new(AllPagesPage {
                     path: vec!["all_pages.json".to_string()],
@@ -38,6 +38,16 @@ impl Stage for IndexStage {
                 }),
             ],
         };
+        for taxonomy in env.taxonomies() {
+            result_bundle.p.push(Arc::new(AllTermsPage {
+                path: vec![format!("all_{}.json", taxonomy)],
+                taxonomy: taxonomy.to_string(),
+            }));
+            result_bundle.p.push(Arc::new(PagesByTermPage {
+                path: vec![format!("pages_by_{}.json", taxonomy)],
+                taxonomy,
+            }));
+        }
         let end = DateTime::<Utc>::from(SystemTime::now());
         Ok((
             Arc::new(result_bundle),
@@ -75,6 +85,16 @@ struct PagesByTagPage {
 struct PagesByAuthorPage {
     path: Vec<String>,
 }
+#[derive(Debug)]
+struct AllTermsPage {
+    path: Vec<String>,
+    taxonomy: String,
+}
+#[derive(Debug)]
+struct PagesByTermPage {
+    path: Vec<String>,
+    taxonomy: String,
+}
 
 impl Page for AllPagesPage {
     fn path(&self) -> &[String] {
@@ -89,6 +109,10 @@ impl Page for AllPagesPage {
         let content = serde_json::to_string(&output_index.all_pages)?;
         Ok(Box::new(Cursor::new(content)))
     }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        Some(Fingerprint::new(b"all_pages").using_index())
+    }
 }
 
 impl Page for AllAuthorsPage {
@@ -104,6 +128,10 @@ impl Page for AllAuthorsPage {
         let content = serde_json::to_string(&output_index.all_authors)?;
         Ok(Box::new(Cursor::new(content)))
     }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        Some(Fingerprint::new(b"all_authors").using_index())
+    }
 }
 
 impl Page for AllTagsPage {
@@ -119,6 +147,10 @@ impl Page for AllTagsPage {
         let content = serde_json::to_string(&output_index.all_tags)?;
         Ok(Box::new(Cursor::new(content)))
     }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        Some(Fingerprint::new(b"all_tags").using_index())
+    }
 }
 
 impl Page for PagesByTagPage {
@@ -134,6 +166,10 @@ impl Page for PagesByTagPage {
         let content = serde_json::to_string(&output_index.pages_by_tag)?;
         Ok(Box::new(Cursor::new(content)))
     }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        Some(Fingerprint::new(b"pages_by_tag").using_index())
+    }
 }
 
 impl Page for PagesByAuthorPage {
@@ -149,4 +185,46 @@ impl Page for PagesByAuthorPage {
         let content = serde_json::to_string(&output_index.pages_by_author)?;
         Ok(Box::new(Cursor::new(content)))
     }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        Some(Fingerprint::new(b"pages_by_author").using_index())
+    }
+}
+
+impl Page for AllTermsPage {
+    fn path(&self) -> &[String] {
+        &self.path
+    }
+
+    fn metadata(&self) -> Option<&Metadata> {
+        None
+    }
+
+    fn open(&self, _: &PageIndex, output_index: &BundleIndex, _: &Env) -> anyhow::Result<Box<dyn Read>> {
+        let content = serde_json::to_string(&output_index.all_terms.get(&self.taxonomy))?;
+        Ok(Box::new(Cursor::new(content)))
+    }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        Some(Fingerprint::new(b"all_terms").with(self.taxonomy.as_bytes()).using_index())
+    }
+}
+
+impl Page for PagesByTermPage {
+    fn path(&self) -> &[String] {
+        &self.path
+    }
+
+    fn metadata(&self) -> Option<&Metadata> {
+        None
+    }
+
+    fn open(&self, _: &PageIndex, output_index: &BundleIndex, _: &Env) -> anyhow::Result<Box<dyn Read>> {
+        let content = serde_json::to_string(&output_index.pages_by_term.get(&self.taxonomy))?;
+        Ok(Box::new(Cursor::new(content)))
+    }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        Some(Fingerprint::new(b"pages_by_term").with(self.taxonomy.as_bytes()).using_index())
+    }
 }
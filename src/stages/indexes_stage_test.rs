@@ -2,7 +2,7 @@
 mod tests {
     use crate::config::Value;
     use crate::pages::test_page::TestPage;
-    use crate::pages::{Author, BundleIndex, Env, Metadata, PageBundle, PageIndex, VecBundle};
+    use crate::pages::{Author, BundleIndex, Env, Metadata, Page, PageBundle, PageIndex, VecBundle, TAXONOMIES_KEY};
     use crate::stages::indexes_stage::IndexStage;
     use crate::stages::stage::Stage;
     use crate::stages::test_stage::TestProcessingResult;
@@ -25,10 +25,9 @@ mod tests {
                     authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                         name: "f1 author".to_string(),
                         contacts: Default::default(),
+                        profile: None,
                     })])),
                     tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
-                    publishing_date: None,
-                    last_edit_date: None,
                     data: HashMap::from_iter(IntoIter::new([
                         ("a".to_string(), Value::String("a".to_string())),
                         (
@@ -37,6 +36,7 @@ mod tests {
                         ),
                         ("c".to_string(), Value::I32(10)),
                     ])),
+                    ..Default::default()
                 }),
                 content: String::new(),
             })],
@@ -144,7 +144,8 @@ mod tests {
                 ])),
                 all_authors: HashSet::from_iter(IntoIter::new([Author {
                     name: "f1 author".to_string(),
-                    contacts: Default::default()
+                    contacts: Default::default(),
+                    profile: None,
                 }]))
             }
         );
@@ -162,11 +163,10 @@ mod tests {
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "f1 author".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -184,16 +184,16 @@ mod tests {
                             Arc::new(Author {
                                 name: "f3 author 1".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "f3 author 2".to_string(),
                                 contacts: Default::default(),
+                                profile: None,
                             }),
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t3".to_string()), Arc::new("t4".to_string())])),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -205,11 +205,9 @@ mod tests {
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "f3 author 1".to_string(),
                             contacts: Default::default(),
+                            profile: None,
                         })])),
-                        tags: HashSet::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: String::new(),
                 }),
@@ -351,21 +349,67 @@ mod tests {
                 all_authors: HashSet::from_iter(IntoIter::new([
                     Author {
                         name: "f1 author".to_string(),
-                        contacts: Default::default()
+                        contacts: Default::default(),
+                        profile: None,
                     },
                     Author {
                         name: "f3 author 1".to_string(),
-                        contacts: Default::default()
+                        contacts: Default::default(),
+                        profile: None,
                     },
                     Author {
                         name: "f3 author 2".to_string(),
-                        contacts: Default::default()
+                        contacts: Default::default(),
+                        profile: None,
                     }
                 ]))
             }
         );
     }
 
+    #[test]
+    fn generate_taxonomy_index_pages() {
+        let page = |path: &str, categories: Value| -> Arc<dyn Page> {
+            Arc::new(TestPage {
+                path: vec![path.to_string()],
+                metadata: Some(Metadata {
+                    data: HashMap::from_iter(IntoIter::new([("categories".to_string(), categories)])),
+                    ..Default::default()
+                }),
+                content: String::new(),
+            })
+        };
+        let vec_bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                page("f1", Value::Vec(vec![Value::String("rust".to_string()), Value::String("web".to_string())])),
+                page("f2", Value::String("rust".to_string())),
+            ],
+        });
+        let env = Env::test();
+        env.insert(TAXONOMIES_KEY.to_string(), Value::Vec(vec![Value::String("categories".to_string())]));
+
+        let result_bundle = IndexStage { name: "index stage".to_string() }.process(&vec_bundle, &env, &PageGeneratorBagImpl::new()).unwrap();
+        let output_index = BundleIndex::new(&vec_bundle, &env.taxonomies());
+        let open = |path: &str| {
+            let page = result_bundle.0.pages().iter().find(|p| p.path().join("/") == path).unwrap();
+            page.open(&PageIndex::from(page), &output_index, &env).unwrap()
+        };
+
+        let all_categories: HashSet<String> = serde_json::from_reader(open("all_categories.json")).unwrap();
+        assert_eq!(all_categories, HashSet::from_iter(IntoIter::new(["rust".to_string(), "web".to_string()])));
+        let pages_by_categories: HashMap<String, HashSet<TestPageRef>> = serde_json::from_reader(open("pages_by_categories.json")).unwrap();
+        assert_eq!(
+            pages_by_categories,
+            HashMap::from_iter(IntoIter::new([
+                (
+                    "rust".to_string(),
+                    HashSet::from_iter(IntoIter::new([TestPageRef { path: vec!["f1".to_string()] }, TestPageRef { path: vec!["f2".to_string()] }]))
+                ),
+                ("web".to_string(), HashSet::from_iter(IntoIter::new([TestPageRef { path: vec!["f1".to_string()] }]))),
+            ]))
+        );
+    }
+
     #[derive(Debug, Serialize, Deserialize, PartialEq)]
     struct TestPageIndex {
         page_ref: TestPageRef,
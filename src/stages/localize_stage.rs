@@ -0,0 +1,38 @@
+use crate::pages::{localize, Env, PageBundle};
+use crate::stages::stage::Stage;
+use crate::stages::{PageGeneratorBag, ProcessingResult};
+use chrono::{DateTime, Utc};
+use std::any::Any;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+// moves pages declaring a lang to their locale path, so that following stages, indexes and the writer share the same paths
+pub struct LocalizeStage {
+    pub name: String,
+}
+
+impl Stage for LocalizeStage {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn process(&self, bundle: &Arc<dyn PageBundle>, env: &Env, _: &Arc<dyn PageGeneratorBag>) -> anyhow::Result<(Arc<dyn PageBundle>, ProcessingResult)> {
+        let start = DateTime::<Utc>::from(SystemTime::now());
+        env.print_vv(&format!("stage {}", self.name()), "localize pages");
+        let result_bundle = localize(bundle, env);
+        let end = DateTime::<Utc>::from(SystemTime::now());
+        Ok((
+            result_bundle,
+            ProcessingResult {
+                stage_name: self.name.clone(),
+                start,
+                end,
+                sub_results: vec![],
+            },
+        ))
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+}
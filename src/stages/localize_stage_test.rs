@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::Value;
+    use crate::pages::test_page::TestPage;
+    use crate::pages::{BundleIndex, Env, Metadata, Page, PageBundle, VecBundle, DEFAULT_LANG_KEY};
+    use crate::stages::{LocalizeStage, PageGeneratorBagImpl, Stage};
+    use std::sync::Arc;
+
+    fn page(path: &str, lang: Option<&str>) -> Arc<dyn Page> {
+        Arc::new(TestPage {
+            path: path.split('/').map(|s| s.to_string()).collect(),
+            metadata: lang.map(|l| Metadata {
+                lang: Some(Arc::new(l.to_string())),
+                ..Default::default()
+            }),
+            content: String::new(),
+        })
+    }
+
+    #[test]
+    fn index_pages_at_their_locale_path() {
+        let bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![page("docs/intro.en.html", Some("en")), page("docs/intro.fr.html", Some("fr")), page("style.css", None)],
+        });
+        let env = Env::test();
+        env.insert(DEFAULT_LANG_KEY.to_string(), Value::String("en".to_string()));
+        let stage = LocalizeStage { name: "localize stage".to_string() };
+
+        let (result_bundle, _) = stage.process(&bundle, &env, &PageGeneratorBagImpl::new()).unwrap();
+
+        let mut paths = result_bundle.pages().iter().map(|p| p.path().join("/")).collect::<Vec<String>>();
+        paths.sort();
+        assert_eq!(paths, vec!["docs/intro.html", "fr/docs/intro.html", "style.css"]);
+        let bundle_index = BundleIndex::from(&result_bundle);
+        let intro = bundle_index.all_pages.iter().find(|p| p.page_uri == "/docs/intro.html").unwrap();
+        assert_eq!(bundle_index.translations(intro).iter().map(|p| p.page_uri.as_str()).collect::<Vec<&str>>(), vec!["/fr/docs/intro.html"]);
+    }
+}
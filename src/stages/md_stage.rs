@@ -1,4 +1,4 @@
-use crate::pages::{BundleIndex, Env, Metadata, Page, PageBundle, PageIndex, VecBundle};
+use crate::pages::{BundleIndex, Env, Fingerprint, Metadata, Page, PageBundle, PageIndex, VecBundle};
 use crate::stages::stage::Stage;
 use crate::stages::{PageGeneratorBag, ProcessingResult};
 use chrono::{DateTime, Utc};
@@ -32,7 +32,7 @@ impl Stage for MdStage {
                             rel_path[f_index - 1] = format!("{}.html", &rel_path[f_index - 1][0..ext_index]);
                         }
                     }
-                    Arc::new(MdPage { source: Arc::clone(p), rel_path }) as Arc<dyn Page>
+                    env.cache_content(Arc::new(MdPage { source: Arc::clone(p), rel_path }))
                 })
                 .collect(),
         };
@@ -83,4 +83,8 @@ impl Page for MdPage {
 
         Ok(Box::new(Cursor::new(html_output)))
     }
+
+    fn fingerprint(&self) -> Option<Fingerprint> {
+        self.source.fingerprint().map(|f| f.with(b"md"))
+    }
 }
@@ -10,12 +10,7 @@ mod tests {
     fn new_metadata(title: &str) -> Metadata {
         Metadata {
             title: Some(Arc::new(title.to_string())),
-            summary: None,
-            authors: Default::default(),
-            tags: Default::default(),
-            publishing_date: None,
-            last_edit_date: None,
-            data: HashMap::default(),
+            ..Default::default()
         }
     }
 
@@ -4,6 +4,8 @@ mod compose_stage;
 mod compose_stage_test;
 mod copy_cut_stage;
 mod copy_cut_stage_test;
+mod front_matter_stage;
+mod front_matter_stage_test;
 mod git_metadata;
 mod git_metadata_test;
 mod hbs_asset;
@@ -16,6 +18,8 @@ mod hbs_tpl_asset;
 mod hbs_tpl_model;
 mod indexes_stage;
 mod indexes_stage_test;
+mod localize_stage;
+mod localize_stage_test;
 mod md_stage;
 mod md_stage_test;
 mod metadata_tree;
@@ -32,15 +36,19 @@ mod stage;
 mod test_stage;
 mod union_stage;
 mod union_stage_test;
+mod validate_stage;
+mod validate_stage_test;
 
 pub use self::append_stage::*;
 pub use self::compose_stage::*;
 pub use self::copy_cut_stage::*;
+pub use self::front_matter_stage::*;
 pub use self::git_metadata::*;
 pub use self::hbs_page::*;
 pub use self::hbs_registry_helpers::*;
 pub use self::hbs_stage::*;
 pub use self::indexes_stage::*;
+pub use self::localize_stage::*;
 pub use self::md_stage::*;
 pub use self::path_generator_stage::*;
 pub use self::replace_stage::*;
@@ -48,3 +56,4 @@ pub use self::sequence_stage::*;
 pub use self::shadow_pages::*;
 pub use self::stage::*;
 pub use self::union_stage::*;
+pub use self::validate_stage::*;
@@ -7,7 +7,7 @@ use regex::Regex;
 use serde::Serialize;
 use std::any::Any;
 use std::cmp;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap};
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::sync::Arc;
@@ -166,10 +166,10 @@ pub struct PathParams<'a> {
     pub title: &'a str,
     pub url_title: &'a str,
     pub summary: &'a str,
-    #[serde(default = "HashSet::default")]
-    pub authors: Option<&'a HashSet<String>>,
-    #[serde(default = "HashSet::default")]
-    pub tags: Option<&'a HashSet<String>>,
+    #[serde(default = "BTreeSet::default")]
+    pub authors: Option<&'a BTreeSet<String>>,
+    #[serde(default = "BTreeSet::default")]
+    pub tags: Option<&'a BTreeSet<String>>,
     pub timestamp: Option<i64>,
     pub i_year: Option<i32>,
     pub short_year: &'a str,
@@ -186,6 +186,7 @@ pub struct PathParams<'a> {
     pub i_second: Option<u32>,
     #[serde(default)]
     pub last_edit_date: Option<&'a DateIndex>,
+    pub lang: &'a str,
     #[serde(default = "HashMap::default")]
     pub data: Option<&'a HashMap<String, Value>>,
     pub path: &'a [String],
@@ -215,6 +216,7 @@ impl<'a> From<(&'a [String], &'a [String], &'a str)> for PathParams<'a> {
             i_minute: None,
             i_second: None,
             last_edit_date: None,
+            lang: "",
             data: None,
             path,
             rev_path,
@@ -275,6 +277,10 @@ impl<'a> From<(&'a [String], &'a [String], &'a str, &'a MetadataIndex)> for Path
             i_minute: (&metadata_index.publishing_date).as_ref().map(|d| d.i_minute),
             i_second: (&metadata_index.publishing_date).as_ref().map(|d| d.i_second),
             last_edit_date: metadata_index.last_edit_date.as_ref(),
+            lang: match &metadata_index.lang {
+                None => "",
+                Some(s) => s,
+            },
             data: Some(&metadata_index.data),
             path,
             rev_path,
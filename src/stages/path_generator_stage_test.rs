@@ -17,26 +17,17 @@ mod tests {
                 Arc::new(TestPage {
                     path: vec!["d1".to_string(), "f1".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("a/b/c".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }),
                 Arc::new(TestPage {
                     path: vec!["d1".to_string(), "f2".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(1637671914),
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("page/{{short_year}}/{{short_month}}/{{day}}".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }),
@@ -44,26 +35,14 @@ mod tests {
                     path: vec!["d1".to_string(), "f3".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("F3 Title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("{{url_title}}".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }),
                 Arc::new(TestPage {
                     path: vec!["d1".to_string(), "f4".to_string()],
-                    metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: Default::default(),
-                    }),
+                    metadata: Some(Metadata::default()),
                     content: "test content".to_string(),
                 }),
             ],
@@ -87,52 +66,31 @@ mod tests {
                 TestPage {
                     path: vec!["a".to_string(), "b".to_string(), "c".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("a/b/c".to_string())),])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 },
                 TestPage {
                     path: vec!["d1".to_string(), "f4".to_string()],
-                    metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: Default::default(),
-                    }),
+                    metadata: Some(Metadata::default()),
                     content: "test content".to_string(),
                 },
                 TestPage {
                     path: vec!["f3_title".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("F3 Title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("{{url_title}}".to_string())),])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 },
                 TestPage {
                     path: vec!["page".to_string(), "21".to_string(), "Nov".to_string(), "23".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
                         publishing_date: Some(1637671914),
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("page/{{short_year}}/{{short_month}}/{{day}}".to_string()),),])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 },
@@ -147,26 +105,16 @@ mod tests {
                 Arc::new(TestPage {
                     path: vec!["d1".to_string(), "f1".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("{{path.0}}/other".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }),
                 Arc::new(TestPage {
                     path: vec!["d1".to_string(), "f2".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("other/{{rev_path.0}}".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }),
@@ -191,26 +139,16 @@ mod tests {
                 TestPage {
                     path: vec!["d1".to_string(), "other".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("{{path.0}}/other".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 },
                 TestPage {
                     path: vec!["other".to_string(), "f2".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("other/{{rev_path.0}}".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }
@@ -225,39 +163,24 @@ mod tests {
                 Arc::new(TestPage {
                     path: vec!["d1".to_string(), "f1".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("{{path_join path}}/index.html".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }),
                 Arc::new(TestPage {
                     path: vec!["d1".to_string(), "f2".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("{{path_join path 0 -2}}/index.html".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }),
                 Arc::new(TestPage {
                     path: vec!["d1".to_string(), "d2".to_string(), "f3.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("{{path_join path 0 -2}}/{{file_name}}/index.html".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }),
@@ -282,39 +205,24 @@ mod tests {
                 TestPage {
                     path: vec!["d1".to_string(), "d2".to_string(), "f3".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("{{path_join path 0 -2}}/{{file_name}}/index.html".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 },
                 TestPage {
                     path: vec!["d1".to_string(), "f1".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("{{path_join path}}/index.html".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 },
                 TestPage {
                     path: vec!["d1".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("path".to_string(), Value::String("{{path_join path 0 -2}}/index.html".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }
@@ -329,28 +237,17 @@ mod tests {
                 Arc::new(TestPage {
                     path: vec!["d1".to_string(), "f1".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([(
                             "path".to_string(),
                             Value::Vec(vec![Value::String("^d1/d2".to_string()), Value::String("{{path_join path}}/index.html".to_string())]),
                         )])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }),
                 Arc::new(TestPage {
                     path: vec!["d1".to_string(), "f2".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([(
                             "path".to_string(),
                             Value::Vec(vec![
@@ -359,22 +256,18 @@ mod tests {
                                 Value::Vec(vec![Value::String("^d4".to_string()), Value::String("{{path_join path 0 -2}}/index_3.html".to_string())]),
                             ]),
                         )])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }),
                 Arc::new(TestPage {
                     path: vec!["d1".to_string(), "d2".to_string(), "f3.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([(
                             "path".to_string(),
                             Value::Vec(vec![Value::String("^d1/d2".to_string()), Value::String("{{path_join path 0 -2}}/{{file_name}}/index.html".to_string())]),
                         )])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }),
@@ -399,44 +292,28 @@ mod tests {
                 TestPage {
                     path: vec!["d1".to_string(), "d2".to_string(), "f3".to_string(), "index.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([(
                             "path".to_string(),
                             Value::Vec(vec![Value::String("^d1/d2".to_string()), Value::String("{{path_join path 0 -2}}/{{file_name}}/index.html".to_string())])
                         )])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 },
                 TestPage {
                     path: vec!["d1".to_string(), "f1".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([(
                             "path".to_string(),
                             Value::Vec(vec![Value::String("^d1/d2".to_string()), Value::String("{{path_join path}}/index.html".to_string())])
                         )])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 },
                 TestPage {
                     path: vec!["d1".to_string(), "index_2.html".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([(
                             "path".to_string(),
                             Value::Vec(vec![
@@ -445,6 +322,7 @@ mod tests {
                                 Value::Vec(vec![Value::String("^d4".to_string()), Value::String("{{path_join path 0 -2}}/index_3.html".to_string())]),
                             ]),
                         )])),
+                        ..Default::default()
                     }),
                     content: "test content".to_string(),
                 }
@@ -458,16 +336,11 @@ mod tests {
             p: vec![Arc::new(TestPage {
                 path: vec!["d1".to_string(), "f1".to_string()],
                 metadata: Some(Metadata {
-                    title: None,
-                    summary: None,
-                    authors: Default::default(),
-                    tags: Default::default(),
-                    publishing_date: None,
-                    last_edit_date: None,
                     data: HashMap::from_iter(IntoIter::new([(
                         "path".to_string(),
                         Value::Vec(vec![Value::String("^[a".to_string()), Value::String("{{path_join path}}/index.html".to_string())]),
                     )])),
+                    ..Default::default()
                 }),
                 content: "test content".to_string(),
             })],
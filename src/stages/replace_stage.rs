@@ -3,6 +3,7 @@ use crate::stages::{PageGeneratorBag, ProcessingResult, Stage};
 use chrono::{DateTime, Utc};
 use std::any::Any;
 use std::collections::HashSet;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::SystemTime;
 
@@ -53,6 +54,14 @@ impl Stage for ReplaceStage {
         ))
     }
 
+    fn watched_paths(&self) -> Vec<PathBuf> {
+        self.inner.watched_paths()
+    }
+
+    fn unwatched_paths(&self) -> Vec<PathBuf> {
+        self.inner.unwatched_paths()
+    }
+
     fn as_any(&self) -> Option<&dyn Any> {
         Some(self)
     }
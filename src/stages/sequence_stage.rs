@@ -3,6 +3,7 @@ use crate::stages::stage::Stage;
 use crate::stages::{PageGeneratorBag, ProcessingResult};
 use chrono::{DateTime, Utc};
 use std::any::Any;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::SystemTime;
 
@@ -51,6 +52,14 @@ impl Stage for SequenceStage {
         ))
     }
 
+    fn watched_paths(&self) -> Vec<PathBuf> {
+        self.stages.iter().flat_map(|s| s.watched_paths()).collect()
+    }
+
+    fn unwatched_paths(&self) -> Vec<PathBuf> {
+        self.stages.iter().flat_map(|s| s.unwatched_paths()).collect()
+    }
+
     fn as_any(&self) -> Option<&dyn Any> {
         Some(self)
     }
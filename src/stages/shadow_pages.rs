@@ -1,5 +1,5 @@
 use crate::config::Value;
-use crate::pages::{ArcPage, BundleIndex, Env, Metadata, Page, PageBundle, PageIndex, VecBundle};
+use crate::pages::{ArcPage, AuthorRegistry, BundleIndex, Env, Metadata, Page, PageBundle, PageIndex, VecBundle, AUTHORS_KEY};
 use crate::stages::metadata_tree::MetadataTree;
 use crate::stages::stage::Stage;
 use crate::stages::{PageGeneratorBag, ProcessingResult};
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use std::any::Any;
 use std::array::IntoIter;
 use std::collections::{HashMap, HashSet};
+use std::io::Read;
 use std::sync::Arc;
 use std::time::SystemTime;
 
@@ -22,6 +23,7 @@ pub struct RootPageMetadata {
 pub trait ShadowLoader: Send + Sync {
     fn load(&self, page: Arc<dyn Page>, shadow_page_index: &PageIndex, shadow_output_index: &BundleIndex, env: &Env) -> anyhow::Result<Metadata>;
     fn load_root(&self, page: Arc<dyn Page>, shadow_page_index: &PageIndex, shadow_output_index: &BundleIndex, env: &Env) -> anyhow::Result<RootPageMetadata>;
+    fn load_value(&self, page: Arc<dyn Page>, shadow_page_index: &PageIndex, shadow_output_index: &BundleIndex, env: &Env) -> anyhow::Result<Value>;
 }
 
 pub struct ShadowPages {
@@ -37,7 +39,7 @@ impl Stage for ShadowPages {
     fn process(&self, bundle: &Arc<dyn PageBundle>, env: &Env, _: &Arc<dyn PageGeneratorBag>) -> anyhow::Result<(Arc<dyn PageBundle>, ProcessingResult)> {
         let start = DateTime::<Utc>::from(SystemTime::now());
         env.print_vv(&format!("stage {}", self.name()), "shadow metadata page processing started");
-        let shadow_output_index = BundleIndex::from(bundle);
+        let shadow_output_index = BundleIndex::new(bundle, &env.taxonomies());
 
         let mut vec_bundle = VecBundle { p: vec![] };
 
@@ -48,6 +50,8 @@ impl Stage for ShadowPages {
         let mut root_metadata = None;
         let mut root_env = HashMap::default();
         let mut root_path = None;
+        let mut authors_path = None;
+        let mut author_registry = None;
         for page in bundle.pages() {
             let path = page.path();
             if path.is_empty() {
@@ -59,11 +63,24 @@ impl Stage for ShadowPages {
                     // root metadata
                     let page_index = PageIndex::from(page);
                     let rm = loader.load_root(Arc::clone(page), &page_index, &shadow_output_index, env)?;
-                    root_metadata = rm.page;
+                    if let Some(strategies) = rm.page.as_ref().and_then(|m| m.merge.as_ref()) {
+                        strategies.validate()?;
+                    }
+                    root_metadata = rm.page.map(|m| tag_unknown_fields(path, m));
                     root_env = rm.env;
                     root_path = Some(ext_root_path);
                     continue;
                 }
+                let ext_authors_path = vec![format!("authors{}", ext)];
+                if path == ext_authors_path.as_slice() && author_registry.is_none() {
+                    // authors registry
+                    let page_index = PageIndex::from(page);
+                    let authors = loader.load_value(Arc::clone(page), &page_index, &shadow_output_index, env)?;
+                    author_registry = Some(AuthorRegistry::from_value(authors.clone())?);
+                    env.insert(AUTHORS_KEY.to_string(), authors);
+                    authors_path = Some(ext_authors_path);
+                    continue;
+                }
                 if path[path.len() - 1].ends_with(ext) {
                     metadata_candidates.push(MetadataCandidate {
                         path: page
@@ -100,18 +117,20 @@ impl Stage for ShadowPages {
                 let c_page = Arc::clone(metadata_candidate.page);
                 let c_page_index = PageIndex::from(&c_page);
                 let path = metadata_candidate.path.clone();
-                metadata_candidate
-                    .loader
-                    .load(c_page, &c_page_index, &shadow_output_index, env)
-                    .map(|metadata| LoadedMetadata { path, metadata })
+                metadata_candidate.loader.load(c_page, &c_page_index, &shadow_output_index, env).and_then(|metadata| {
+                    if let Some(strategies) = &metadata.merge {
+                        strategies.validate()?;
+                    }
+                    Ok(LoadedMetadata { path, metadata })
+                })
             })
             .collect::<anyhow::Result<Vec<LoadedMetadata>>>()?;
 
         // feed metadata tree
         let mut metadata_tree = MetadataTree::Root { sub: HashMap::new() };
 
-        for loaded_metadata in loaded_metadata_vec {
-            metadata_tree.push(&loaded_metadata.path, loaded_metadata.metadata)?
+        for (loaded_metadata, candidate) in loaded_metadata_vec.into_iter().zip(&metadata_candidates) {
+            metadata_tree.push(&loaded_metadata.path, tag_unknown_fields(candidate.page.path(), loaded_metadata.metadata))?
         }
 
         let metadata_pages_set = metadata_candidates.iter().map(|c| c.page.path().to_vec()).collect::<HashSet<Vec<String>>>();
@@ -123,6 +142,11 @@ impl Stage for ShadowPages {
                     continue;
                 }
             }
+            if let Some(ap) = &authors_path {
+                if page.path() == ap.as_slice() {
+                    continue;
+                }
+            }
             if !metadata_pages_set.contains(page.path()) {
                 // get path metadata
                 let mut metadata_vec = vec![];
@@ -130,22 +154,6 @@ impl Stage for ShadowPages {
                 if metadata_vec.is_empty() && root_metadata.is_none() {
                     vec_bundle.p.push(Arc::clone(page));
                     continue;
-                } else if metadata_vec.is_empty() && page.metadata().is_none() {
-                    // default metadata
-                    let current_metadata = Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
-                    };
-                    vec_bundle.p.push(page.change_meta(current_metadata.merge(&root_metadata.as_ref().unwrap())?));
-                    continue;
-                } else if metadata_vec.is_empty() {
-                    vec_bundle.p.push(page.change_meta(page.metadata().unwrap().merge(&root_metadata.as_ref().unwrap())?));
-                    continue;
                 }
                 let mut current_metadata: Metadata;
                 if let Some(page_metadata) = page.metadata() {
@@ -153,44 +161,37 @@ impl Stage for ShadowPages {
                     current_metadata = page_metadata.clone();
                 } else if metadata_vec.len() == page.path().len() {
                     // dedicated metadata for current page
-                    current_metadata = metadata_vec
-                        .pop()
-                        .unwrap()
-                        .metadata
-                        .unwrap_or(&Metadata {
-                            title: None,
-                            summary: None,
-                            authors: Default::default(),
-                            tags: Default::default(),
-                            publishing_date: None,
-                            last_edit_date: None,
-                            data: HashMap::default(),
-                        })
-                        .clone();
+                    current_metadata = metadata_vec.pop().unwrap().metadata.unwrap_or(&Metadata::default()).clone();
                 } else {
                     // default metadata
-                    current_metadata = Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
-                    }
+                    current_metadata = Metadata::default()
                 }
 
-                while let Some(metadata_node) = metadata_vec.pop() {
-                    if let Some(m) = metadata_node.metadata {
-                        current_metadata = current_metadata.merge(m)?;
-                    }
-                }
-                if let Some(rm) = &root_metadata {
-                    current_metadata = current_metadata.merge(&rm)?;
+                // each shadow file strategies apply when merging its children into it, the page ones complete them for its nearest parent
+                let mut page_strategies = current_metadata.merge.clone();
+                let parents = metadata_vec.iter().rev().filter_map(|node| node.metadata).chain(root_metadata.iter());
+                for parent in parents {
+                    let parent_strategies = parent.merge.clone().unwrap_or_default();
+                    let strategies = match page_strategies.take() {
+                        Some(s) => s.or(&parent_strategies),
+                        None => parent_strategies,
+                    };
+                    current_metadata = current_metadata.merge_with(parent, &strategies)?;
                 }
                 vec_bundle.p.push(page.change_meta(current_metadata));
             }
         }
+        // resolve authors to registry entries
+        if let Some(registry) = &author_registry {
+            for page in vec_bundle.p.iter_mut() {
+                if let Some(m) = page.metadata() {
+                    if !m.authors.is_empty() {
+                        *page = page.change_meta(registry.resolve_metadata(m)?);
+                    }
+                }
+            }
+        }
+
         env.print_vv(&format!("stage {}", self.name()), "shadow metadata page processing ended");
         let end = DateTime::<Utc>::from(SystemTime::now());
         Ok((
@@ -209,6 +210,12 @@ impl Stage for ShadowPages {
     }
 }
 
+// unknown fields are tagged with the shadow file declaring them, the validate stage reports them on the pages they are merged into
+fn tag_unknown_fields(shadow_path: &[String], mut metadata: Metadata) -> Metadata {
+    metadata.unknown_fields = metadata.unknown_fields.from_shadow(shadow_path);
+    metadata
+}
+
 impl ShadowPages {
     pub fn new(name: String, loaders: HashMap<String, Arc<dyn ShadowLoader>>) -> Self {
         ShadowPages { name, loaders }
@@ -220,6 +227,7 @@ impl ShadowPages {
             loaders: IntoIter::new([
                 (".json".to_string(), Arc::new(JsonShadowLoader()) as Arc<dyn ShadowLoader>),
                 (".yaml".to_string(), Arc::new(YamlShadowLoader()) as Arc<dyn ShadowLoader>),
+                (".toml".to_string(), Arc::new(TomlShadowLoader()) as Arc<dyn ShadowLoader>),
             ])
             .collect(),
         }
@@ -228,6 +236,7 @@ impl ShadowPages {
 
 struct JsonShadowLoader();
 struct YamlShadowLoader();
+struct TomlShadowLoader();
 
 impl ShadowLoader for JsonShadowLoader {
     fn load(&self, page: Arc<dyn Page>, shadow_page_index: &PageIndex, shadow_output_index: &BundleIndex, env: &Env) -> anyhow::Result<Metadata> {
@@ -239,6 +248,11 @@ impl ShadowLoader for JsonShadowLoader {
         env.print_vvv("json shadow root loader", &format!("loading from page {}", page.path().join("/")));
         Ok(serde_json::from_reader(page.open(shadow_page_index, shadow_output_index, env)?)?)
     }
+
+    fn load_value(&self, page: Arc<dyn Page>, shadow_page_index: &PageIndex, shadow_output_index: &BundleIndex, env: &Env) -> anyhow::Result<Value> {
+        env.print_vvv("json shadow value loader", &format!("loading from page {}", page.path().join("/")));
+        Ok(serde_json::from_reader(page.open(shadow_page_index, shadow_output_index, env)?)?)
+    }
 }
 
 impl ShadowLoader for YamlShadowLoader {
@@ -251,6 +265,34 @@ impl ShadowLoader for YamlShadowLoader {
         env.print_vvv("yaml shadow root loader", &format!("loading from page {}", page.path().join("/")));
         Ok(serde_yaml::from_reader(page.open(shadow_page_index, shadow_output_index, env)?)?)
     }
+
+    fn load_value(&self, page: Arc<dyn Page>, shadow_page_index: &PageIndex, shadow_output_index: &BundleIndex, env: &Env) -> anyhow::Result<Value> {
+        env.print_vvv("yaml shadow value loader", &format!("loading from page {}", page.path().join("/")));
+        Ok(serde_yaml::from_reader(page.open(shadow_page_index, shadow_output_index, env)?)?)
+    }
+}
+
+impl ShadowLoader for TomlShadowLoader {
+    fn load(&self, page: Arc<dyn Page>, shadow_page_index: &PageIndex, shadow_output_index: &BundleIndex, env: &Env) -> anyhow::Result<Metadata> {
+        env.print_vvv("toml shadow loader", &format!("loading from page {}", page.path().join("/")));
+        let mut content = String::new();
+        page.open(shadow_page_index, shadow_output_index, env)?.read_to_string(&mut content)?;
+        Ok(toml::from_str(&content)?)
+    }
+
+    fn load_root(&self, page: Arc<dyn Page>, shadow_page_index: &PageIndex, shadow_output_index: &BundleIndex, env: &Env) -> anyhow::Result<RootPageMetadata> {
+        env.print_vvv("toml shadow root loader", &format!("loading from page {}", page.path().join("/")));
+        let mut content = String::new();
+        page.open(shadow_page_index, shadow_output_index, env)?.read_to_string(&mut content)?;
+        Ok(toml::from_str(&content)?)
+    }
+
+    fn load_value(&self, page: Arc<dyn Page>, shadow_page_index: &PageIndex, shadow_output_index: &BundleIndex, env: &Env) -> anyhow::Result<Value> {
+        env.print_vvv("toml shadow value loader", &format!("loading from page {}", page.path().join("/")));
+        let mut content = String::new();
+        page.open(shadow_page_index, shadow_output_index, env)?.read_to_string(&mut content)?;
+        Ok(toml::from_str(&content)?)
+    }
 }
 
 struct MetadataCandidate<'a> {
@@ -2,11 +2,12 @@
 mod tests {
     use crate::config::Value;
     use crate::pages::test_page::TestPage;
-    use crate::pages::{Author, Env, Metadata, PageBundle, VecBundle};
+    use crate::pages::{Author, Env, Metadata, Page, PageBundle, VecBundle, AUTHORS_KEY};
     use crate::stages::shadow_pages::ShadowPages;
     use crate::stages::stage::Stage;
     use crate::stages::test_stage::TestProcessingResult;
     use crate::stages::PageGeneratorBagImpl;
+    use chrono::DateTime;
     use indoc::indoc;
     use std::array::IntoIter;
     use std::collections::{HashMap, HashSet};
@@ -47,12 +48,7 @@ mod tests {
                 path: vec!["a".to_string()],
                 metadata: Some(Metadata {
                     title: Some(Arc::new("a title".to_string())),
-                    summary: None,
-                    authors: Default::default(),
-                    tags: Default::default(),
-                    publishing_date: None,
-                    last_edit_date: None,
-                    data: HashMap::default(),
+                    ..Default::default()
                 }),
                 content: "'a' content".to_string()
             },]
@@ -136,12 +132,7 @@ mod tests {
                     path: vec!["a".to_string()],
                     metadata: Some(Metadata {
                         title: Some(Arc::new("a title".to_string())),
-                        summary: None,
-                        authors: Default::default(),
-                        tags: Default::default(),
-                        publishing_date: None,
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "'a' content".to_string()
                 },
@@ -154,16 +145,19 @@ mod tests {
                             Arc::new(Author {
                                 name: "a1".to_string(),
                                 contacts: HashSet::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "a2".to_string(),
                                 contacts: vec!["c1", "c2"].iter().map(|x| x.to_string()).collect(),
+                                profile: None,
                             })
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
                         publishing_date: Some(1634774400),
                         last_edit_date: Some(1634778000),
                         data: HashMap::from_iter(IntoIter::new([("a".to_string(), Value::I32(10))])),
+                        ..Default::default()
                     }),
                     content: "'b' content".to_string()
                 },
@@ -176,16 +170,19 @@ mod tests {
                             Arc::new(Author {
                                 name: "a1".to_string(),
                                 contacts: HashSet::default(),
+                                profile: None,
                             }),
                             Arc::new(Author {
                                 name: "a2".to_string(),
                                 contacts: vec!["c1", "c2"].iter().map(|x| x.to_string()).collect(),
+                                profile: None,
                             })
                         ])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
                         publishing_date: Some(1634745600),
                         last_edit_date: Some(1634688000),
                         data: HashMap::from_iter(IntoIter::new([("a".to_string(), Value::I32(10))])),
+                        ..Default::default()
                     }),
                     content: "'c' content".to_string()
                 },
@@ -331,39 +328,27 @@ mod tests {
                 TestPage {
                     path: vec!["a".to_string(), "b".to_string(), "c.txt".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(1634774400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "'c' content".to_string()
                 },
                 TestPage {
                     path: vec!["a".to_string(), "d.txt".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(1634774400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "'d' content".to_string()
                 },
                 TestPage {
                     path: vec!["a".to_string(), "e.txt".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(1634774400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "'e' content".to_string()
                 },
@@ -462,37 +447,29 @@ mod tests {
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "a2".to_string(),
                             contacts: vec!["c3", "c4"].iter().map(|x| x.to_string()).collect(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string()), Arc::new("t3".to_string())])),
                         publishing_date: Some(1634778000),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "'c' content".to_string()
                 },
                 TestPage {
                     path: vec!["a".to_string(), "d.txt".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(1634774400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "'d' content".to_string()
                 },
                 TestPage {
                     path: vec!["a".to_string(), "e.txt".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(1634774400),
-                        last_edit_date: None,
-                        data: HashMap::default(),
+                        ..Default::default()
                     }),
                     content: "'e' content".to_string()
                 },
@@ -602,6 +579,7 @@ mod tests {
                         authors: HashSet::from_iter(IntoIter::new([Arc::new(Author {
                             name: "a2".to_string(),
                             contacts: vec!["c3", "c4"].iter().map(|x| x.to_string()).collect(),
+                            profile: None,
                         })])),
                         tags: HashSet::from_iter(IntoIter::new([
                             Arc::new("root_tag".to_string()),
@@ -610,60 +588,46 @@ mod tests {
                             Arc::new("t3".to_string())
                         ])),
                         publishing_date: Some(1634778000),
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("some_root_key".to_string(), Value::String("some_value".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "'c' content".to_string()
                 },
                 TestPage {
                     path: vec!["a".to_string(), "d.txt".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("root_tag".to_string()), Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(1634774400),
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("some_root_key".to_string(), Value::String("some_value".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "'d' content".to_string()
                 },
                 TestPage {
                     path: vec!["a".to_string(), "e.txt".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("root_tag".to_string()), Arc::new("t1".to_string()), Arc::new("t2".to_string())])),
                         publishing_date: Some(1634774400),
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("some_root_key".to_string(), Value::String("some_value".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "'e' content".to_string()
                 },
                 TestPage {
                     path: vec!["f.txt".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("root_tag".to_string())])),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("some_root_key".to_string(), Value::String("some_value".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "'f' content".to_string()
                 },
                 TestPage {
                     path: vec!["g".to_string(), "h".to_string(), "i.txt".to_string()],
                     metadata: Some(Metadata {
-                        title: None,
-                        summary: None,
-                        authors: Default::default(),
                         tags: HashSet::from_iter(IntoIter::new([Arc::new("root_tag".to_string())])),
-                        publishing_date: None,
-                        last_edit_date: None,
                         data: HashMap::from_iter(IntoIter::new([("some_root_key".to_string(), Value::String("some_value".to_string()))])),
+                        ..Default::default()
                     }),
                     content: "'i' content".to_string()
                 },
@@ -703,4 +667,267 @@ mod tests {
             ])))
         );
     }
+
+    #[test]
+    fn shadow_pages_stage_should_load_toml_page_and_root_metadata() {
+        let vec_bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                Arc::new(TestPage {
+                    path: vec!["a".to_string()],
+                    metadata: None,
+                    content: "'a' content".to_string(),
+                }),
+                Arc::new(TestPage {
+                    path: vec!["a.toml".to_string()],
+                    metadata: None,
+                    content: indoc! {r#"
+                        title = "a title"
+                        publishing_date = 2021-10-20T16:00:00-08:00
+                        last_edit_date = "2021-10-21"
+                    "#}
+                    .to_string(),
+                }),
+                Arc::new(TestPage {
+                    path: vec!["pages.toml".to_string()],
+                    metadata: None,
+                    content: indoc! {r#"
+                        [page]
+                        summary = "root summary"
+                        tags = ["t1"]
+
+                        [env]
+                        A = 10
+                        B = "B value"
+                    "#}
+                    .to_string(),
+                }),
+            ],
+        });
+        let shadow_stage = ShadowPages::default("shadow stage".to_string());
+        let test_env = Env::test();
+
+        let result_bundle = shadow_stage.process(&vec_bundle, &test_env, &PageGeneratorBagImpl::new()).unwrap();
+        let actual = result_bundle.0.pages().iter().map(|p| TestPage::from(p)).collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            &[TestPage {
+                path: vec!["a".to_string()],
+                metadata: Some(Metadata {
+                    title: Some(Arc::new("a title".to_string())),
+                    summary: Some(Arc::new("root summary".to_string())),
+                    tags: HashSet::from_iter(IntoIter::new([Arc::new("t1".to_string())])),
+                    publishing_date: Some(DateTime::parse_from_rfc3339("2021-10-20T16:00:00-08:00").unwrap().timestamp()),
+                    last_edit_date: Some(DateTime::parse_from_rfc3339("2021-10-21T00:00:00Z").unwrap().timestamp()),
+                    ..Default::default()
+                }),
+                content: "'a' content".to_string()
+            }]
+        );
+        assert_eq!(test_env.get("A").unwrap(), Value::I32(10));
+        assert_eq!(test_env.get("B").unwrap(), Value::String("B value".to_string()));
+    }
+
+    #[test]
+    fn shadow_pages_stage_should_resolve_authors_from_registry() {
+        let vec_bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                Arc::new(TestPage {
+                    path: vec!["a".to_string()],
+                    metadata: None,
+                    content: "'a' content".to_string(),
+                }),
+                Arc::new(TestPage {
+                    path: vec!["a.yaml".to_string()],
+                    metadata: None,
+                    content: indoc! {"
+                        ---
+                        authors:
+                          - name: John Doe
+                          - name: git user
+                            contacts: [jdoe@corp]
+                          - name: guest
+                    "}
+                    .to_string(),
+                }),
+                Arc::new(TestPage {
+                    path: vec!["authors.yaml".to_string()],
+                    metadata: None,
+                    content: indoc! {"
+                        ---
+                        jdoe:
+                          display_name: John Doe
+                          aliases: [jdoe@corp]
+                    "}
+                    .to_string(),
+                }),
+            ],
+        });
+        let shadow_stage = ShadowPages::default("shadow stage".to_string());
+        let env = Env::test();
+
+        let result_bundle = shadow_stage.process(&vec_bundle, &env, &PageGeneratorBagImpl::new()).unwrap();
+        let actual = result_bundle.0.pages().iter().map(|p| TestPage::from(p)).collect::<Vec<_>>();
+        assert_eq!(actual.iter().map(|p| p.path.join("/")).collect::<Vec<_>>(), vec!["a"]);
+        let mut authors = actual[0].metadata.as_ref().unwrap().authors.iter().collect::<Vec<_>>();
+        authors.sort_by_key(|a| a.name.to_string());
+        assert_eq!(
+            authors
+                .iter()
+                .map(|a| (a.name.as_str(), a.profile.as_ref().and_then(|p| p.display_name.as_deref())))
+                .collect::<Vec<_>>(),
+            vec![("guest", None), ("jdoe", Some("John Doe"))]
+        );
+        assert_eq!(authors[1].contacts, HashSet::from_iter(IntoIter::new(["jdoe@corp".to_string()])));
+        assert!(env.get(AUTHORS_KEY).is_some());
+    }
+
+    #[test]
+    fn shadow_pages_stage_should_apply_declared_merge_strategies() {
+        let page = |path: &str, content: &str| -> Arc<dyn Page> {
+            Arc::new(TestPage {
+                path: path.split('/').map(|s| s.to_string()).collect(),
+                metadata: None,
+                content: content.to_string(),
+            })
+        };
+        let vec_bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                page("pages.yaml", "{page: {tags: [site], data: {menu: {icon: home, weight: 0}}, merge: {data: deep-merge}}}"),
+                page("guides.yaml", "{tags: [guide], merge: {tags: replace}}"),
+                page("guides/a.txt.yaml", "{tags: [setup], data: {menu: {weight: 2}}}"),
+                page("guides/a.txt", "'a' content"),
+                page("guides/b.txt", "'b' content"),
+                page("blog/c.txt.yaml", "{tags: [news], data: {menu: {weight: 3}}}"),
+                page("blog/c.txt", "'c' content"),
+            ],
+        });
+        let shadow_stage = ShadowPages::default("shadow stage".to_string());
+
+        let result_bundle = shadow_stage.process(&vec_bundle, &Env::test(), &PageGeneratorBagImpl::new()).unwrap();
+        let mut actual = result_bundle
+            .0
+            .pages()
+            .iter()
+            .map(|p| {
+                let m = p.metadata().unwrap();
+                let mut tags = m.tags.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+                tags.sort();
+                (p.path().join("/"), tags, m.data.get("menu").cloned())
+            })
+            .collect::<Vec<_>>();
+        actual.sort_by(|a, b| a.0.cmp(&b.0));
+        let menu = |weight: i32| {
+            Some(Value::Map(
+                IntoIter::new([("icon".to_string(), Value::String("home".to_string())), ("weight".to_string(), Value::I32(weight))]).collect(),
+            ))
+        };
+        assert_eq!(
+            actual,
+            vec![
+                ("blog/c.txt".to_string(), vec!["news".to_string(), "site".to_string()], menu(3)),
+                ("guides/a.txt".to_string(), vec!["setup".to_string(), "site".to_string()], menu(2)),
+                ("guides/b.txt".to_string(), vec!["guide".to_string(), "site".to_string()], menu(0)),
+            ]
+        );
+
+        let vec_bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![page("guides.yaml", "{merge: {title: append}}"), page("guides/a.txt", "'a' content")],
+        });
+        let err = shadow_stage.process(&vec_bundle, &Env::test(), &PageGeneratorBagImpl::new()).err().unwrap();
+        assert_eq!(err.to_string(), "merge strategy append is not supported for field title");
+    }
+
+    #[test]
+    fn shadow_pages_stage_should_apply_merge_strategies_of_each_level() {
+        let page = |path: &str, content: &str| -> Arc<dyn Page> {
+            Arc::new(TestPage {
+                path: path.split('/').map(|s| s.to_string()).collect(),
+                metadata: None,
+                content: content.to_string(),
+            })
+        };
+        let vec_bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                page("pages.yaml", "{page: {tags: [site]}}"),
+                page("docs.yaml", "{tags: [docs], merge: {tags: ignore-parent}}"),
+                page("docs/guides.yaml", "{tags: [guide], merge: {tags: replace}}"),
+                page("docs/guides/a.txt", "'a' content"),
+                page("docs/guides/b.txt.yaml", "{tags: [b]}"),
+                page("docs/guides/b.txt", "'b' content"),
+                page("docs/c.txt.yaml", "{merge: {tags: replace}}"),
+                page("docs/c.txt", "'c' content"),
+            ],
+        });
+        let shadow_stage = ShadowPages::default("shadow stage".to_string());
+
+        let result_bundle = shadow_stage.process(&vec_bundle, &Env::test(), &PageGeneratorBagImpl::new()).unwrap();
+        let mut actual = result_bundle
+            .0
+            .pages()
+            .iter()
+            .map(|p| {
+                let mut tags = p.metadata().unwrap().tags.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+                tags.sort();
+                (p.path().join("/"), tags.join(","))
+            })
+            .collect::<Vec<_>>();
+        actual.sort();
+        // guides replaces tags of its children, docs ignores its own tags for them and root appends its ones ;
+        // c.txt own strategy takes precedence over the docs one for its merge into docs only
+        assert_eq!(
+            actual,
+            vec![
+                ("docs/c.txt".to_string(), "docs,site".to_string()),
+                ("docs/guides/a.txt".to_string(), "guide,site".to_string()),
+                ("docs/guides/b.txt".to_string(), "b,site".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn shadow_pages_stage_should_tag_unknown_fields_with_shadow_files() {
+        let page = |path: &str, content: &str| -> Arc<dyn Page> {
+            Arc::new(TestPage {
+                path: path.split('/').map(|s| s.to_string()).collect(),
+                metadata: None,
+                content: content.to_string(),
+            })
+        };
+        let vec_bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![
+                page("pages.yaml", "{page: {tags: [site], colour: red}}"),
+                page("guides.yaml", "{titel: guides}"),
+                page("guides/a.txt.yaml", "{title: a, sumary: a}"),
+                page("guides/a.txt", "'a' content"),
+                page("guides/b.txt", "'b' content"),
+            ],
+        });
+        let result_bundle = ShadowPages::default("shadow stage".to_string())
+            .process(&vec_bundle, &Env::test(), &PageGeneratorBagImpl::new())
+            .unwrap();
+        let mut unknown_fields = result_bundle
+            .0
+            .pages()
+            .iter()
+            .map(|page| (page.path().join("/"), page.metadata().unwrap().unknown_fields.0.iter().map(|f| f.to_string()).collect::<Vec<String>>()))
+            .collect::<Vec<(String, Vec<String>)>>();
+        unknown_fields.sort();
+        assert_eq!(
+            unknown_fields,
+            vec![
+                (
+                    "guides/a.txt".to_string(),
+                    vec![
+                        "unknown metadata field colour in pages.yaml".to_string(),
+                        "unknown metadata field sumary in guides/a.txt.yaml".to_string(),
+                        "unknown metadata field titel in guides.yaml".to_string()
+                    ]
+                ),
+                (
+                    "guides/b.txt".to_string(),
+                    vec!["unknown metadata field colour in pages.yaml".to_string(), "unknown metadata field titel in guides.yaml".to_string()]
+                ),
+            ]
+        );
+    }
 }
@@ -2,6 +2,7 @@ use crate::pages::{BundleIndex, Env, Page, PageBundle};
 use crate::pages_error::PagesError;
 use chrono::{DateTime, Utc};
 use std::any::Any;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
 pub trait Stage: Send + Sync {
@@ -10,6 +11,13 @@ pub trait Stage: Send + Sync {
     fn as_any(&self) -> Option<&dyn Any> {
         None
     }
+    fn watched_paths(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+    // paths under watched ones that the stage writes itself (npm installs and builds...)
+    fn unwatched_paths(&self) -> Vec<PathBuf> {
+        vec![]
+    }
 }
 
 #[derive(Debug)]
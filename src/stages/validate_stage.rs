@@ -0,0 +1,153 @@
+use crate::config::Value;
+use crate::pages::{Env, Metadata, Page, PageBundle};
+use crate::pages_error::PagesError;
+use crate::stages::stage::Stage;
+use crate::stages::{PageGeneratorBag, ProcessingResult};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataType {
+    String,
+    Number,
+    Bool,
+    Map,
+    List,
+}
+
+impl DataType {
+    fn matches(&self, value: &Value) -> bool {
+        matches!(
+            (self, value),
+            (DataType::String, Value::String(_))
+                | (DataType::Number, Value::I32(_) | Value::I64(_) | Value::U64(_) | Value::F64(_))
+                | (DataType::Bool, Value::Bool(_))
+                | (DataType::Map, Value::Map(_))
+                | (DataType::List, Value::Vec(_))
+        )
+    }
+}
+
+impl Display for DataType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            DataType::String => "string",
+            DataType::Number => "number",
+            DataType::Bool => "bool",
+            DataType::Map => "map",
+            DataType::List => "list",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetadataSchema {
+    pub required_title: bool,
+    pub required_summary: bool,
+    pub allowed_tags: Option<HashSet<String>>,
+    pub known_authors: Option<HashSet<String>>,
+    pub data_types: HashMap<String, DataType>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Violation {
+    pub path: Vec<String>,
+    pub message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{} : {}", self.path.join("/"), self.message))
+    }
+}
+
+impl MetadataSchema {
+    pub fn validate(&self, page: &Arc<dyn Page>) -> Vec<Violation> {
+        let violation = |message: String| Violation { path: page.path().to_vec(), message };
+        let metadata = match page.metadata() {
+            None if self.required_title || self.required_summary => return vec![violation("missing metadata".to_string())],
+            None => return vec![],
+            Some(m) => m,
+        };
+        let mut result = self.validate_metadata(metadata).into_iter().map(violation).collect::<Vec<_>>();
+        result.sort_by(|a, b| a.message.cmp(&b.message));
+        result
+    }
+
+    fn validate_metadata(&self, metadata: &Metadata) -> Vec<String> {
+        let mut result = vec![];
+        for field in &metadata.unknown_fields.0 {
+            result.push(field.to_string());
+        }
+        if self.required_title && metadata.title.is_none() {
+            result.push("missing title".to_string());
+        }
+        if self.required_summary && metadata.summary.is_none() {
+            result.push("missing summary".to_string());
+        }
+        if let Some(allowed_tags) = &self.allowed_tags {
+            for tag in metadata.tags.iter().filter(|t| !allowed_tags.contains(t.as_str())) {
+                result.push(format!("tag {} is not allowed", tag));
+            }
+        }
+        if let Some(known_authors) = &self.known_authors {
+            for author in metadata.authors.iter().filter(|a| !known_authors.contains(&a.name)) {
+                result.push(format!("author {} is unknown", author.name));
+            }
+        }
+        for (key, data_type) in &self.data_types {
+            if let Some(value) = metadata.data.get(key) {
+                if !data_type.matches(value) {
+                    result.push(format!("data {} is expected to be a {}", key, data_type));
+                }
+            }
+        }
+        result
+    }
+}
+
+// checks pages metadata against a schema, violations fail the build only in strict mode
+pub struct ValidateStage {
+    pub name: String,
+    pub schema: MetadataSchema,
+    pub strict: bool,
+}
+
+impl Stage for ValidateStage {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn process(&self, bundle: &Arc<dyn PageBundle>, env: &Env, _: &Arc<dyn PageGeneratorBag>) -> anyhow::Result<(Arc<dyn PageBundle>, ProcessingResult)> {
+        let start = DateTime::<Utc>::from(SystemTime::now());
+        env.print_vv(&format!("stage {}", self.name()), "metadata validation");
+        let violations = bundle.pages().iter().flat_map(|p| self.schema.validate(p)).collect::<Vec<Violation>>();
+        if !violations.is_empty() {
+            let report = violations.iter().map(|v| v.to_string()).collect::<Vec<String>>().join("\n");
+            if self.strict {
+                return Err(PagesError::Validation(format!("{} metadata violations found\n{}", violations.len(), report)).into());
+            }
+            env.print_error(&format!("stage {}", self.name()), &format!("{} metadata violations found\n{}", violations.len(), report));
+        }
+        let end = DateTime::<Utc>::from(SystemTime::now());
+        Ok((
+            Arc::clone(bundle),
+            ProcessingResult {
+                stage_name: self.name.clone(),
+                start,
+                end,
+                sub_results: vec![],
+            },
+        ))
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+}
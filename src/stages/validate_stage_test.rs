@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use crate::pages::test_page::TestPage;
+    use crate::pages::{Env, Page, PageBundle, VecBundle};
+    use crate::pages_error::PagesError;
+    use crate::stages::stage::Stage;
+    use crate::stages::{DataType, FrontMatterStage, MetadataSchema, PageGeneratorBagImpl, SequenceStage, ShadowPages, ValidateStage};
+    use indoc::indoc;
+    use std::array::IntoIter;
+    use std::sync::Arc;
+
+    fn bundle() -> Arc<dyn PageBundle> {
+        Arc::new(VecBundle {
+            p: vec![
+                Arc::new(TestPage {
+                    path: vec!["f1.md".to_string()],
+                    metadata: None,
+                    content: indoc! {"
+                        ---
+                        title: f1 title
+                        publishingdate: 2021-10-20
+                        tags: [t1, t3]
+                        authors:
+                          - name: a2
+                        data:
+                          weight: heavy
+                        ---
+                        f1 content
+                    "}
+                    .to_string(),
+                }),
+                Arc::new(TestPage {
+                    path: vec!["f2.md".to_string()],
+                    metadata: None,
+                    content: indoc! {"
+                        ---
+                        title: f2 title
+                        tags: [t1]
+                        data:
+                          weight: 2
+                        ---
+                        f2 content
+                    "}
+                    .to_string(),
+                }),
+                Arc::new(TestPage {
+                    path: vec!["f3.md".to_string()],
+                    metadata: None,
+                    content: "f3 content".to_string(),
+                }),
+            ],
+        })
+    }
+
+    fn validation_sequence(strict: bool) -> SequenceStage {
+        SequenceStage {
+            name: "sequence".to_string(),
+            stages: vec![
+                Arc::new(FrontMatterStage {
+                    name: "front matter stage".to_string(),
+                }),
+                Arc::new(ValidateStage {
+                    name: "validate stage".to_string(),
+                    schema: MetadataSchema {
+                        required_title: true,
+                        required_summary: false,
+                        allowed_tags: Some(IntoIter::new(["t1".to_string(), "t2".to_string()]).collect()),
+                        known_authors: Some(IntoIter::new(["a1".to_string()]).collect()),
+                        data_types: IntoIter::new([("weight".to_string(), DataType::Number)]).collect(),
+                    },
+                    strict,
+                }),
+            ],
+        }
+    }
+
+    #[test]
+    fn report_all_violations_with_page_paths_in_strict_mode() {
+        let err = validation_sequence(true).process(&bundle(), &Env::test(), &PageGeneratorBagImpl::new()).err().unwrap();
+        assert_eq!(
+            err.downcast_ref::<PagesError>().unwrap(),
+            &PagesError::Validation(
+                indoc! {"
+                    5 metadata violations found
+                    f1.md : author a2 is unknown
+                    f1.md : data weight is expected to be a number
+                    f1.md : tag t3 is not allowed
+                    f1.md : unknown metadata field publishingdate
+                    f3.md : missing metadata"}
+                .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn report_unknown_fields_of_shadow_files_in_strict_mode() {
+        let page = |path: &str, content: &str| -> Arc<dyn Page> {
+            Arc::new(TestPage {
+                path: path.split('/').map(|s| s.to_string()).collect(),
+                metadata: None,
+                content: content.to_string(),
+            })
+        };
+        let bundle: Arc<dyn PageBundle> = Arc::new(VecBundle {
+            p: vec![page("docs.yaml", "{title: docs, publishingdate: 2021-10-20}"), page("docs/f1.md", "f1 content")],
+        });
+        let sequence = SequenceStage {
+            name: "sequence".to_string(),
+            stages: vec![
+                Arc::new(ShadowPages::default("shadow stage".to_string())),
+                Arc::new(ValidateStage {
+                    name: "validate stage".to_string(),
+                    schema: MetadataSchema::default(),
+                    strict: true,
+                }),
+            ],
+        };
+
+        let err = sequence.process(&bundle, &Env::test(), &PageGeneratorBagImpl::new()).err().unwrap();
+        assert_eq!(
+            err.downcast_ref::<PagesError>().unwrap(),
+            &PagesError::Validation("1 metadata violations found\ndocs/f1.md : unknown metadata field publishingdate in docs.yaml".to_string())
+        );
+    }
+
+    #[test]
+    fn keep_all_pages_when_not_strict() {
+        let (result, _) = validation_sequence(false).process(&bundle(), &Env::test(), &PageGeneratorBagImpl::new()).unwrap();
+        assert_eq!(
+            result.pages().iter().map(|p| TestPage::from(p).content).collect::<Vec<_>>(),
+            vec!["f1 content\n", "f2 content\n", "f3 content"]
+        );
+    }
+}
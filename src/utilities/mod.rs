@@ -1,29 +1,63 @@
-use std::fs;
-use std::fs::DirEntry;
-use std::path::Path;
+use ignore::gitignore::Gitignore;
+use ignore::{Match, WalkBuilder};
+use std::path::{Path, PathBuf};
 
-pub(crate) fn visit_dirs<T>(dir: &Path, callback: &mut T) -> anyhow::Result<()>
+pub(crate) const IGNORE_FILE_NAME: &str = ".elepagesignore";
+
+// visits dir files recursively skipping hidden entries and entries matched by .elepagesignore files (and .gitignore ones when use_gitignore is set)
+pub(crate) fn visit_dirs<T>(dir: &Path, use_gitignore: bool, callback: &mut T) -> anyhow::Result<()>
 where
-    T: FnMut(DirEntry) -> anyhow::Result<()>,
+    T: FnMut(PathBuf) -> anyhow::Result<()>,
 {
-    if dir.is_dir() {
-        for entry in fs::read_dir(dir)? {
-            let entry = entry?;
-            let is_hidden = entry.file_name().to_str().map(|s| s.starts_with('.')).unwrap_or(false);
-            if is_hidden {
-                continue;
-            }
-            let path = entry.path();
-            if path.is_dir() {
-                visit_dirs(&path, callback)?;
-            } else {
-                callback(entry)?;
-            }
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    let walker = WalkBuilder::new(dir)
+        .standard_filters(false)
+        .hidden(true)
+        .follow_links(true)
+        .add_custom_ignore_filename(IGNORE_FILE_NAME)
+        .git_ignore(use_gitignore)
+        .require_git(false)
+        // parent .gitignore files apply to the visited dir as in git
+        .parents(use_gitignore)
+        .build();
+    for entry in walker {
+        let entry = entry?;
+        if entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
+            callback(entry.into_path())?;
         }
     }
     Ok(())
 }
 
+// tells whether visit_dirs would skip the path because of the ignore files of its directories
+// deeper ignore files override upper ones, parent .gitignore files apply when use_gitignore is set
+pub(crate) fn is_ignored(dir: &Path, path: &Path, use_gitignore: bool) -> bool {
+    let is_dir = path.is_dir();
+    let parents = match path.parent() {
+        None => return false,
+        Some(p) => p.ancestors().collect::<Vec<&Path>>(),
+    };
+    let mut ignored = false;
+    for parent in parents.iter().rev() {
+        let ignore_files = match (parent.starts_with(dir), use_gitignore) {
+            (true, true) => vec![".gitignore", IGNORE_FILE_NAME],
+            (true, false) => vec![IGNORE_FILE_NAME],
+            (false, true) => vec![".gitignore"],
+            (false, false) => vec![],
+        };
+        for ignore_file in ignore_files.iter().map(|f| parent.join(f)).filter(|f| f.is_file()) {
+            match Gitignore::new(&ignore_file).0.matched_path_or_any_parents(path, is_dir) {
+                Match::Ignore(_) => ignored = true,
+                Match::Whitelist(_) => ignored = false,
+                Match::None => {}
+            }
+        }
+    }
+    ignored
+}
+
 pub(crate) fn uri_friendly_string(original: &str) -> String {
     return original
         .chars()